@@ -0,0 +1,381 @@
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use bytes::BytesMut;
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use customtale_client::tls::ClientIdentity;
+use customtale_protocol::{
+    capture::{CaptureReader, CaptureRecord},
+    compression::CompressionDictionary,
+    framed::HytaleDecoder,
+    packets::{ClientboundPacket, PacketCategory, PacketDirection},
+};
+use futures::StreamExt;
+use miette::IntoDiagnostic;
+use tokio::time::Instant;
+use tokio_util::codec::FramedRead;
+
+fn command() -> Command {
+    let capture = Arg::new("capture")
+        .required(true)
+        .value_parser(value_parser!(PathBuf));
+
+    let filter = [
+        Arg::new("category")
+            .long("category")
+            .action(ArgAction::Append)
+            .value_parser(parse_category)
+            .help("Only shows packets in this category, e.g. `SETUP` or `WORLD_MAP`"),
+        Arg::new("direction")
+            .long("direction")
+            .value_parser(["serverbound", "clientbound"])
+            .help("Only shows packets travelling in this direction"),
+    ];
+
+    Command::new("customtale-capture")
+        .about("Inspects and replays packet captures")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("list")
+                .about("Lists the packets in a capture, one per line")
+                .arg(capture.clone())
+                .args(filter.clone()),
+        )
+        .subcommand(
+            Command::new("print")
+                .about("Decodes and pretty-prints the packets in a capture")
+                .arg(capture.clone())
+                .args(filter),
+        )
+        .subcommand(
+            Command::new("replay")
+                .about(
+                    "Connects to a server and sends it the serverbound packets of a capture, \
+                     printing what the server sends back",
+                )
+                .arg(capture)
+                .arg(
+                    Arg::new("server")
+                        .required(true)
+                        .value_parser(value_parser!(SocketAddr)),
+                )
+                .arg(
+                    Arg::new("server-name")
+                        .long("server-name")
+                        .default_value("localhost"),
+                )
+                .arg(Arg::new("server-fingerprint").long("server-fingerprint").help(
+                    "The fingerprint the server's certificate must have. Any certificate is \
+                     accepted if this is omitted",
+                ))
+                .arg(
+                    Arg::new("realtime")
+                        .long("realtime")
+                        .action(ArgAction::SetTrue)
+                        .help("Waits between packets as long as the original client did"),
+                )
+                .arg(
+                    Arg::new("linger")
+                        .long("linger")
+                        .default_value("5")
+                        .value_parser(value_parser!(u64))
+                        .help("How many seconds to keep listening after the last packet is sent"),
+                ),
+        )
+        .subcommand(
+            Command::new("train-dictionary")
+                .about("Trains a zstd dictionary from the payloads of packets in captures")
+                .arg(
+                    Arg::new("output")
+                        .required(true)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("captures")
+                        .required(true)
+                        .num_args(1..)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("packet")
+                        .long("packet")
+                        .required(true)
+                        .action(ArgAction::Append)
+                        .help("The name of a packet to train on, e.g. `SetChunk`"),
+                )
+                .arg(
+                    Arg::new("max-size")
+                        .long("max-size")
+                        .default_value("112640")
+                        .value_parser(value_parser!(usize))
+                        .help("The largest the dictionary may be, in bytes"),
+                ),
+        )
+}
+
+fn parse_category(name: &str) -> Result<PacketCategory, String> {
+    PacketCategory::from_name(&name.to_uppercase()).ok_or_else(|| {
+        let known = PacketCategory::all()
+            .iter_names()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+
+        format!("expected one of {}", known.join(", "))
+    })
+}
+
+struct Filter {
+    categories: Vec<PacketCategory>,
+    direction: Option<PacketDirection>,
+}
+
+impl Filter {
+    fn from_matches(matches: &ArgMatches) -> Self {
+        Self {
+            categories: matches
+                .get_many::<PacketCategory>("category")
+                .into_iter()
+                .flatten()
+                .copied()
+                .collect(),
+            direction: matches.get_one::<String>("direction").map(|direction| {
+                match direction.as_str() {
+                    "clientbound" => PacketDirection::Clientbound,
+                    _ => PacketDirection::Serverbound,
+                }
+            }),
+        }
+    }
+
+    fn matches(&self, record: &CaptureRecord) -> bool {
+        let direction = self
+            .direction
+            .is_none_or(|direction| direction == record.direction);
+
+        let category = self.categories.is_empty()
+            || record.descriptor().is_some_and(|descriptor| {
+                self.categories
+                    .iter()
+                    .any(|category| category.contains(descriptor.category))
+            });
+
+        direction && category
+    }
+}
+
+fn main() -> miette::Result<()> {
+    let matches = command().get_matches();
+    let (subcommand, matches) = matches.subcommand().unwrap();
+
+    if subcommand == "train-dictionary" {
+        return train_dictionary(
+            matches.get_one::<PathBuf>("output").unwrap(),
+            matches.get_many::<PathBuf>("captures").unwrap(),
+            &matches
+                .get_many::<String>("packet")
+                .unwrap()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            *matches.get_one::<usize>("max-size").unwrap(),
+        );
+    }
+
+    let capture = matches.get_one::<PathBuf>("capture").unwrap();
+
+    match subcommand {
+        "list" => for_each_record(capture, &Filter::from_matches(matches), |start, record| {
+            println!("{}", summarize(start, record));
+        }),
+        "print" => for_each_record(capture, &Filter::from_matches(matches), |start, record| {
+            println!("{}", summarize(start, record));
+
+            match record.decode() {
+                Ok(packet) => println!("{packet:#?}\n"),
+                Err(error) => println!("failed to decode: {error}\n"),
+            }
+        }),
+        "replay" => tokio::runtime::Runtime::new()
+            .into_diagnostic()?
+            .block_on(replay(
+                capture,
+                *matches.get_one::<SocketAddr>("server").unwrap(),
+                matches.get_one::<String>("server-name").unwrap(),
+                matches.get_one::<String>("server-fingerprint").cloned(),
+                matches.get_flag("realtime"),
+                Duration::from_secs(*matches.get_one::<u64>("linger").unwrap()),
+            )),
+        _ => unreachable!(),
+    }
+}
+
+fn for_each_record(
+    capture: &Path,
+    filter: &Filter,
+    mut f: impl FnMut(SystemTime, &CaptureRecord),
+) -> miette::Result<()> {
+    let mut start = None;
+
+    for record in CaptureReader::open(capture).into_diagnostic()? {
+        let record = record.into_diagnostic()?;
+        let start = *start.get_or_insert(record.timestamp);
+
+        if filter.matches(&record) {
+            f(start, &record);
+        }
+    }
+
+    Ok(())
+}
+
+fn summarize(start: SystemTime, record: &CaptureRecord) -> String {
+    let elapsed = record
+        .timestamp
+        .duration_since(start)
+        .unwrap_or_default()
+        .as_secs_f64();
+
+    let arrow = match record.direction {
+        PacketDirection::Clientbound => "S->C",
+        _ => "C->S",
+    };
+
+    let name = record
+        .descriptor()
+        .map_or("<unknown>", |descriptor| descriptor.name);
+
+    format!(
+        "{elapsed:>10.3}s  {arrow}  {:>4}  {name:<32} {} bytes",
+        record.id,
+        record.frame.len(),
+    )
+}
+
+async fn replay(
+    capture: &Path,
+    server: SocketAddr,
+    server_name: &str,
+    server_fingerprint: Option<String>,
+    realtime: bool,
+    linger: Duration,
+) -> miette::Result<()> {
+    let records = CaptureReader::open(capture)
+        .into_diagnostic()?
+        .filter(|record| {
+            record.as_ref().map_or(true, |record| {
+                record.direction == PacketDirection::Serverbound
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .into_diagnostic()?;
+
+    let identity = ClientIdentity::generate().into_diagnostic()?;
+    let endpoint = quinn::Endpoint::client((Ipv4Addr::UNSPECIFIED, 0).into()).into_diagnostic()?;
+
+    let connection = endpoint
+        .connect_with(
+            identity.quic_config(server_fingerprint).into_diagnostic()?,
+            server,
+            server_name,
+        )
+        .into_diagnostic()?
+        .await
+        .into_diagnostic()?;
+
+    let (mut tx, rx) = connection.open_bi().await.into_diagnostic()?;
+
+    let receive = tokio::spawn(async move {
+        let mut rx = FramedRead::new(
+            rx,
+            HytaleDecoder::<ClientboundPacket>::new(PacketCategory::all()),
+        );
+
+        while let Some(packet) = rx.next().await {
+            match packet {
+                Ok(packet) => println!("<- {}", packet.descriptor().name),
+                Err(error) => {
+                    println!("<- failed to decode: {error}");
+                    break;
+                }
+            }
+        }
+    });
+
+    let replay_start = Instant::now();
+    let capture_start = records.first().map(|record| record.timestamp);
+
+    for record in &records {
+        if realtime && let Some(capture_start) = capture_start {
+            let offset = record
+                .timestamp
+                .duration_since(capture_start)
+                .unwrap_or_default();
+
+            tokio::time::sleep_until(replay_start + offset).await;
+        }
+
+        tx.write_all(&record.frame).await.into_diagnostic()?;
+
+        let name = record
+            .descriptor()
+            .map_or("<unknown>", |descriptor| descriptor.name);
+
+        println!("-> {name}");
+    }
+
+    _ = tokio::time::timeout(linger, receive).await;
+
+    tx.finish().into_diagnostic()?;
+    connection.close(0u32.into(), b"");
+    endpoint.wait_idle().await;
+
+    Ok(())
+}
+
+fn train_dictionary<'a>(
+    output: &Path,
+    captures: impl IntoIterator<Item = &'a PathBuf>,
+    packets: &[&str],
+    max_size: usize,
+) -> miette::Result<()> {
+    let mut samples = Vec::new();
+
+    for capture in captures {
+        for record in CaptureReader::open(capture).into_diagnostic()? {
+            let record = record.into_diagnostic()?;
+
+            if !record
+                .descriptor()
+                .is_some_and(|descriptor| packets.contains(&descriptor.name))
+            {
+                continue;
+            }
+
+            // Dictionaries are trained on payloads as they are before compression.
+            let mut payload = BytesMut::new();
+            record
+                .decode()
+                .into_diagnostic()?
+                .encode_static(&mut payload)
+                .map_err(|err| miette::miette!("{err:#}"))?;
+
+            samples.push(payload);
+        }
+    }
+
+    println!("Training on {} samples", samples.len());
+
+    let dictionary = CompressionDictionary::train(&samples, max_size)
+        .map_err(|code| miette::miette!("training failed with Zstd error {code}"))?;
+
+    std::fs::write(output, dictionary.as_bytes()).into_diagnostic()?;
+    println!(
+        "Wrote a {} byte dictionary to {}",
+        dictionary.as_bytes().len(),
+        output.display(),
+    );
+
+    Ok(())
+}
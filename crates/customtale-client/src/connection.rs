@@ -0,0 +1,280 @@
+use std::net::SocketAddr;
+
+use customtale_auth::session::{GameSessionResponse, SessionService, SessionServiceError};
+use customtale_protocol::{
+    framed::{HytaleDecodeError, HytaleDecoder, HytaleEncodeError, HytaleEncoder},
+    packets::{
+        AnyPacket, AuthGrant, AuthToken, ClientType, ClientboundPacket, Connect, Packet,
+        PacketCategory, PacketDescriptor, ProtocolVersion, ServerboundPacket,
+    },
+};
+use futures::{SinkExt, StreamExt};
+use quinn::{RecvStream, SendStream};
+use thiserror::Error;
+use tokio_util::codec::Framed;
+use uuid::Uuid;
+
+use crate::tls::{ClientIdentity, ClientTlsError};
+
+// com/hypixel/hytale/server/core/io/handlers/InitialPacketHandler.java
+// com/hypixel/hytale/server/core/io/handlers/login/AuthenticationPacketHandler.java
+
+pub type PacketSink = Framed<SendStream, HytaleEncoder<ServerboundPacket>>;
+pub type PacketStream = Framed<RecvStream, HytaleDecoder<ClientboundPacket>>;
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("failed to set up the client's TLS configuration")]
+    Tls(#[from] ClientTlsError),
+    #[error("failed to start connecting to the server")]
+    Connect(#[from] quinn::ConnectError),
+    #[error("the connection to the server failed")]
+    Connection(#[from] quinn::ConnectionError),
+    #[error("failed to send a packet")]
+    Encode(#[from] HytaleEncodeError),
+    #[error("failed to receive a packet")]
+    Decode(#[from] HytaleDecodeError),
+    #[error("the session service rejected our authentication")]
+    Session(#[from] SessionServiceError),
+    #[error("the server closed the connection during the handshake")]
+    Closed,
+    #[error(
+        "the server disconnected us: {}",
+        reason.as_deref().unwrap_or("no reason given"),
+    )]
+    Disconnected { reason: Option<String> },
+    #[error("expected {expected} but the server sent {descriptor}")]
+    UnexpectedPacket {
+        descriptor: &'static PacketDescriptor,
+        expected: &'static str,
+    },
+    #[error("the server sent {descriptor} without a {field}")]
+    MissingField {
+        descriptor: &'static PacketDescriptor,
+        field: &'static str,
+    },
+    #[error("the server requires authentication but the client is configured to play offline")]
+    AuthRequired,
+    #[error("the server requires a password, which is not supported")]
+    PasswordRequired,
+}
+
+/// How a client proves its identity to servers.
+#[derive(Debug, Clone)]
+pub enum ClientAuth {
+    /// Sends no identity token, for servers which do not authenticate players.
+    Offline,
+    /// Authenticates through the session service using an existing game session.
+    Session {
+        service: SessionService,
+        session: GameSessionResponse,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub protocol: ProtocolVersion,
+    pub client_version: String,
+    pub client_type: ClientType,
+    pub uuid: Uuid,
+    pub username: String,
+    pub language: String,
+    pub auth: ClientAuth,
+    /// The fingerprint the server's certificate must have, or `None` to accept any certificate.
+    pub server_fingerprint: Option<String>,
+}
+
+impl ClientConfig {
+    pub fn new(protocol: ProtocolVersion, username: impl Into<String>) -> Self {
+        Self {
+            protocol,
+            client_version: concat!("customtale/", env!("CARGO_PKG_VERSION")).to_string(),
+            client_type: ClientType::Game,
+            uuid: Uuid::new_v4(),
+            username: username.into(),
+            language: "en-US".to_string(),
+            auth: ClientAuth::Offline,
+            server_fingerprint: None,
+        }
+    }
+}
+
+/// A connection to a server which has completed the authentication handshake.
+#[derive(Debug)]
+pub struct HytaleClient {
+    connection: quinn::Connection,
+    tx: PacketSink,
+    rx: PacketStream,
+}
+
+impl HytaleClient {
+    pub async fn connect(
+        endpoint: &quinn::Endpoint,
+        addr: SocketAddr,
+        server_name: &str,
+        config: &ClientConfig,
+    ) -> Result<Self, ClientError> {
+        let identity = ClientIdentity::generate()?;
+        let quic_config = identity.quic_config(config.server_fingerprint.clone())?;
+
+        let connection = endpoint
+            .connect_with(quic_config, addr, server_name)?
+            .await?;
+
+        let (tx, rx) = connection.open_bi().await?;
+
+        let mut tx = Framed::new(tx, HytaleEncoder::<ServerboundPacket>::new());
+        let mut rx = Framed::new(
+            rx,
+            HytaleDecoder::<ClientboundPacket>::new(
+                PacketCategory::CONNECTION | PacketCategory::AUTH,
+            ),
+        );
+
+        handshake(&mut tx, &mut rx, &identity, config).await?;
+
+        rx.codec_mut().allowed_categories = PacketCategory::all();
+
+        Ok(Self { connection, tx, rx })
+    }
+
+    pub fn connection(&self) -> &quinn::Connection {
+        &self.connection
+    }
+
+    pub async fn send(&mut self, packet: impl Into<AnyPacket>) -> Result<(), HytaleEncodeError> {
+        self.tx.send(packet.into()).await
+    }
+
+    pub async fn recv(&mut self) -> Option<Result<ClientboundPacket, HytaleDecodeError>> {
+        self.rx.next().await
+    }
+
+    /// Splits the client into a sink of outbound packets and a stream of inbound packets.
+    pub fn into_parts(self) -> (PacketSink, PacketStream) {
+        (self.tx, self.rx)
+    }
+}
+
+async fn handshake(
+    tx: &mut PacketSink,
+    rx: &mut PacketStream,
+    identity: &ClientIdentity,
+    config: &ClientConfig,
+) -> Result<(), ClientError> {
+    let identity_token = match &config.auth {
+        ClientAuth::Offline => None,
+        ClientAuth::Session { session, .. } => Some(session.identity_token.as_str().into()),
+    };
+
+    tx.send(
+        Connect {
+            protocolCrc: config.protocol.crc,
+            protocolBuildNumber: config.protocol.build_number,
+            clientVersion: config.client_version.as_str().into(),
+            clientType: config.client_type,
+            uuid: config.uuid,
+            username: config.username.as_str().into(),
+            identityToken: identity_token,
+            language: config.language.as_str().into(),
+            referralData: None,
+            referralSource: None,
+        }
+        .into(),
+    )
+    .await?;
+
+    let grant = match next_packet(rx).await? {
+        AnyPacket::ConnectAccept(packet) => {
+            if packet.passwordChallenge.is_some() {
+                return Err(ClientError::PasswordRequired);
+            }
+
+            return Ok(());
+        }
+        AnyPacket::AuthGrant(packet) => packet,
+        packet => {
+            return Err(ClientError::UnexpectedPacket {
+                descriptor: packet.descriptor(),
+                expected: "ConnectAccept or AuthGrant",
+            });
+        }
+    };
+
+    let ClientAuth::Session { service, session } = &config.auth else {
+        return Err(ClientError::AuthRequired);
+    };
+
+    let descriptor = AuthGrant::DESCRIPTOR;
+
+    let authorization_grant =
+        grant
+            .authorizationGrant
+            .as_deref()
+            .ok_or(ClientError::MissingField {
+                descriptor,
+                field: "authorizationGrant",
+            })?;
+
+    let server_identity_token =
+        grant
+            .serverIdentityToken
+            .as_deref()
+            .ok_or(ClientError::MissingField {
+                descriptor,
+                field: "serverIdentityToken",
+            })?;
+
+    let access_token = service
+        .exchange_auth_grant_for_token(
+            authorization_grant,
+            &identity.fingerprint,
+            &session.session_token,
+        )
+        .await?;
+
+    // The server exchanges this grant for a token bound to its own certificate, proving to us
+    // that it is who its identity token claims.
+    let server_authorization_grant = service
+        .request_authorization_grant(
+            server_identity_token,
+            &config.uuid.to_string(),
+            &session.session_token,
+        )
+        .await?;
+
+    tx.send(
+        AuthToken {
+            accessToken: Some(access_token.into()),
+            serverAuthorizationGrant: Some(server_authorization_grant.into()),
+        }
+        .into(),
+    )
+    .await?;
+
+    match next_packet(rx).await? {
+        AnyPacket::ServerAuthToken(packet) => {
+            if packet.passwordChallenge.is_some() {
+                return Err(ClientError::PasswordRequired);
+            }
+
+            Ok(())
+        }
+        packet => Err(ClientError::UnexpectedPacket {
+            descriptor: packet.descriptor(),
+            expected: "ServerAuthToken",
+        }),
+    }
+}
+
+async fn next_packet(rx: &mut PacketStream) -> Result<AnyPacket, ClientError> {
+    let packet = rx.next().await.ok_or(ClientError::Closed)??.into_inner();
+
+    if let AnyPacket::Disconnect(packet) = packet {
+        return Err(ClientError::Disconnected {
+            reason: packet.reason.map(String::from),
+        });
+    }
+
+    Ok(packet)
+}
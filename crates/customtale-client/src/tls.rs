@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use customtale_auth::fingerprint::compute_certificate_fingerprint;
+use quinn::crypto::rustls::{NoInitialCipherSuite, QuicClientConfig};
+use rustls::{
+    CertificateError, DigitallySignedStruct, SignatureScheme,
+    client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    crypto::{CryptoProvider, verify_tls12_signature, verify_tls13_signature},
+    pki_types::{CertificateDer, PrivatePkcs8KeyDer, ServerName, UnixTime},
+};
+use thiserror::Error;
+
+// com/hypixel/hytale/server/core/io/transport/QUICTransport.java
+
+pub const ALPN_PROTOCOL: &[u8] = b"hytale/2";
+
+#[derive(Debug, Error)]
+pub enum ClientTlsError {
+    #[error("failed to generate a client certificate")]
+    Identity(#[from] rcgen::Error),
+    #[error("failed to configure TLS")]
+    Tls(#[from] rustls::Error),
+    #[error("no QUIC-compatible cipher suite is available")]
+    CipherSuite(#[from] NoInitialCipherSuite),
+}
+
+/// The certificate a client presents to servers. Servers bind the access tokens they are issued
+/// to its fingerprint.
+#[derive(Debug)]
+pub struct ClientIdentity {
+    pub cert: CertificateDer<'static>,
+    pub fingerprint: String,
+    key: PrivatePkcs8KeyDer<'static>,
+}
+
+impl ClientIdentity {
+    pub fn generate() -> Result<Self, ClientTlsError> {
+        let ssc = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])?;
+
+        let cert = CertificateDer::from(ssc.cert);
+        let key = PrivatePkcs8KeyDer::from(ssc.signing_key.serialize_der());
+        let fingerprint = compute_certificate_fingerprint(&cert);
+
+        Ok(Self {
+            cert,
+            fingerprint,
+            key,
+        })
+    }
+
+    pub fn quic_config(
+        &self,
+        server_fingerprint: Option<String>,
+    ) -> Result<quinn::ClientConfig, ClientTlsError> {
+        let provider = CryptoProvider::get_default()
+            .cloned()
+            .unwrap_or_else(|| Arc::new(rustls::crypto::aws_lc_rs::default_provider()));
+
+        let verifier = FingerprintVerifier {
+            expected: server_fingerprint,
+            provider: provider.clone(),
+        };
+
+        let mut tls_config = rustls::ClientConfig::builder_with_provider(provider)
+            .with_protocol_versions(&[&rustls::version::TLS13])?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(verifier))
+            .with_client_auth_cert(vec![self.cert.clone()], self.key.clone_key().into())?;
+
+        tls_config.alpn_protocols = vec![ALPN_PROTOCOL.to_vec()];
+
+        let crypto = QuicClientConfig::try_from(tls_config)?;
+
+        Ok(quinn::ClientConfig::new(Arc::new(crypto)))
+    }
+}
+
+/// Servers use self-signed certificates so the only meaningful check is against a fingerprint
+/// learned out of band. Without one, any certificate is accepted.
+#[derive(Debug)]
+struct FingerprintVerifier {
+    expected: Option<String>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for FingerprintVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if let Some(expected) = &self.expected
+            && compute_certificate_fingerprint(end_entity) != *expected
+        {
+            return Err(rustls::Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            ));
+        }
+
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
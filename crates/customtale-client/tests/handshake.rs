@@ -0,0 +1,157 @@
+//! Runs the client against a minimal in-process server which accepts players without
+//! authentication.
+
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::Arc,
+};
+
+use customtale_auth::fingerprint::compute_certificate_fingerprint;
+use customtale_client::{
+    connection::{ClientConfig, ClientError, HytaleClient},
+    tls::ALPN_PROTOCOL,
+};
+use customtale_protocol::{
+    framed::{HytaleDecoder, HytaleEncoder},
+    packets::{
+        AnyPacket, ClientboundPacket, ConnectAccept, Disconnect, DisconnectType, PacketCategory,
+        Ping, ProtocolVersion, ServerboundPacket,
+    },
+};
+use futures::{SinkExt, StreamExt};
+use quinn::crypto::rustls::QuicServerConfig;
+use rustls::pki_types::{CertificateDer, PrivatePkcs8KeyDer};
+use tokio_util::codec::Framed;
+
+const PROTOCOL: ProtocolVersion = ProtocolVersion {
+    crc: 0x1234_5678,
+    build_number: 1,
+};
+
+/// Starts a server which answers the first `Connect` it receives with `reply` and then sends a
+/// `Ping`. Returns its address and certificate fingerprint.
+fn spawn_server(reply: AnyPacket) -> (SocketAddr, String) {
+    let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+
+    let ssc = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let cert = CertificateDer::from(ssc.cert);
+    let key = PrivatePkcs8KeyDer::from(ssc.signing_key.serialize_der());
+    let fingerprint = compute_certificate_fingerprint(&cert);
+
+    let mut tls_config = rustls::ServerConfig::builder_with_provider(provider)
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(vec![cert], key.into())
+        .unwrap();
+
+    tls_config.alpn_protocols = vec![ALPN_PROTOCOL.to_vec()];
+
+    let crypto = QuicServerConfig::try_from(tls_config).unwrap();
+    let endpoint = quinn::Endpoint::server(
+        quinn::ServerConfig::with_crypto(Arc::new(crypto)),
+        (Ipv4Addr::LOCALHOST, 0).into(),
+    )
+    .unwrap();
+
+    let addr = endpoint.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let Some(incoming) = endpoint.accept().await else {
+            return;
+        };
+
+        let Ok(conn) = incoming.await else {
+            return;
+        };
+
+        let (tx, rx) = conn.accept_bi().await.unwrap();
+        let mut tx = Framed::new(tx, HytaleEncoder::<ClientboundPacket>::new());
+        let mut rx = Framed::new(
+            rx,
+            HytaleDecoder::<ServerboundPacket>::new(PacketCategory::CONNECTION),
+        );
+
+        let packet = rx.next().await.unwrap().unwrap().into_inner();
+        let AnyPacket::Connect(packet) = packet else {
+            panic!("expected Connect, got {packet:?}");
+        };
+
+        assert_eq!(packet.protocolCrc, PROTOCOL.crc);
+        assert_eq!(packet.username, "bot");
+
+        tx.send(reply).await.unwrap();
+        tx.send(
+            Ping {
+                id: 7,
+                ..Default::default()
+            }
+            .into(),
+        )
+        .await
+        .unwrap();
+
+        // Keep the connection open until the client is done with it.
+        conn.closed().await;
+    });
+
+    (addr, fingerprint)
+}
+
+fn client_endpoint() -> quinn::Endpoint {
+    quinn::Endpoint::client((Ipv4Addr::LOCALHOST, 0).into()).unwrap()
+}
+
+#[tokio::test]
+async fn offline_handshake() {
+    let (addr, fingerprint) = spawn_server(ConnectAccept::default().into());
+
+    let mut config = ClientConfig::new(PROTOCOL, "bot");
+    config.server_fingerprint = Some(fingerprint);
+
+    let mut client = HytaleClient::connect(&client_endpoint(), addr, "localhost", &config)
+        .await
+        .unwrap();
+
+    let packet = client.recv().await.unwrap().unwrap().into_inner();
+    let AnyPacket::Ping(packet) = packet else {
+        panic!("expected Ping, got {packet:?}");
+    };
+
+    assert_eq!(packet.id, 7);
+}
+
+#[tokio::test]
+async fn disconnect_during_handshake() {
+    let (addr, _) = spawn_server(
+        Disconnect {
+            reason: Some("go away".into()),
+            r#type: DisconnectType::Disconnect,
+        }
+        .into(),
+    );
+
+    let config = ClientConfig::new(PROTOCOL, "bot");
+    let err = HytaleClient::connect(&client_endpoint(), addr, "localhost", &config)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(&err, ClientError::Disconnected { reason: Some(reason) } if reason == "go away"),
+        "{err:?}",
+    );
+}
+
+#[tokio::test]
+async fn rejects_unexpected_fingerprint() {
+    let (addr, _) = spawn_server(ConnectAccept::default().into());
+
+    let mut config = ClientConfig::new(PROTOCOL, "bot");
+    config.server_fingerprint = Some("not the server's fingerprint".to_string());
+
+    let err = HytaleClient::connect(&client_endpoint(), addr, "localhost", &config)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ClientError::Connection(_)), "{err:?}");
+}
@@ -0,0 +1,782 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Expr, Fields, GenericArgument, Ident,
+    LitInt, LitStr, PathArguments, Type, ext::IdentExt as _, parse_macro_input, spanned::Spanned,
+};
+
+/// Derives `customtale_protocol::serde::Serde` for a struct or an enum.
+///
+/// - Structs with named fields use the `StructCodec` layout. `#[codec(small)]` on the struct
+///   makes `Option<Self>` use the fixed nullable layout.
+/// - Enums whose variants are all unit variants use the `EnumCodec` layout and must also
+///   implement `Ordinalize<VariantType = u8>`.
+/// - Enums whose variants all hold exactly one unnamed field use the union layout. A `Default`
+///   impl selecting the first variant is generated alongside the codec.
+///
+/// Non-generic types additionally get a `StaticSerde` impl whose codec dispatches to its fields
+/// statically. It produces the same wire format as the erased codec.
+///
+/// Fields (and union variants) accept the following overrides:
+///
+/// - `#[codec(max_len = N)]` bounds a string, byte array, array, or dictionary.
+/// - `#[codec(fixed_string = N)]` encodes a string as a NUL-padded `N` byte buffer.
+/// - `#[codec(nullable = "fixed" | "variable")]` picks the layout of an `Option` field.
+/// - `#[codec(with = expr)]` uses an arbitrary codec expression for the field.
+#[proc_macro_derive(Serde, attributes(codec))]
+pub fn derive_serde(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match &input.data {
+        Data::Struct(data) => expand_struct(&input, data),
+        Data::Enum(data) => expand_enum(&input, data),
+        Data::Union(_) => Err(syn::Error::new(
+            input.ident.span(),
+            "`Serde` cannot be derived for Rust unions",
+        )),
+    }
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+fn internals() -> TokenStream {
+    quote! { ::customtale_protocol::serde::codec_internals }
+}
+
+// === Attributes === //
+
+#[derive(Default)]
+struct ContainerAttrs {
+    small: bool,
+}
+
+impl ContainerAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut out = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("codec")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("small") {
+                    out.small = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown container attribute, expected `small`"))
+                }
+            })?;
+        }
+
+        Ok(out)
+    }
+}
+
+#[derive(Copy, Clone)]
+enum NullableMode {
+    Fixed,
+    Variable,
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    max_len: Option<LitInt>,
+    fixed_string: Option<LitInt>,
+    nullable: Option<(NullableMode, LitStr)>,
+    with: Option<Expr>,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut out = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("codec")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("max_len") {
+                    out.max_len = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("fixed_string") {
+                    out.fixed_string = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("nullable") {
+                    let lit = meta.value()?.parse::<LitStr>()?;
+                    let mode = match lit.value().as_str() {
+                        "fixed" => NullableMode::Fixed,
+                        "variable" => NullableMode::Variable,
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "expected `\"fixed\"` or `\"variable\"`",
+                            ));
+                        }
+                    };
+                    out.nullable = Some((mode, lit));
+                } else if meta.path.is_ident("with") {
+                    out.with = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error(
+                        "unknown field attribute, expected one of `max_len`, `fixed_string`, \
+                         `nullable`, or `with`",
+                    ));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if let Some(with) = &out.with
+            && (out.max_len.is_some() || out.fixed_string.is_some() || out.nullable.is_some())
+        {
+            return Err(syn::Error::new(
+                with.span(),
+                "`with` cannot be combined with other codec attributes",
+            ));
+        }
+
+        if let (Some(_), Some(fixed_string)) = (&out.max_len, &out.fixed_string) {
+            return Err(syn::Error::new(
+                fixed_string.span(),
+                "`max_len` and `fixed_string` are mutually exclusive",
+            ));
+        }
+
+        Ok(out)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.max_len.is_none()
+            && self.fixed_string.is_none()
+            && self.nullable.is_none()
+            && self.with.is_none()
+    }
+}
+
+// === Codec Selection === //
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    if path.qself.is_some() {
+        return None;
+    }
+
+    let last = path.path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+
+    match args.args.iter().collect::<Vec<_>>()[..] {
+        [GenericArgument::Type(inner)] => Some(inner),
+        _ => None,
+    }
+}
+
+struct FieldCodec {
+    dynamic: TokenStream,
+    static_ty: TokenStream,
+    static_expr: TokenStream,
+}
+
+fn field_codec(ty: &Type, attrs: &FieldAttrs) -> syn::Result<FieldCodec> {
+    let internals = internals();
+    let span = ty.span();
+
+    if let Some(with) = &attrs.with {
+        return Ok(FieldCodec {
+            dynamic: quote_spanned! {with.span()=> #internals::Codec::erase(#with) },
+            static_ty: quote_spanned! {with.span()=> #internals::ErasedCodec<#ty> },
+            static_expr: quote_spanned! {with.span()=> #internals::Codec::erase(#with) },
+        });
+    }
+
+    if attrs.is_empty() {
+        return Ok(FieldCodec {
+            dynamic: quote_spanned! {span=> <#ty as #internals::Serde>::codec() },
+            static_ty: quote_spanned! {span=> <#ty as #internals::StaticSerde>::StaticCodec },
+            static_expr: quote_spanned! {span=> <#ty as #internals::StaticSerde>::static_codec() },
+        });
+    }
+
+    let inner_ty = option_inner(ty);
+
+    if let (None, Some((_, lit))) = (inner_ty, &attrs.nullable) {
+        return Err(syn::Error::new(
+            lit.span(),
+            "`nullable` can only be applied to `Option` fields",
+        ));
+    }
+
+    let base_ty = inner_ty.unwrap_or(ty);
+    let base = if let Some(max_len) = &attrs.max_len {
+        FieldCodec {
+            dynamic: quote_spanned! {span=>
+                <#base_ty as #internals::BoundedSerde>::bounded_codec(#max_len)
+            },
+            static_ty: quote_spanned! {span=>
+                <#base_ty as #internals::BoundedStaticSerde>::BoundedStaticCodec
+            },
+            static_expr: quote_spanned! {span=>
+                <#base_ty as #internals::BoundedStaticSerde>::bounded_static_codec(#max_len)
+            },
+        }
+    } else if let Some(size) = &attrs.fixed_string {
+        FieldCodec {
+            dynamic: quote_spanned! {span=>
+                #internals::Codec::erase(
+                    #internals::FixedSizeStringCodec::<#base_ty>::new(#size),
+                )
+            },
+            static_ty: quote_spanned! {span=> #internals::FixedSizeStringCodec<#base_ty> },
+            static_expr: quote_spanned! {span=>
+                #internals::FixedSizeStringCodec::<#base_ty>::new(#size)
+            },
+        }
+    } else {
+        FieldCodec {
+            dynamic: quote_spanned! {span=> <#base_ty as #internals::Serde>::codec() },
+            static_ty: quote_spanned! {span=> <#base_ty as #internals::StaticSerde>::StaticCodec },
+            static_expr: quote_spanned! {span=>
+                <#base_ty as #internals::StaticSerde>::static_codec()
+            },
+        }
+    };
+
+    if inner_ty.is_none() {
+        return Ok(base);
+    }
+
+    let mode = match &attrs.nullable {
+        Some((mode, _)) => *mode,
+        None if attrs.fixed_string.is_some() => NullableMode::Fixed,
+        None => NullableMode::Variable,
+    };
+
+    let FieldCodec {
+        dynamic,
+        static_ty,
+        static_expr,
+    } = base;
+
+    Ok(match mode {
+        NullableMode::Fixed => FieldCodec {
+            dynamic: quote_spanned! {span=> #internals::Codec::nullable_fixed(#dynamic) },
+            static_ty: quote_spanned! {span=> #internals::FixedNullableCodec<#static_ty> },
+            static_expr: quote_spanned! {span=>
+                #internals::FixedNullableCodec::new(#static_expr)
+            },
+        },
+        NullableMode::Variable => FieldCodec {
+            dynamic: quote_spanned! {span=> #internals::Codec::nullable_variable(#dynamic) },
+            static_ty: quote_spanned! {span=> #internals::VariableNullableCodec<#static_ty> },
+            static_expr: quote_spanned! {span=>
+                #internals::VariableNullableCodec::new(#static_expr)
+            },
+        },
+    })
+}
+
+fn wire_name(ident: &Ident) -> String {
+    ident.unraw().to_string()
+}
+
+// === Structs === //
+
+fn expand_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream> {
+    let internals = internals();
+    let container = ContainerAttrs::parse(&input.attrs)?;
+
+    let fields = match &data.fields {
+        Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+        Fields::Unit => Vec::new(),
+        Fields::Unnamed(fields) => {
+            return Err(syn::Error::new(
+                fields.span(),
+                "`Serde` can only be derived for structs with named fields",
+            ));
+        }
+    };
+
+    let mut named_codecs = Vec::new();
+    let mut static_fields = Vec::new();
+
+    for (idx, field) in fields.into_iter().enumerate() {
+        let ident = field.ident.as_ref().unwrap();
+        let name = wire_name(ident);
+        let codec = field_codec(&field.ty, &FieldAttrs::parse(&field.attrs)?)?;
+        let dynamic = &codec.dynamic;
+
+        named_codecs.push(quote! {
+            #internals::Codec::named(
+                #internals::Codec::field(
+                    #dynamic,
+                    ::customtale_protocol::serde::field![Self, #ident],
+                ),
+                #name,
+            )
+        });
+
+        static_fields.push(StaticField {
+            idx,
+            slot: format_ident!("f{idx}"),
+            name,
+            access: quote! { #ident },
+            codec,
+        });
+    }
+
+    let option_is_fixed = container
+        .small
+        .then(|| quote! { const OPTION_IS_FIXED: #internals::bool = true; });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let static_impl = input
+        .generics
+        .params
+        .is_empty()
+        .then(|| expand_static_struct(ident, &static_fields));
+
+    Ok(quote! {
+        impl #impl_generics #internals::Serde for #ident #ty_generics #where_clause {
+            #option_is_fixed
+            const SCHEMA_BY_REF: #internals::bool = true;
+
+            fn build_codec() -> #internals::ErasedCodec<Self> {
+                #internals::Codec::erase(
+                    #internals::StructCodec::<Self>::new([#(#named_codecs,)*]),
+                )
+            }
+        }
+
+        #static_impl
+    })
+}
+
+// === Static Codecs === //
+
+// Static codecs are only generated for non-generic types since they keep their codecs in a
+// `static`.
+
+struct StaticField {
+    idx: usize,
+    slot: Ident,
+    name: String,
+    access: TokenStream,
+    codec: FieldCodec,
+}
+
+fn expand_static_codecs(
+    ident: &Ident,
+    layout_ty: TokenStream,
+    layout_expr: TokenStream,
+    fields: &[StaticField],
+    codec_impl: TokenStream,
+) -> TokenStream {
+    let internals = internals();
+    let slots = fields.iter().map(|field| &field.slot).collect::<Vec<_>>();
+    let slot_tys = fields.iter().map(|field| &field.codec.static_ty);
+    let slot_exprs = fields.iter().map(|field| &field.codec.static_expr);
+
+    quote! {
+        const _: () = {
+            #[doc(hidden)]
+            #[derive(Copy, Clone)]
+            pub struct __StaticCodec;
+
+            struct __Codecs {
+                layout: #layout_ty,
+                #(#slots: #slot_tys,)*
+            }
+
+            fn __codecs() -> &'static __Codecs {
+                static CODECS: #internals::CodecCell<__Codecs> = #internals::CodecCell::new();
+
+                CODECS.get_or_init(|| {
+                    #(let #slots = #slot_exprs;)*
+
+                    __Codecs {
+                        layout: #layout_expr,
+                        #(#slots,)*
+                    }
+                })
+            }
+
+            #[allow(unused_variables, unreachable_code, clippy::match_single_binding)]
+            impl #internals::Codec for __StaticCodec {
+                type Target = #ident;
+
+                #codec_impl
+
+                fn schema(&self) -> #internals::Schema {
+                    #internals::Schema::Named(#internals::SchemaRef::of::<#ident>())
+                }
+            }
+
+            impl #internals::StaticSerde for #ident {
+                type StaticCodec = __StaticCodec;
+
+                fn static_codec() -> Self::StaticCodec {
+                    __StaticCodec
+                }
+            }
+        };
+    }
+}
+
+fn expand_static_struct(ident: &Ident, fields: &[StaticField]) -> TokenStream {
+    let internals = internals();
+    let shapes = fields.iter().map(|field| {
+        let StaticField { slot, name, .. } = field;
+        quote! { #internals::FieldShape::of(#name, &#slot) }
+    });
+
+    let decode_arms = fields.iter().map(|field| {
+        let StaticField {
+            idx, slot, access, ..
+        } = field;
+        quote! {
+            #idx => #internals::Codec::decode(
+                &codecs.#slot,
+                &mut target.#access,
+                buf,
+                non_null_bit_set,
+                limits,
+            ),
+        }
+    });
+
+    let non_null_arms = fields.iter().map(|field| {
+        let StaticField {
+            idx, slot, access, ..
+        } = field;
+        quote! { #idx => #internals::Codec::is_non_null_bit_set(&codecs.#slot, &target.#access), }
+    }).collect::<Vec<_>>();
+
+    let encode_arms = fields.iter().map(|field| {
+        let StaticField {
+            idx, slot, access, ..
+        } = field;
+        quote! { #idx => #internals::Codec::encode(&codecs.#slot, &target.#access, buf), }
+    });
+
+    let len_arms = fields.iter().map(|field| {
+        let StaticField {
+            idx, slot, access, ..
+        } = field;
+        quote! { #idx => #internals::Codec::encoded_len(&codecs.#slot, &target.#access), }
+    });
+
+    expand_static_codecs(
+        ident,
+        quote! { #internals::StructLayout },
+        quote! { #internals::StructLayout::new([#(#shapes,)*]) },
+        fields,
+        quote! {
+            fn fixed_size(&self) -> #internals::Option<#internals::usize> {
+                __codecs().layout.fixed_size()
+            }
+
+            fn decode(
+                &self,
+                target: &mut Self::Target,
+                buf: &mut #internals::Bytes,
+                _non_null_bit_set: #internals::bool,
+                limits: &mut #internals::DecodeLimits,
+            ) -> #internals::DecodeResult {
+                let codecs = __codecs();
+
+                codecs.layout.decode_fields(buf, |idx, buf, non_null_bit_set| {
+                    match idx {
+                        #(#decode_arms)*
+                        _ => #internals::unreachable!(),
+                    }
+                })
+            }
+
+            fn encode(
+                &self,
+                target: &Self::Target,
+                buf: &mut #internals::BytesMut,
+            ) -> #internals::Result<()> {
+                let codecs = __codecs();
+
+                codecs.layout.encode_fields(
+                    buf,
+                    |idx| match idx {
+                        #(#non_null_arms)*
+                        _ => #internals::unreachable!(),
+                    },
+                    |idx, buf| match idx {
+                        #(#encode_arms)*
+                        _ => #internals::unreachable!(),
+                    },
+                )
+            }
+
+            fn encoded_len(&self, target: &Self::Target) -> #internals::usize {
+                let codecs = __codecs();
+
+                codecs.layout.encoded_len(
+                    |idx| match idx {
+                        #(#non_null_arms)*
+                        _ => #internals::unreachable!(),
+                    },
+                    |idx| match idx {
+                        #(#len_arms)*
+                        _ => #internals::unreachable!(),
+                    },
+                )
+            }
+        },
+    )
+}
+
+fn expand_static_union(ident: &Ident, variants: &[StaticField]) -> TokenStream {
+    let internals = internals();
+    let names = variants.iter().map(|variant| &variant.name);
+
+    let decode_arms = variants.iter().map(|variant| {
+        let StaticField {
+            idx, slot, access, ..
+        } = variant;
+        quote! {
+            #idx => {
+                let mut value = #internals::Default::default();
+                #internals::Codec::decode(&codecs.#slot, &mut value, buf, true, limits)?;
+                *target = #ident::#access(value);
+                #internals::Ok(())
+            }
+        }
+    });
+
+    let encode_arms = variants.iter().map(|variant| {
+        let StaticField {
+            idx, slot, access, ..
+        } = variant;
+        quote! {
+            #ident::#access(value) => codecs.layout.encode_variant(#idx, buf, |buf| {
+                #internals::Codec::encode(&codecs.#slot, value, buf)
+            }),
+        }
+    });
+
+    let len_arms = variants.iter().map(|variant| {
+        let StaticField {
+            idx, slot, access, ..
+        } = variant;
+        quote! {
+            #ident::#access(value) => codecs.layout.encoded_len(
+                #idx,
+                #internals::Codec::encoded_len(&codecs.#slot, value),
+            ),
+        }
+    });
+
+    expand_static_codecs(
+        ident,
+        quote! { #internals::UnionLayout },
+        quote! { #internals::UnionLayout::new([#(#names,)*]) },
+        variants,
+        quote! {
+            fn fixed_size(&self) -> #internals::Option<#internals::usize> {
+                #internals::Option::None
+            }
+
+            fn decode(
+                &self,
+                target: &mut Self::Target,
+                buf: &mut #internals::Bytes,
+                _non_null_bit_set: #internals::bool,
+                limits: &mut #internals::DecodeLimits,
+            ) -> #internals::DecodeResult {
+                let codecs = __codecs();
+
+                codecs.layout.decode_variant::<Self::Target>(buf, |type_id, buf| match type_id {
+                    #(#decode_arms)*
+                    _ => #internals::unreachable!(),
+                })
+            }
+
+            fn encode(
+                &self,
+                target: &Self::Target,
+                buf: &mut #internals::BytesMut,
+            ) -> #internals::Result<()> {
+                let codecs = __codecs();
+
+                match target {
+                    #(#encode_arms)*
+                }
+            }
+
+            fn encoded_len(&self, target: &Self::Target) -> #internals::usize {
+                let codecs = __codecs();
+
+                match target {
+                    #(#len_arms)*
+                }
+            }
+        },
+    )
+}
+
+// === Enums === //
+
+fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    if data.variants.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`Serde` cannot be derived for empty enums",
+        ));
+    }
+
+    if data
+        .variants
+        .iter()
+        .all(|variant| matches!(variant.fields, Fields::Unit))
+    {
+        return expand_simple_enum(input, data);
+    }
+
+    if data
+        .variants
+        .iter()
+        .all(|variant| matches!(&variant.fields, Fields::Unnamed(fields) if fields.unnamed.len() == 1))
+    {
+        return expand_union(input, data);
+    }
+
+    Err(syn::Error::new(
+        input.ident.span(),
+        "`Serde` enums must either consist entirely of unit variants or entirely of \
+         single-field tuple variants",
+    ))
+}
+
+fn expand_simple_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    let internals = internals();
+    let container = ContainerAttrs::parse(&input.attrs)?;
+
+    if container.small {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "simple enums are always `small`",
+        ));
+    }
+
+    for variant in &data.variants {
+        if !FieldAttrs::parse(&variant.attrs)?.is_empty() {
+            return Err(syn::Error::new(
+                variant.span(),
+                "unit variants do not accept codec attributes",
+            ));
+        }
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #internals::Serde for #ident #ty_generics #where_clause {
+            const OPTION_IS_FIXED: #internals::bool = true;
+
+            fn build_codec() -> #internals::ErasedCodec<Self> {
+                #internals::Codec::erase(#internals::EnumCodec::<Self>::new())
+            }
+        }
+
+        impl #impl_generics #internals::StaticSerde for #ident #ty_generics #where_clause {
+            type StaticCodec = #internals::EnumCodec<Self>;
+
+            fn static_codec() -> Self::StaticCodec {
+                #internals::EnumCodec::new()
+            }
+        }
+    })
+}
+
+fn expand_union(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    let internals = internals();
+    let container = ContainerAttrs::parse(&input.attrs)?;
+
+    if container.small {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "unions cannot be `small`",
+        ));
+    }
+
+    let mut ordinal_arms = Vec::new();
+    let mut named_codecs = Vec::new();
+    let mut static_variants = Vec::new();
+
+    for (idx, variant) in data.variants.iter().enumerate() {
+        let variant_ident = &variant.ident;
+        let name = wire_name(variant_ident);
+        let Fields::Unnamed(fields) = &variant.fields else {
+            unreachable!();
+        };
+        let field = &fields.unnamed[0];
+        let codec = field_codec(&field.ty, &FieldAttrs::parse(&variant.attrs)?)?;
+        let dynamic = &codec.dynamic;
+
+        ordinal_arms.push(quote! { Self::#variant_ident(_) => #idx, });
+        named_codecs.push(quote! {
+            #internals::Codec::named(
+                #internals::VariantCodec::new(
+                    #dynamic,
+                    |target| match target {
+                        Self::#variant_ident(value) => #internals::Option::Some(value),
+                        _ => #internals::Option::None,
+                    },
+                    Self::#variant_ident,
+                ),
+                #name,
+            )
+        });
+
+        static_variants.push(StaticField {
+            idx,
+            slot: format_ident!("v{idx}"),
+            name,
+            access: quote! { #variant_ident },
+            codec,
+        });
+    }
+
+    let first = &data.variants[0].ident;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let static_impl = input
+        .generics
+        .params
+        .is_empty()
+        .then(|| expand_static_union(ident, &static_variants));
+
+    Ok(quote! {
+        impl #impl_generics #internals::Default for #ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self::#first(#internals::Default::default())
+            }
+        }
+
+        #[allow(unreachable_patterns)]
+        impl #impl_generics #internals::Serde for #ident #ty_generics #where_clause {
+            const SCHEMA_BY_REF: #internals::bool = true;
+
+            fn build_codec() -> #internals::ErasedCodec<Self> {
+                #internals::Codec::erase(#internals::UnionCodec::<Self>::new(
+                    |target| match target {
+                        #(#ordinal_arms)*
+                    },
+                    [#(#named_codecs,)*],
+                ))
+            }
+        }
+
+        #static_impl
+    })
+}
@@ -77,6 +77,16 @@ fun main(args: Array<String>) {
     val defSb = StringBuilder()
     defSb.append(getResourceAsText("/prefix_defs.rs"))
 
+    val version = importProtocolVersion(loader)
+    if (version != null) {
+        defSb.append("pub const PROTOCOL_VERSION: Option<ProtocolVersion> = Some(ProtocolVersion {\n")
+        defSb.append("    crc: ${version.first},\n")
+        defSb.append("    build_number: ${version.second},\n")
+        defSb.append("});\n\n")
+    } else {
+        defSb.append("pub const PROTOCOL_VERSION: Option<ProtocolVersion> = None;\n\n")
+    }
+
     defSb.append("define_packets! {")
     for (def in importer.definitions) {
         if (def.packet == null || def.codec.isTainted())
@@ -95,6 +105,20 @@ fun main(args: Array<String>) {
     outPath.resolve("packets.rs").writeText(defSb.toString())
 }
 
+// Reads the CRC and build number which clients built against this JAR report in `Connect`, if the
+// JAR exposes them.
+private fun importProtocolVersion(loader: ClassLoader) : Pair<Int, Int>? {
+    return try {
+        val settings = loader.loadClass("$PACKET_PKG_ROOT.ProtocolSettings")
+
+        Pair(
+            settings.getField("PROTOCOL_CRC").getInt(null),
+            settings.getField("PROTOCOL_BUILD_NUMBER").getInt(null))
+    } catch (e: ReflectiveOperationException) {
+        null
+    }
+}
+
 private fun formatByteArray(arr: ByteArray) : String {
     val builder = StringBuilder()
 
@@ -116,6 +116,10 @@ class Importer(val loader: ClassLoader) {
         if (ty == UUID::class.java)
             return CodecNode.Uuid()
 
+        if (ty.isArray && ty.componentType == Byte::class.java) {
+            return CodecNode.VarByteArray(DEFAULT_MAX_VAR_LEN)
+        }
+
         if (ty.isArray) {
             return CodecNode.VarList(import(ty.componentType), DEFAULT_MAX_VAR_LEN)
         }
@@ -157,6 +161,7 @@ class Importer(val loader: ClassLoader) {
                 maxSize = ty.getField("MAX_SIZE").get(null) as Int,
                 compressed = ty.getField("IS_COMPRESSED").get(null) as Boolean,
                 categories = getPacketCategory(ty),
+                direction = getPacketDirection(ty),
             )
         } else {
             null
@@ -264,6 +269,7 @@ class ImportedDefinition(val packet: PacketAnnotation?, val codec: CodecNode) {
         sb.append("        is_compressed: ${packet.compressed},\n")
         sb.append("        max_size: ${packet.maxSize},\n")
         sb.append($"        category: ${packet.categories},\n")
+        sb.append($"        direction: ${packet.direction},\n")
         sb.append("    };\n")
         sb.append("}\n\n")
     }
@@ -274,4 +280,5 @@ class PacketAnnotation(
     val maxSize: Int,
     val compressed: Boolean,
     val categories: String,
+    val direction: String,
 )
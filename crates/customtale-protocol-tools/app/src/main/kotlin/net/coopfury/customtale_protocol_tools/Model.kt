@@ -27,6 +27,30 @@ sealed class CodecNode {
 
     abstract fun toRustType(sb: StringBuilder)
     abstract fun toRustSerializer(sb: StringBuilder)
+
+    // The arguments of a `#[codec(...)]` attribute overriding this node's default serializer, or
+    // `null` if the override can only be expressed as a `with = ...` expression.
+    open fun toRustCodecAttributeArgs() : String? {
+        return if (isDefaultSerializer) "" else null
+    }
+
+    fun toRustCodecAttribute(sb: StringBuilder, indent: String) {
+        if (isDefaultSerializer)
+            return
+
+        sb.append(indent)
+        sb.append("#[codec(")
+
+        val args = toRustCodecAttributeArgs()
+        if (args != null) {
+            sb.append(args)
+        } else {
+            sb.append("with = ")
+            toRustSerializer(sb)
+        }
+
+        sb.append(")]\n")
+    }
     protected abstract fun generateInstance(rng: Random, depth: Int) : Any?
     protected abstract fun isTainted(coinductive: MutableSet<CodecNode>) : Boolean
 
@@ -67,29 +91,27 @@ sealed class CodecNode {
         }
 
         override fun toRustDefinition(sb: StringBuilder) {
-            sb.append("codec! {\n")
-            sb.append("    pub struct ")
-            sb.append(ctor.declaringClass.simpleName)
-            sb.append(" {\n")
+            sb.append("#[derive(Debug, Clone, Default, Serde)]\n")
+            sb.append("#[cfg_attr(feature = \"serde-interop\", derive(::serde::Serialize, ::serde::Deserialize))]\n")
 
             if (defaultOptionSerdeMode == OptionSerdeMode.Fixed) {
-                sb.append("        @small = true;\n")
+                sb.append("#[codec(small)]\n")
             }
 
+            sb.append("pub struct ")
+            sb.append(ctor.declaringClass.simpleName)
+            sb.append(" {\n")
+
             for (field in fields) {
-                sb.append("        pub ")
+                field.codec.toRustCodecAttribute(sb, "    ")
+                sb.append("    pub ")
                 sb.append(escapeNameToIdent(field.name))
                 sb.append(": ")
                 field.codec.toRustType(sb)
-
-                if (!field.codec.isDefaultSerializer) {
-                    sb.append("\n            => ")
-                    field.codec.toRustSerializer(sb)
-                }
                 sb.append(",\n")
             }
 
-            sb.append("    }\n}\n\n")
+            sb.append("}\n\n")
         }
 
         override fun generateInstance(rng: Random, depth: Int) : Any? {
@@ -147,25 +169,21 @@ sealed class CodecNode {
         }
 
         override fun toRustDefinition(sb: StringBuilder) {
-            sb.append("codec! {\n")
-            sb.append("    pub union ")
+            sb.append("#[derive(Debug, Clone, Serde)]\n")
+            sb.append("#[cfg_attr(feature = \"serde-interop\", derive(::serde::Serialize, ::serde::Deserialize))]\n")
+            sb.append("pub enum ")
             sb.append(type.simpleName)
             sb.append(" {\n")
 
             for (variant in variants) {
-                sb.append("        ")
-
+                variant.codec.toRustCodecAttribute(sb, "    ")
+                sb.append("    ")
                 sb.append(variant.name)
-                sb.append("(")
+                sb.append("(Box<")
                 variant.codec.toRustType(sb)
-                sb.append(")")
-                if (!variant.codec.isDefaultSerializer) {
-                    sb.append("\n            => ")
-                    variant.codec.toRustSerializer(sb)
-                }
-                sb.append(",\n")
+                sb.append(">),\n")
             }
-            sb.append("    }\n}\n\n")
+            sb.append("}\n\n")
         }
     }
 
@@ -186,17 +204,22 @@ sealed class CodecNode {
         }
 
         override fun toRustDefinition(sb: StringBuilder) {
-            sb.append("codec! {\n")
-            sb.append("    pub enum ")
+            sb.append("#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]\n")
+            sb.append("#[cfg_attr(feature = \"serde-interop\", derive(::serde::Serialize, ::serde::Deserialize))]\n")
+            sb.append("#[repr(u8)]\n")
+            sb.append("pub enum ")
             sb.append(type.simpleName)
             sb.append(" {\n")
 
-            for (variant in variants) {
-                sb.append("        ")
+            for ((i, variant) in variants.withIndex()) {
+                if (i == 0)
+                    sb.append("    #[default]\n")
+
+                sb.append("    ")
                 sb.append(escapeNameToIdent(variant.toString()))
                 sb.append(",\n")
             }
-            sb.append("    }\n}\n\n")
+            sb.append("}\n\n")
         }
 
         override fun generateInstance(rng: Random, depth: Int): Any? {
@@ -225,7 +248,30 @@ sealed class CodecNode {
 
         override fun toRustSerializer(sb: StringBuilder) {
             node.toRustSerializer(sb)
-            sb.append(".nullable_variable()")
+
+            when (mode) {
+                OptionSerdeMode.Variable -> sb.append(".nullable_variable()")
+                OptionSerdeMode.Fixed -> sb.append(".nullable_fixed()")
+            }
+        }
+
+        override fun toRustCodecAttributeArgs(): String? {
+            val args = node.toRustCodecAttributeArgs() ?: return null
+
+            // The derive macro picks the fixed layout for `fixed_string` overrides and the
+            // variable one for every other override.
+            val attributeMode = if (node is FixedString) OptionSerdeMode.Fixed else OptionSerdeMode.Variable
+            val impliedMode = if (node.isDefaultSerializer) node.defaultOptionSerdeMode else attributeMode
+
+            if (mode == impliedMode)
+                return args
+
+            val nullable = when (mode) {
+                OptionSerdeMode.Variable -> "nullable = \"variable\""
+                OptionSerdeMode.Fixed -> "nullable = \"fixed\""
+            }
+
+            return if (args.isEmpty()) nullable else "$args, $nullable"
         }
 
         override fun generateInstance(rng: Random, depth: Int) : Any? {
@@ -294,11 +340,11 @@ sealed class CodecNode {
         override val jvmType: Class<*> get() = Byte::class.java
 
         override fun toRustType(sb: StringBuilder) {
-            sb.append("u8")
+            sb.append("i8")
         }
 
         override fun toRustSerializer(sb: StringBuilder) {
-            sb.append("u8::codec()")
+            sb.append("i8::codec()")
         }
 
         override fun generateInstance(rng: Random, depth: Int): Any {
@@ -316,11 +362,11 @@ sealed class CodecNode {
         override val jvmType: Class<*> get() = Short::class.java
 
         override fun toRustType(sb: StringBuilder) {
-            sb.append("u16")
+            sb.append("i16")
         }
 
         override fun toRustSerializer(sb: StringBuilder) {
-            sb.append("u16::codec()")
+            sb.append("i16::codec()")
         }
 
         override fun generateInstance(rng: Random, depth: Int): Any {
@@ -338,11 +384,11 @@ sealed class CodecNode {
         override val jvmType: Class<*> get() = Int::class.java
 
         override fun toRustType(sb: StringBuilder) {
-            sb.append("u32")
+            sb.append("i32")
         }
 
         override fun toRustSerializer(sb: StringBuilder) {
-            sb.append("u32::codec()")
+            sb.append("i32::codec()")
         }
 
         override fun generateInstance(rng: Random, depth: Int): Any {
@@ -360,11 +406,11 @@ sealed class CodecNode {
         override val jvmType: Class<*> get() = Long::class.java
 
         override fun toRustType(sb: StringBuilder) {
-            sb.append("u64")
+            sb.append("i64")
         }
 
         override fun toRustSerializer(sb: StringBuilder) {
-            sb.append("u64::codec()")
+            sb.append("i64::codec()")
         }
 
         override fun generateInstance(rng: Random, depth: Int): Any {
@@ -376,6 +422,28 @@ sealed class CodecNode {
         }
     }
 
+    class LeHalfFloat : CodecNode() {
+        override val isDefaultSerializer: Boolean get() = true
+        override val defaultOptionSerdeMode: OptionSerdeMode = OptionSerdeMode.Fixed
+        override val jvmType: Class<*> get() = Short::class.java
+
+        override fun toRustType(sb: StringBuilder) {
+            sb.append("half::f16")
+        }
+
+        override fun toRustSerializer(sb: StringBuilder) {
+            sb.append("half::f16::codec()")
+        }
+
+        override fun generateInstance(rng: Random, depth: Int): Any {
+            return rng.nextInt().toShort()
+        }
+
+        override fun isTainted(coinductive: MutableSet<CodecNode>): Boolean {
+            return false
+        }
+    }
+
     class LeFloat : CodecNode() {
         override val isDefaultSerializer: Boolean get() = true
         override val defaultOptionSerdeMode: OptionSerdeMode = OptionSerdeMode.Fixed
@@ -474,6 +542,10 @@ sealed class CodecNode {
             sb.append(")")
         }
 
+        override fun toRustCodecAttributeArgs(): String? {
+            return if (elem.isDefaultSerializer) "max_len = $maxLen" else null
+        }
+
         override fun generateInstance(rng: Random, depth: Int): Any {
             val len = randomLenForDepth(rng, depth)
             val arr = ArrayReflect.newInstance(elem.jvmType, len)
@@ -514,6 +586,10 @@ sealed class CodecNode {
             sb.append(")")
         }
 
+        override fun toRustCodecAttributeArgs(): String? {
+            return if (key.isDefaultSerializer && value.isDefaultSerializer) "max_len = $maxLen" else null
+        }
+
         override fun generateInstance(rng: Random, depth: Int): Any {
             val len = randomLenForDepth(rng, depth)
             val map = mutableMapOf<Any?, Any?>()
@@ -536,7 +612,7 @@ sealed class CodecNode {
         override val jvmType: Class<*> get() = String::class.java
 
         override fun toRustType(sb: StringBuilder) {
-            sb.append("String")
+            sb.append("ByteString")
         }
 
         override fun toRustSerializer(sb: StringBuilder) {
@@ -545,6 +621,10 @@ sealed class CodecNode {
             sb.append(")")
         }
 
+        override fun toRustCodecAttributeArgs(): String {
+            return "fixed_string = $maxLen"
+        }
+
         override fun generateInstance(rng: Random, depth: Int): Any {
             return rng.nextInt().toString()
         }
@@ -560,7 +640,7 @@ sealed class CodecNode {
         override val jvmType: Class<*> get() = String::class.java
 
         override fun toRustType(sb: StringBuilder) {
-            sb.append("String")
+            sb.append("ByteString")
         }
 
         override fun toRustSerializer(sb: StringBuilder) {
@@ -569,6 +649,10 @@ sealed class CodecNode {
             sb.append(")")
         }
 
+        override fun toRustCodecAttributeArgs(): String {
+            return "max_len = $maxLen"
+        }
+
         override fun generateInstance(rng: Random, depth: Int): Any {
             return rng.nextInt().toString()
         }
@@ -581,7 +665,7 @@ sealed class CodecNode {
     class VarByteArray(val maxLen: Int) : CodecNode() {
         override val isDefaultSerializer: Boolean get() = maxLen == DEFAULT_MAX_VAR_LEN
         override val defaultOptionSerdeMode: OptionSerdeMode get() = OptionSerdeMode.Variable
-        override val jvmType: Class<*> get() = Array<Byte>::class.java
+        override val jvmType: Class<*> get() = ByteArray::class.java
 
         override fun toRustType(sb: StringBuilder) {
             sb.append("Bytes")
@@ -593,6 +677,10 @@ sealed class CodecNode {
             sb.append(")")
         }
 
+        override fun toRustCodecAttributeArgs(): String {
+            return "max_len = $maxLen"
+        }
+
         override fun generateInstance(rng: Random, depth: Int): Any {
             val bytes = ByteArray(rng.nextInt(min(100, maxLen)))
             rng.nextBytes(bytes)
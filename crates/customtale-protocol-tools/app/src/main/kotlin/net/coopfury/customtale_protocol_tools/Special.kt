@@ -99,6 +99,9 @@ fun overrideSpecialField(field: Field, importer: Importer) : CodecNode? {
             return CodecNode.VarString(512)
     }
 
+    if (field.declaringClass.name == "$PACKET_PKG_ROOT.HalfFloatPosition")
+        return CodecNode.LeHalfFloat()
+
     if (field.declaringClass.name == "$PACKET_PKG_ROOT.HostAddress") {
         if (field.name == "host")
             return CodecNode.VarString(256)
@@ -178,3 +181,55 @@ fun getPacketCategory(ty: Class<*>) : String {
 
     throw UnsupportedOperationException("unknown packet category for ${ty.name}")
 }
+
+// The JAR does not record which side sends a packet so these are maintained by hand. Packets not
+// listed here are accepted in both directions.
+private val CLIENTBOUND_PACKETS = setOf(
+    "Ping", "AuthGrant", "ServerAuthToken", "ConnectAccept", "PasswordAccepted",
+    "PasswordRejected", "ClientReferral", "WorldSettings", "WorldLoadProgress",
+    "WorldLoadFinished", "AssetInitialize", "AssetPart", "AssetFinalize", "RemoveAssets",
+    "SetUpdateRate", "SetTimeDilation", "UpdateFeatures", "ServerTags", "CameraShakeEffect",
+    "SetServerCamera", "SetFlyCameraMode", "SetClientId", "SetMovementStates",
+    "SetBlockPlacementOverride", "JoinWorld", "ClientTeleport", "UpdateMovementSettings",
+    "DamageInfo", "ReticleEvent", "DisplayDebug", "ClearDebugShapes",
+    "UpdateMemoriesFeatureStatus", "SetChunk", "SetChunkHeightmap", "SetChunkTintmap",
+    "SetChunkEnvironments", "UnloadChunk", "SetFluids", "ServerSetBlock", "ServerSetBlocks",
+    "ServerSetFluid", "ServerSetFluids", "UpdateBlockDamage", "UpdateTimeSettings", "UpdateTime",
+    "UpdateWeather", "UpdateEnvironmentMusic", "SpawnParticleSystem", "SpawnBlockParticleSystem",
+    "PlaySoundEvent2D", "PlaySoundEvent3D", "PlaySoundEventEntity", "UpdateSleepState",
+    "ServerSetPaused", "UpdateSunSettings", "UpdatePostFxSettings", "SetEntitySeed",
+    "EntityUpdates", "PlayAnimation", "ChangeVelocity", "ApplyKnockback", "SpawnModelParticles",
+    "UpdatePlayerInventory", "OpenWindow", "UpdateWindow", "ServerMessage", "Notification",
+    "KillFeedMessage", "ShowEventTitle", "HideEventTitle", "CustomHud", "CustomPage", "ServerInfo",
+    "AddToServerPlayerList", "RemoveFromServerPlayerList", "UpdateServerPlayerList",
+    "UpdateServerPlayerListPing", "UpdateKnownRecipes", "UpdatePortal",
+    "UpdateVisibleHudComponents", "ResetUserInterfaceState", "WorldSavingStatus",
+    "OpenChatWithCommand", "UpdateWorldMapSettings", "UpdateWorldMap", "ClearWorldMap",
+    "PlayInteractionFor",
+)
+
+private val SERVERBOUND_PACKETS = setOf(
+    "Connect", "Pong", "AuthToken", "PasswordResponse", "RequestAssets", "PlayerOptions",
+    "ClientReady", "ClientMovement", "MouseInteraction", "ClientPlaceBlock",
+    "SyncPlayerPreferences", "SetPaused", "MountMovement", "SetCreativeItem", "DropCreativeItem",
+    "SmartGiveCreativeItem", "DropItemStack", "MoveItemStack", "SmartMoveItemStack",
+    "InventoryAction", "SendWindowAction", "ClientOpenWindow", "ChatMessage", "CustomPageEvent",
+    "TeleportToWorldMapMarker", "TeleportToWorldMapPosition", "RequestServerAccess",
+    "RequestMachinimaActorModel", "RequestFlyCameraMode",
+)
+
+fun getPacketDirection(ty: Class<*>) : String {
+    if (ty.packageName == "$PACKET_PKG_ROOT.packets.assets")
+        return "PacketDirection::Clientbound"
+
+    if (ty.packageName == "$PACKET_PKG_ROOT.packets.asseteditor" && ty.simpleName.endsWith("Reply"))
+        return "PacketDirection::Clientbound"
+
+    if (ty.simpleName in CLIENTBOUND_PACKETS)
+        return "PacketDirection::Clientbound"
+
+    if (ty.simpleName in SERVERBOUND_PACKETS)
+        return "PacketDirection::Serverbound"
+
+    return "PacketDirection::Both"
+}
@@ -3,53 +3,101 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
-use std::fmt;
+use std::{any::Any, fmt, ops::Deref};
 
 use bytes::{Bytes, BytesMut};
+use enum_ordinalize::Ordinalize;
 use uuid::Uuid;
 
-use crate::serde::*;
-
-bitflags::bitflags! {
-    #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-    pub struct PacketCategory: u32 {
-        const ASSET_EDITOR = 1 << 0;
-        const ASSETS = 1 << 1;
-        const AUTH = 1 << 2;
-        const BUILDER_TOOLS = 1 << 3;
-        const CAMERA = 1 << 4;
-        const CONNECTION = 1 << 5;
-        const ENTITIES = 1 << 6;
-        const INTERACTION = 1 << 7;
-        const INTERFACE = 1 << 8;
-        const INVENTORY = 1 << 9;
-        const MACHINIMA = 1 << 10;
-        const PLAYER = 1 << 11;
-        const SERVER_ACCESS = 1 << 12;
-        const SETUP = 1 << 13;
-        const WINDOW = 1 << 14;
-        const WORLD = 1 << 15;
-        const WORLD_MAP = 1 << 16;
-    }
-}
+use crate::{
+    packets::{
+        PacketCategory, PacketDescriptor, PacketDirection, PacketEntry, PacketTable, Protocol,
+        ProtocolPacket, ProtocolVersion, build_packet_table, packet_table_entry, packet_table_len,
+    },
+    serde::*,
+};
+
+#[cfg(test)]
+#[path = "tests.rs"]
+mod tests;
 
-pub trait Packet: Into<AnyPacket> + fmt::Debug + Clone + Serde {
+pub trait Packet: Into<AnyPacket> + fmt::Debug + Clone + StaticSerde {
     const DESCRIPTOR: &'static PacketDescriptor;
 }
 
-#[derive(Debug, Clone)]
-pub struct PacketDescriptor {
-    pub name: &'static str,
-    pub id: u32,
-    pub is_compressed: bool,
-    pub max_size: u32,
-    pub category: PacketCategory,
+/// A view over the [`AnyPacket`]s sent in one direction.
+pub trait DirectedPacket:
+    Into<AnyPacket> + TryFrom<AnyPacket, Error = AnyPacket> + fmt::Debug + Clone
+{
+    /// The direction this view's packets travel in.
+    const DIRECTION: PacketDirection;
+
+    fn accepts(direction: PacketDirection) -> bool;
+}
+
+macro_rules! define_direction_view {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $direction:ident, $is_direction:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone)]
+        pub struct $name(AnyPacket);
+
+        impl $name {
+            pub fn new(packet: AnyPacket) -> Result<Self, AnyPacket> {
+                if Self::accepts(packet.descriptor().direction) {
+                    Ok(Self(packet))
+                } else {
+                    Err(packet)
+                }
+            }
+
+            pub fn into_inner(self) -> AnyPacket {
+                self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = AnyPacket;
+
+            fn deref(&self) -> &AnyPacket {
+                &self.0
+            }
+        }
+
+        impl TryFrom<AnyPacket> for $name {
+            type Error = AnyPacket;
+
+            fn try_from(packet: AnyPacket) -> Result<Self, AnyPacket> {
+                Self::new(packet)
+            }
+        }
+
+        impl From<$name> for AnyPacket {
+            fn from(packet: $name) -> Self {
+                packet.0
+            }
+        }
+
+        impl DirectedPacket for $name {
+            const DIRECTION: PacketDirection = PacketDirection::$direction;
+
+            fn accepts(direction: PacketDirection) -> bool {
+                direction.$is_direction()
+            }
+        }
+    };
+}
+
+define_direction_view! {
+    /// An [`AnyPacket`] which servers may send to clients.
+    ClientboundPacket, Clientbound, is_clientbound
 }
 
-impl fmt::Display for PacketDescriptor {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}({})", self.name, self.id)
-    }
+define_direction_view! {
+    /// An [`AnyPacket`] which clients may send to servers.
+    ServerboundPacket, Serverbound, is_serverbound
 }
 
 macro_rules! define_packets {
@@ -57,42 +105,139 @@ macro_rules! define_packets {
         $($name:ident),*$(,)?
     ) => {
         #[derive(Debug, Clone)]
+        #[cfg_attr(feature = "serde-interop", derive(::serde::Serialize, ::serde::Deserialize))]
         pub enum AnyPacket {
             $($name (Box<$name>),)*
         }
 
+        pub static PROTOCOL: Protocol<AnyPacket> = Protocol {
+            module_path: module_path!(),
+            version: PROTOCOL_VERSION,
+            packets: &[$(<self::$name as Packet>::DESCRIPTOR,)*],
+            table: PACKET_TABLE,
+        };
+
+        const PACKET_ENTRIES: &[PacketEntry<AnyPacket>] = &[$(
+            PacketEntry {
+                descriptor: <self::$name as Packet>::DESCRIPTOR,
+                decode: |contents, limits| {
+                    <self::$name as Serde>::decode_with_limits(contents, limits).map(Into::into)
+                },
+                decode_static: |contents, limits| {
+                    <self::$name as StaticSerde>::decode_static_with_limits(contents, limits)
+                        .map(Into::into)
+                },
+                from_dynamic: |value| {
+                    <self::$name as Serde>::codec().from_dynamic(value).map(Into::into)
+                },
+            },
+        )*];
+
+        const PACKET_TABLE: &PacketTable<AnyPacket> =
+            &build_packet_table::<_, { packet_table_len(PACKET_ENTRIES) }>(PACKET_ENTRIES);
+
         impl AnyPacket {
             pub const fn descriptor_for(id: u32) -> Option<&'static PacketDescriptor> {
-                $(
-                    if id == <self::$name as Packet>::DESCRIPTOR.id {
-                        return Some(<self::$name as Packet>::DESCRIPTOR);
-                    }
-                )*
+                match packet_table_entry(PACKET_TABLE, id) {
+                    Some(entry) => Some(entry.descriptor),
+                    None => None,
+                }
+            }
+
+            pub fn decode(id: u32, contents: Bytes) -> DecodeResult<Self> {
+                Self::decode_with_limits(id, contents, &mut DecodeLimits::unlimited())
+            }
+
+            pub fn decode_with_limits(
+                id: u32,
+                contents: Bytes,
+                limits: &mut DecodeLimits,
+            ) -> DecodeResult<Self> {
+                if let Some(entry) = packet_table_entry(PACKET_TABLE, id) {
+                    return (entry.decode)(contents, limits);
+                }
+
+                Err(DecodeError::at(DecodeErrorKind::UnknownPacket(id), contents.len())
+                    .resolve::<Self>(contents.len()))
+            }
+
+            pub fn decode_static(id: u32, contents: Bytes) -> DecodeResult<Self> {
+                Self::decode_static_with_limits(id, contents, &mut DecodeLimits::unlimited())
+            }
 
-                None
+            pub fn decode_static_with_limits(
+                id: u32,
+                contents: Bytes,
+                limits: &mut DecodeLimits,
+            ) -> DecodeResult<Self> {
+                if let Some(entry) = packet_table_entry(PACKET_TABLE, id) {
+                    return (entry.decode_static)(contents, limits);
+                }
+
+                Err(DecodeError::at(DecodeErrorKind::UnknownPacket(id), contents.len())
+                    .resolve::<Self>(contents.len()))
             }
 
-            pub fn decode(id: u32, contents: Bytes) -> anyhow::Result<Self> {
-                $(
-                    if id == <self::$name as Packet>::DESCRIPTOR.id {
-                        return <self::$name as Serde>::decode(contents).map(Into::into);
-                    }
-                )*
+            pub fn to_dynamic(&self) -> anyhow::Result<DynamicValue> {
+                match self {
+                    $(Self::$name(v) => <self::$name as Serde>::codec().to_dynamic(v),)*
+                }
+            }
+
+            pub fn from_dynamic(id: u32, value: &DynamicValue) -> anyhow::Result<Self> {
+                if let Some(entry) = packet_table_entry(PACKET_TABLE, id) {
+                    return (entry.from_dynamic)(value);
+                }
 
                 anyhow::bail!("unknown packet id {id:?}")
             }
 
+            pub fn schemas() -> Vec<(&'static PacketDescriptor, Schema)> {
+                vec![$((<self::$name as Packet>::DESCRIPTOR, <self::$name as Serde>::schema()),)*]
+            }
+
             pub fn descriptor(&self) -> &'static PacketDescriptor {
                 match self {
                     $(Self::$name(_) => <self::$name as Packet>::DESCRIPTOR,)*
                 }
             }
 
+            /// Extracts the packet if it is a `P`, giving it back otherwise.
+            pub fn downcast<P: Packet + 'static>(self) -> Result<Box<P>, Self> {
+                match self {
+                    $(Self::$name(v) => (v as Box<dyn Any>)
+                        .downcast::<P>()
+                        .map_err(|v| Self::$name(v.downcast().unwrap())),)*
+                }
+            }
+
             pub fn encode(&self, target: &mut BytesMut) -> anyhow::Result<()> {
                 match self {
                     $(Self::$name(v) => Serde::encode(v, target),)*
                 }
             }
+
+            pub fn encode_static(&self, target: &mut BytesMut) -> anyhow::Result<()> {
+                match self {
+                    $(Self::$name(v) => StaticSerde::encode_static(v, target),)*
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                match self {
+                    $(Self::$name(v) => <self::$name as StaticSerde>::static_codec().encoded_len(v),)*
+                }
+            }
+        }
+
+        impl ProtocolPacket for AnyPacket {
+            fn descriptor(&self) -> &'static PacketDescriptor {
+                AnyPacket::descriptor(self)
+            }
+
+            fn encode_static(&self, target: &mut BytesMut) -> anyhow::Result<()> {
+                AnyPacket::encode_static(self, target)
+            }
         }
 
         $(
@@ -6,7 +6,7 @@
 use anyhow::Context as _;
 use bytes::{Bytes, BytesMut};
 
-use crate::packets::AnyPacket;
+use super::AnyPacket;
 
 #[track_caller]
 fn check_round_trip(id: u32, data: &'static [u8]) {
@@ -31,6 +31,31 @@ fn check_round_trip_fallible(id: u32, data: &'static [u8]) -> anyhow::Result<()>
         .with_context(|| format!("failed to encode {}", descriptor.name))?;
 
     assert_eq!(&target[..], data, "failed to round-trip {}\nDecoded: {:#?}", descriptor.name, decoded);
+    assert_eq!(decoded.encoded_len(), data.len(), "mispredicted encoded length of {}", descriptor.name);
+
+    let decoded_static = AnyPacket::decode_static(id, Bytes::from_static(data))
+        .with_context(|| format!("failed to statically decode {}", descriptor.name))?;
+
+    let mut target_static = BytesMut::new();
+    decoded_static
+        .encode_static(&mut target_static)
+        .with_context(|| format!("failed to statically encode {}", descriptor.name))?;
+
+    assert_eq!(&target_static[..], data, "failed to statically round-trip {}\nDecoded: {:#?}", descriptor.name, decoded_static);
+
+    let dynamic = decoded
+        .to_dynamic()
+        .with_context(|| format!("failed to convert {} to a dynamic value", descriptor.name))?;
+
+    let decoded_dynamic = AnyPacket::from_dynamic(id, &dynamic)
+        .with_context(|| format!("failed to convert {} from a dynamic value", descriptor.name))?;
+
+    let mut target_dynamic = BytesMut::new();
+    decoded_dynamic
+        .encode(&mut target_dynamic)
+        .with_context(|| format!("failed to encode dynamically converted {}", descriptor.name))?;
+
+    assert_eq!(&target_dynamic[..], data, "failed to dynamically round-trip {}\nDynamic: {:#?}", descriptor.name, dynamic);
 
     Ok(())
 }
@@ -4,7 +4,13 @@ set -euo pipefail
 cd "$(dirname "$0")"
 
 HYTALE_JAR="$HOME/Library/Application Support/Hytale/install/release/package/game/latest/Server/HytaleServer.jar"
-OUT_DIR="$(realpath ../customtale-protocol/src/generated)"
+
+# The module to generate the packet table into. New versions must also be mounted in
+# `customtale-protocol/src/packets.rs`.
+VERSION="${1:-v1}"
+
+mkdir -p "../customtale-protocol/src/generated/$VERSION"
+OUT_DIR="$(realpath "../customtale-protocol/src/generated/$VERSION")"
 
 ./gradlew run --args \
     "\"$HYTALE_JAR\" \"$OUT_DIR\""\
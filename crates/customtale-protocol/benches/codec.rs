@@ -0,0 +1,177 @@
+use std::hint::black_box;
+
+use bytes::{Bytes, BytesMut};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use customtale_protocol::{
+    packets::{
+        ClientMovement, ComponentUpdate, ComponentUpdateType, Direction, EntityUpdate,
+        EntityUpdates, HalfFloatPosition, ModelTransform, MovementStates, Nameplate, Packet,
+        Position, SetChunk, TeleportAck, Vector3d,
+    },
+    serde::Serde,
+};
+use half::f16;
+
+// === Samples === //
+
+fn entity_updates() -> EntityUpdates {
+    EntityUpdates {
+        removed: Some((0..16).collect()),
+        updates: Some(
+            (0..64)
+                .map(|id| EntityUpdate {
+                    networkId: id,
+                    removed: None,
+                    updates: Some(vec![
+                        ComponentUpdate {
+                            r#type: ComponentUpdateType::Transform,
+                            transform: Some(ModelTransform {
+                                position: Some(Position {
+                                    x: id as f64,
+                                    y: 64.0,
+                                    z: -(id as f64),
+                                }),
+                                bodyOrientation: Some(Direction {
+                                    yaw: 1.5,
+                                    pitch: 0.0,
+                                    roll: 0.0,
+                                }),
+                                lookOrientation: Some(Direction {
+                                    yaw: 1.5,
+                                    pitch: -0.25,
+                                    roll: 0.0,
+                                }),
+                            }),
+                            ..Default::default()
+                        },
+                        ComponentUpdate {
+                            r#type: ComponentUpdateType::Nameplate,
+                            nameplate: Some(Nameplate {
+                                text: Some(format!("Entity #{id}").into()),
+                            }),
+                            ..Default::default()
+                        },
+                    ]),
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn client_movement() -> ClientMovement {
+    ClientMovement {
+        movementStates: Some(MovementStates {
+            walking: true,
+            onGround: true,
+            ..Default::default()
+        }),
+        relativePosition: Some(HalfFloatPosition {
+            x: f16::from_f32(0.25),
+            y: f16::from_f32(-0.5),
+            z: f16::from_f32(1.0),
+        }),
+        absolutePosition: Some(Position {
+            x: 100.5,
+            y: 64.0,
+            z: -20.25,
+        }),
+        bodyOrientation: Some(Direction {
+            yaw: 0.5,
+            pitch: 0.0,
+            roll: 0.0,
+        }),
+        lookOrientation: Some(Direction {
+            yaw: 0.5,
+            pitch: 0.1,
+            roll: 0.0,
+        }),
+        teleportAck: Some(TeleportAck { teleportId: 3 }),
+        wishMovement: None,
+        velocity: Some(Vector3d {
+            x: 0.1,
+            y: 0.0,
+            z: 0.2,
+        }),
+        mountedTo: 0,
+        riderMovementStates: None,
+    }
+}
+
+fn set_chunk() -> SetChunk {
+    SetChunk {
+        x: 4,
+        y: 2,
+        z: 7,
+        localLight: Some(Bytes::from(vec![0x0F; 4096])),
+        globalLight: Some(Bytes::from(vec![0xF0; 4096])),
+        data: Some((0..32768).map(|i| (i * 31 % 251) as u8).collect()),
+    }
+}
+
+// === Benchmarks === //
+
+fn bench_packet<P: Packet>(c: &mut Criterion, packet: P) {
+    let name = P::DESCRIPTOR.name;
+
+    let mut dynamic = BytesMut::new();
+    Serde::encode(&packet, &mut dynamic).unwrap();
+
+    let mut static_ = BytesMut::new();
+    packet.encode_static(&mut static_).unwrap();
+
+    assert_eq!(
+        dynamic, static_,
+        "dynamic and static encodings of {name} differ"
+    );
+
+    let encoded = dynamic.freeze();
+
+    let mut redecoded = BytesMut::new();
+    P::decode_static(encoded.clone())
+        .unwrap()
+        .encode_static(&mut redecoded)
+        .unwrap();
+
+    assert_eq!(
+        encoded, redecoded,
+        "static decoding of {name} did not round-trip"
+    );
+
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Bytes(encoded.len() as u64));
+
+    group.bench_function(BenchmarkId::new("encode", "dynamic"), |b| {
+        let mut buf = BytesMut::with_capacity(encoded.len());
+        b.iter(|| {
+            buf.clear();
+            Serde::encode(black_box(&packet), &mut buf).unwrap();
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("encode", "static"), |b| {
+        let mut buf = BytesMut::with_capacity(encoded.len());
+        b.iter(|| {
+            buf.clear();
+            black_box(&packet).encode_static(&mut buf).unwrap();
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("decode", "dynamic"), |b| {
+        b.iter(|| <P as Serde>::decode(black_box(Bytes::clone(&encoded))).unwrap())
+    });
+
+    group.bench_function(BenchmarkId::new("decode", "static"), |b| {
+        b.iter(|| P::decode_static(black_box(Bytes::clone(&encoded))).unwrap())
+    });
+
+    group.finish();
+}
+
+fn codec(c: &mut Criterion) {
+    bench_packet(c, entity_updates());
+    bench_packet(c, client_movement());
+    bench_packet(c, set_chunk());
+}
+
+criterion_group!(benches, codec);
+criterion_main!(benches);
@@ -0,0 +1,85 @@
+use std::hint::black_box;
+
+use bytes::Bytes;
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use customtale_protocol::packets::{AnyPacket, PROTOCOL};
+
+// === Samples === //
+
+/// The longest all-zero body tried when looking for a body which decodes successfully.
+const MAX_ZERO_BODY: usize = 256;
+
+/// Finds the shortest all-zero body each packet decodes from. An all-zero body leaves every
+/// nullable field unset and every variable-length field empty, so this yields a valid body for
+/// every packet whose fixed block fits in [`MAX_ZERO_BODY`].
+fn zero_bodies() -> Vec<(u32, Bytes)> {
+    PROTOCOL
+        .packets
+        .iter()
+        .filter_map(|descriptor| {
+            (0..=MAX_ZERO_BODY)
+                .map(|len| Bytes::from(vec![0; len]))
+                .find(|body| AnyPacket::decode_static(descriptor.id, body.clone()).is_ok())
+                .map(|body| (descriptor.id, body))
+        })
+        .collect()
+}
+
+// === Benchmarks === //
+
+fn dispatch(c: &mut Criterion) {
+    let ids = PROTOCOL
+        .packets
+        .iter()
+        .map(|descriptor| descriptor.id)
+        .collect::<Vec<_>>();
+
+    let max_id = ids.iter().copied().max().unwrap_or(0);
+    let unknown_ids = (0..=max_id + 16)
+        .filter(|id| !ids.contains(id))
+        .collect::<Vec<_>>();
+
+    let bodies = zero_bodies();
+
+    let mut group = c.benchmark_group("dispatch");
+
+    group.throughput(Throughput::Elements(ids.len() as u64));
+    group.bench_function(BenchmarkId::new("descriptor_for", "known"), |b| {
+        b.iter(|| {
+            for &id in &ids {
+                black_box(AnyPacket::descriptor_for(black_box(id)));
+            }
+        })
+    });
+
+    group.throughput(Throughput::Elements(unknown_ids.len() as u64));
+    group.bench_function(BenchmarkId::new("descriptor_for", "unknown"), |b| {
+        b.iter(|| {
+            for &id in &unknown_ids {
+                black_box(AnyPacket::descriptor_for(black_box(id)));
+            }
+        })
+    });
+
+    group.throughput(Throughput::Elements(bodies.len() as u64));
+    group.bench_function(BenchmarkId::new("decode", "static"), |b| {
+        b.iter(|| {
+            for (id, body) in &bodies {
+                black_box(AnyPacket::decode_static(black_box(*id), body.clone()).unwrap());
+            }
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("decode", "dynamic"), |b| {
+        b.iter(|| {
+            for (id, body) in &bodies {
+                black_box(AnyPacket::decode(black_box(*id), body.clone()).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, dispatch);
+criterion_main!(benches);
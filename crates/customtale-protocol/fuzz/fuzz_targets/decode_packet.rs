@@ -0,0 +1,40 @@
+#![no_main]
+
+//! Feeds arbitrary payloads to the packet decoders. The first four bytes pick the packet ID and the
+//! rest is the packet body.
+
+use bytes::{Bytes, BytesMut};
+use customtale_protocol::packets::AnyPacket;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((id, contents)) = data.split_first_chunk::<4>() else {
+        return;
+    };
+
+    let id = u32::from_le_bytes(*id);
+    let contents = Bytes::copy_from_slice(contents);
+
+    let erased = AnyPacket::decode(id, contents.clone());
+    let fixed = AnyPacket::decode_static(id, contents);
+
+    // Both decoding paths must agree on whether the input is valid.
+    assert_eq!(erased.is_ok(), fixed.is_ok());
+
+    let Ok(packet) = erased else {
+        return;
+    };
+
+    // Whatever decodes must encode again, and to exactly the size it claims.
+    let mut encoded = BytesMut::new();
+    packet.encode(&mut encoded).unwrap();
+    assert_eq!(packet.encoded_len(), encoded.len());
+
+    let mut reencoded = BytesMut::new();
+    AnyPacket::decode(id, encoded.clone().freeze())
+        .unwrap()
+        .encode(&mut reencoded)
+        .unwrap();
+
+    assert_eq!(encoded, reencoded);
+});
@@ -0,0 +1,37 @@
+#![no_main]
+
+//! Feeds arbitrary bytes to the framed stream decoder, delivering them in chunks so that partial
+//! headers and partially-received compressed payloads are exercised too. The first byte picks the
+//! chunk size.
+
+use bytes::BytesMut;
+use customtale_protocol::{
+    framed::HytaleDecoder,
+    packets::{PacketCategory, ServerboundPacket},
+};
+use libfuzzer_sys::fuzz_target;
+use tokio_util::codec::Decoder;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&chunk_size, data)) = data.split_first() else {
+        return;
+    };
+
+    let mut decoder = HytaleDecoder::<ServerboundPacket>::new(PacketCategory::all());
+    let mut buf = BytesMut::new();
+
+    for chunk in data.chunks(usize::from(chunk_size).max(1)) {
+        buf.extend_from_slice(chunk);
+
+        loop {
+            match decoder.decode(&mut buf) {
+                Ok(Some(_packet)) => {}
+                Ok(None) => break,
+                // The connection would be dropped here.
+                Err(_) => return,
+            }
+        }
+    }
+
+    let _ = decoder.decode_eof(&mut buf);
+});
@@ -0,0 +1,282 @@
+//! A compact on-disk format for recording the frames sent over a connection.
+//!
+//! A capture starts with [`MAGIC`] and a little-endian `u32` format version, followed by one
+//! record per frame:
+//!
+//! | Field     | Encoding                                               |
+//! |-----------|--------------------------------------------------------|
+//! | timestamp | `u64` LE, microseconds since the UNIX epoch            |
+//! | direction | `u8`, `0` for serverbound and `1` for clientbound      |
+//! | packet ID | `u32` LE                                               |
+//! | length    | `u32` LE, the length of the frame                      |
+//! | frame     | the frame exactly as it was sent, header included      |
+//!
+//! Frames are recorded by attaching a [`CaptureTap`] to a [`HytaleEncoder`] or [`HytaleDecoder`].
+//! Decoders record frames before decoding them so that malformed packets are captured as well.
+//!
+//! [`HytaleEncoder`]: crate::framed::HytaleEncoder
+
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bytes::{Bytes, BytesMut};
+use thiserror::Error;
+use tokio_util::codec::Decoder;
+
+use crate::{
+    framed::{HytaleDecodeError, HytaleDecoder},
+    packets::{
+        AnyPacket, ClientboundPacket, PacketCategory, PacketDescriptor, PacketDirection,
+        ServerboundPacket,
+    },
+};
+
+/// The bytes every capture starts with.
+pub const MAGIC: [u8; 8] = *b"CTALECAP";
+
+/// The version of the format written by [`CaptureWriter`].
+pub const VERSION: u32 = 1;
+
+#[derive(Debug, Error)]
+pub enum CaptureError {
+    #[error("an underlying IO error occurred")]
+    Io(#[from] io::Error),
+    #[error("the file is not a packet capture")]
+    BadMagic,
+    #[error("unsupported capture format version {0}")]
+    UnsupportedVersion(u32),
+    #[error("record has unknown direction {0}")]
+    BadDirection(u8),
+}
+
+// === CaptureRecord === //
+
+/// A single frame read from a capture.
+#[derive(Debug, Clone)]
+pub struct CaptureRecord {
+    pub timestamp: SystemTime,
+    /// Either [`PacketDirection::Serverbound`] or [`PacketDirection::Clientbound`].
+    pub direction: PacketDirection,
+    pub id: u32,
+    /// The frame exactly as it was sent, header included.
+    pub frame: Bytes,
+}
+
+impl CaptureRecord {
+    /// The descriptor of the recorded packet, or `None` if its ID is unknown to this build.
+    pub fn descriptor(&self) -> Option<&'static PacketDescriptor> {
+        AnyPacket::descriptor_for(self.id)
+    }
+
+    /// Decodes the recorded frame as the peer receiving it would, ignoring category filters.
+    pub fn decode(&self) -> Result<AnyPacket, HytaleDecodeError> {
+        let mut src = BytesMut::from(&self.frame[..]);
+
+        let packet = match self.direction {
+            PacketDirection::Clientbound => {
+                HytaleDecoder::<ClientboundPacket>::new(PacketCategory::all())
+                    .decode(&mut src)?
+                    .map(ClientboundPacket::into_inner)
+            }
+            _ => HytaleDecoder::<ServerboundPacket>::new(PacketCategory::all())
+                .decode(&mut src)?
+                .map(ServerboundPacket::into_inner),
+        };
+
+        packet.ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof).into())
+    }
+}
+
+fn encode_direction(direction: PacketDirection) -> u8 {
+    match direction {
+        PacketDirection::Serverbound => 0,
+        PacketDirection::Clientbound => 1,
+        PacketDirection::Both => unreachable!("frames are always sent in a single direction"),
+    }
+}
+
+fn decode_direction(direction: u8) -> Result<PacketDirection, CaptureError> {
+    match direction {
+        0 => Ok(PacketDirection::Serverbound),
+        1 => Ok(PacketDirection::Clientbound),
+        _ => Err(CaptureError::BadDirection(direction)),
+    }
+}
+
+// === CaptureWriter === //
+
+#[derive(Debug)]
+pub struct CaptureWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> CaptureWriter<W> {
+    /// Starts a capture by writing its header to `inner`.
+    pub fn new(mut inner: W) -> io::Result<Self> {
+        inner.write_all(&MAGIC)?;
+        inner.write_all(&VERSION.to_le_bytes())?;
+
+        Ok(Self { inner })
+    }
+
+    pub fn write(
+        &mut self,
+        timestamp: SystemTime,
+        direction: PacketDirection,
+        id: u32,
+        frame: &[u8],
+    ) -> io::Result<()> {
+        let micros = timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_micros() as u64;
+
+        let mut header = [0; 17];
+        header[0..8].copy_from_slice(&micros.to_le_bytes());
+        header[8] = encode_direction(direction);
+        header[9..13].copy_from_slice(&id.to_le_bytes());
+        header[13..17].copy_from_slice(&(frame.len() as u32).to_le_bytes());
+
+        self.inner.write_all(&header)?;
+        self.inner.write_all(frame)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+// === CaptureReader === //
+
+#[derive(Debug)]
+pub struct CaptureReader<R: Read> {
+    inner: R,
+}
+
+impl CaptureReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, CaptureError> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> CaptureReader<R> {
+    /// Reads and validates the capture's header from `inner`.
+    pub fn new(mut inner: R) -> Result<Self, CaptureError> {
+        let mut magic = [0; MAGIC.len()];
+        inner
+            .read_exact(&mut magic)
+            .map_err(|err| match err.kind() {
+                io::ErrorKind::UnexpectedEof => CaptureError::BadMagic,
+                _ => CaptureError::Io(err),
+            })?;
+
+        if magic != MAGIC {
+            return Err(CaptureError::BadMagic);
+        }
+
+        let mut version = [0; 4];
+        inner.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+
+        if version != VERSION {
+            return Err(CaptureError::UnsupportedVersion(version));
+        }
+
+        Ok(Self { inner })
+    }
+
+    /// Reads the next record, returning `None` once the capture ends cleanly.
+    pub fn read(&mut self) -> Result<Option<CaptureRecord>, CaptureError> {
+        let mut header = [0; 17];
+
+        // Only a capture which ends between records ends cleanly.
+        let mut read = 0;
+        while read < header.len() {
+            match self.inner.read(&mut header[read..]) {
+                Ok(0) if read == 0 => return Ok(None),
+                Ok(0) => return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into()),
+                Ok(n) => read += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        let micros = u64::from_le_bytes(header[0..8].try_into().unwrap());
+        let direction = decode_direction(header[8])?;
+        let id = u32::from_le_bytes(header[9..13].try_into().unwrap());
+        let len = u32::from_le_bytes(header[13..17].try_into().unwrap());
+
+        let mut frame = vec![0; len as usize];
+        self.inner.read_exact(&mut frame)?;
+
+        Ok(Some(CaptureRecord {
+            timestamp: UNIX_EPOCH + Duration::from_micros(micros),
+            direction,
+            id,
+            frame: frame.into(),
+        }))
+    }
+}
+
+impl<R: Read> Iterator for CaptureReader<R> {
+    type Item = Result<CaptureRecord, CaptureError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+// === CaptureTap === //
+
+type SharedWriter = Arc<Mutex<CaptureWriter<Box<dyn Write + Send>>>>;
+
+/// A handle through which codecs record the frames they handle into a shared capture.
+///
+/// Clones write to the same capture, so the encoder and decoder of a connection can share one.
+#[derive(Clone)]
+pub struct CaptureTap {
+    writer: SharedWriter,
+}
+
+impl fmt::Debug for CaptureTap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CaptureTap").finish_non_exhaustive()
+    }
+}
+
+impl CaptureTap {
+    pub fn new(writer: impl Write + Send + 'static) -> io::Result<Self> {
+        let writer: Box<dyn Write + Send> = Box::new(writer);
+
+        Ok(Self {
+            writer: Arc::new(Mutex::new(CaptureWriter::new(writer)?)),
+        })
+    }
+
+    /// Creates a buffered capture file at `path`, replacing any existing file.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+
+    /// Records `frame`, timestamped with the current time.
+    pub fn record(&self, direction: PacketDirection, id: u32, frame: &[u8]) -> io::Result<()> {
+        self.writer
+            .lock()
+            .unwrap()
+            .write(SystemTime::now(), direction, id, frame)
+    }
+
+    pub fn flush(&self) -> io::Result<()> {
+        self.writer.lock().unwrap().flush()
+    }
+}
@@ -0,0 +1,300 @@
+//! How [`HytaleEncoder`]s compress the payloads of compressed packets.
+//!
+//! Compressed packets must always carry a zstd frame, but the frame need not actually be
+//! compressed. Payloads below a packet's [`CompressionSettings::min_size`] are written as a frame
+//! of raw blocks, which any zstd decoder accepts but which costs next to nothing to produce.
+//!
+//! Dictionaries trained on typical payloads can shrink small, repetitive packets considerably, but
+//! the peer must have been given the same dictionary out of band. The vanilla client has no way of
+//! receiving one, so dictionaries are only useful between endpoints built from this crate.
+//!
+//! [`HytaleEncoder`]: crate::framed::HytaleEncoder
+
+use std::{fmt, sync::Arc};
+
+use bytes::{BufMut, BytesMut};
+use rustc_hash::FxHashMap;
+
+use crate::packets::PacketDescriptor;
+
+// === CompressionDictionary === //
+
+/// A zstd dictionary shared by both ends of a connection.
+pub struct CompressionDictionary {
+    bytes: Vec<u8>,
+    ddict: zstd_safe::DDict<'static>,
+}
+
+impl fmt::Debug for CompressionDictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompressionDictionary")
+            .field("len", &self.bytes.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl CompressionDictionary {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        let ddict = zstd_safe::DDict::create(&bytes);
+
+        Self { bytes, ddict }
+    }
+
+    /// Trains a dictionary of at most `max_len` bytes from sample payloads.
+    pub fn train<S: AsRef<[u8]>>(
+        samples: &[S],
+        max_len: usize,
+    ) -> Result<Self, zstd_safe::ErrorCode> {
+        let sizes = samples
+            .iter()
+            .map(|sample| sample.as_ref().len())
+            .collect::<Vec<_>>();
+
+        let concatenated = samples
+            .iter()
+            .flat_map(|sample| sample.as_ref())
+            .copied()
+            .collect::<Vec<_>>();
+
+        let mut bytes = Vec::with_capacity(max_len);
+        zstd_safe::train_from_buffer(&mut bytes, &concatenated, &sizes)?;
+
+        Ok(Self::new(bytes))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub(crate) fn ddict(&self) -> &zstd_safe::DDict<'static> {
+        &self.ddict
+    }
+}
+
+// === CompressionPolicy === //
+
+/// How the payloads of one packet type are compressed.
+#[derive(Debug, Clone)]
+pub struct CompressionSettings {
+    /// The zstd compression level.
+    pub level: i32,
+    /// Payloads shorter than this many bytes are stored in raw blocks rather than compressed.
+    pub min_size: usize,
+    /// The dictionary to compress with. Both ends must agree on it.
+    pub dictionary: Option<Arc<CompressionDictionary>>,
+}
+
+impl Default for CompressionSettings {
+    fn default() -> Self {
+        Self {
+            level: zstd_safe::CLEVEL_DEFAULT,
+            min_size: 0,
+            dictionary: None,
+        }
+    }
+}
+
+/// Per-packet-type [`CompressionSettings`], falling back to a default for unlisted packets.
+///
+/// Only the dictionaries matter when decoding.
+#[derive(Debug, Clone, Default)]
+pub struct CompressionPolicy {
+    default: CompressionSettings,
+    overrides: FxHashMap<u32, CompressionSettings>,
+}
+
+impl CompressionPolicy {
+    pub fn new(default: CompressionSettings) -> Self {
+        Self {
+            default,
+            overrides: FxHashMap::default(),
+        }
+    }
+
+    pub fn set(
+        &mut self,
+        descriptor: &PacketDescriptor,
+        settings: CompressionSettings,
+    ) -> &mut Self {
+        self.overrides.insert(descriptor.id, settings);
+        self
+    }
+
+    pub fn settings_for(&self, descriptor: &PacketDescriptor) -> &CompressionSettings {
+        self.overrides.get(&descriptor.id).unwrap_or(&self.default)
+    }
+}
+
+// === CompressionStats === //
+
+/// Totals for the compressed packets of one type sent by an encoder.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PacketCompressionStats {
+    pub packets: u64,
+    pub uncompressed_bytes: u64,
+    pub compressed_bytes: u64,
+}
+
+impl PacketCompressionStats {
+    /// The number of bytes compression saved, which is negative if it cost more than it saved.
+    pub fn bytes_saved(&self) -> i64 {
+        self.uncompressed_bytes as i64 - self.compressed_bytes as i64
+    }
+}
+
+/// [`PacketCompressionStats`] for every compressed packet type an encoder has sent.
+///
+/// Entries keep the descriptor they were recorded with so that they are labelled by the protocol
+/// version the encoder used rather than whichever table happens to be the newest.
+#[derive(Debug, Clone, Default)]
+pub struct CompressionStats {
+    packets: FxHashMap<u32, (&'static PacketDescriptor, PacketCompressionStats)>,
+}
+
+impl CompressionStats {
+    pub(crate) fn record(
+        &mut self,
+        descriptor: &'static PacketDescriptor,
+        uncompressed: usize,
+        compressed: usize,
+    ) {
+        let (_, stats) = self
+            .packets
+            .entry(descriptor.id)
+            .or_insert((descriptor, PacketCompressionStats::default()));
+        stats.packets += 1;
+        stats.uncompressed_bytes += uncompressed as u64;
+        stats.compressed_bytes += compressed as u64;
+    }
+
+    pub fn get(&self, descriptor: &PacketDescriptor) -> Option<&PacketCompressionStats> {
+        self.packets.get(&descriptor.id).map(|(_, stats)| stats)
+    }
+
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static PacketDescriptor, &PacketCompressionStats)> + '_ {
+        self.packets
+            .values()
+            .map(|(descriptor, stats)| (*descriptor, stats))
+    }
+
+    /// Adds the totals of `other`, e.g. to combine the encoders of several streams.
+    pub fn merge(&mut self, other: &CompressionStats) {
+        for (&id, &(descriptor, ref stats)) in &other.packets {
+            let (_, total) = self
+                .packets
+                .entry(id)
+                .or_insert((descriptor, PacketCompressionStats::default()));
+            total.packets += stats.packets;
+            total.uncompressed_bytes += stats.uncompressed_bytes;
+            total.compressed_bytes += stats.compressed_bytes;
+        }
+    }
+
+    pub fn total(&self) -> PacketCompressionStats {
+        self.packets.values().map(|(_, stats)| stats).fold(
+            PacketCompressionStats::default(),
+            |total, stats| PacketCompressionStats {
+                packets: total.packets + stats.packets,
+                uncompressed_bytes: total.uncompressed_bytes + stats.uncompressed_bytes,
+                compressed_bytes: total.compressed_bytes + stats.compressed_bytes,
+            },
+        )
+    }
+}
+
+// === Compressor === //
+
+/// The reusable zstd state behind an encoder.
+#[derive(Default)]
+pub(crate) struct Compressor {
+    cctx: Option<zstd_safe::CCtx<'static>>,
+    /// Dictionaries digested for a given compression level. The `Arc`s are kept so that the
+    /// dictionaries outlive the context referencing them.
+    cdicts: Vec<(Arc<CompressionDictionary>, i32, zstd_safe::CDict<'static>)>,
+}
+
+impl fmt::Debug for Compressor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Compressor")
+            .field("cdicts", &self.cdicts.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Clone for Compressor {
+    fn clone(&self) -> Self {
+        // Contexts cannot be shared and are cheap enough to recreate.
+        Self::default()
+    }
+}
+
+/// The largest block a raw zstd frame may contain.
+const MAX_BLOCK_SIZE: usize = 128 * 1024;
+
+impl Compressor {
+    /// Appends a zstd frame holding `src` to `dst`.
+    pub(crate) fn compress(
+        &mut self,
+        settings: &CompressionSettings,
+        src: &[u8],
+        dst: &mut BytesMut,
+    ) -> Result<(), zstd_safe::ErrorCode> {
+        if src.len() < settings.min_size {
+            write_raw_frame(src, dst);
+            return Ok(());
+        }
+
+        let cctx = self.cctx.get_or_insert_with(zstd_safe::CCtx::create);
+
+        cctx.reset(zstd_safe::ResetDirective::SessionAndParameters)?;
+        cctx.set_parameter(zstd_safe::CParameter::CompressionLevel(settings.level))?;
+
+        if let Some(dictionary) = &settings.dictionary {
+            let index = match self.cdicts.iter().position(|(cached, level, _)| {
+                Arc::ptr_eq(cached, dictionary) && *level == settings.level
+            }) {
+                Some(index) => index,
+                None => {
+                    let cdict = zstd_safe::CDict::create(dictionary.as_bytes(), settings.level);
+                    self.cdicts
+                        .push((dictionary.clone(), settings.level, cdict));
+                    self.cdicts.len() - 1
+                }
+            };
+
+            cctx.ref_cdict(&self.cdicts[index].2)?;
+        }
+
+        let start = dst.len();
+        dst.put_bytes(0, zstd_safe::compress_bound(src.len()));
+
+        let len = cctx.compress2(&mut dst[start..], src)?;
+        dst.truncate(start + len);
+
+        Ok(())
+    }
+}
+
+/// Writes `src` as a single-segment zstd frame of raw blocks.
+fn write_raw_frame(src: &[u8], dst: &mut BytesMut) {
+    const MAGIC: u32 = 0xFD2F_B528;
+    // A four-byte content size in a single-segment frame without a checksum or dictionary.
+    const FRAME_HEADER_DESCRIPTOR: u8 = (2 << 6) | (1 << 5);
+
+    dst.extend_from_slice(&MAGIC.to_le_bytes());
+    dst.put_u8(FRAME_HEADER_DESCRIPTOR);
+    dst.extend_from_slice(&(src.len() as u32).to_le_bytes());
+
+    let mut chunks = src.chunks(MAX_BLOCK_SIZE).peekable();
+
+    while let Some(chunk) = chunks.next() {
+        let last = chunks.peek().is_none() as u32;
+        // Raw blocks have a block type of zero.
+        let header = last | ((chunk.len() as u32) << 3);
+
+        dst.extend_from_slice(&header.to_le_bytes()[..3]);
+        dst.extend_from_slice(chunk);
+    }
+}
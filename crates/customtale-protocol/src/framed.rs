@@ -0,0 +1,547 @@
+//! Length-prefixed packet framing for either end of a connection.
+//!
+//! The endpoint is chosen by the [`DirectedPacket`] type parameter of the codecs. Servers encode
+//! [`ClientboundPacket`]s and decode [`ServerboundPacket`]s, while clients do the reverse.
+//!
+//! [`ClientboundPacket`]: crate::packets::ClientboundPacket
+//! [`ServerboundPacket`]: crate::packets::ServerboundPacket
+
+use std::{fmt, io, marker::PhantomData, mem, sync::Arc};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use derive_where::derive_where;
+use thiserror::Error;
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{
+    capture::CaptureTap,
+    compression::{
+        CompressionDictionary, CompressionPolicy, CompressionSettings, CompressionStats, Compressor,
+    },
+    packets::{self, AnyPacket, DirectedPacket, PacketCategory, PacketDescriptor, Protocol},
+    serde::{DecodeError, DecodeErrorKind, DecodeLimits},
+};
+
+#[derive(Debug, Error)]
+pub enum HytaleEncodeError {
+    #[error("an underlying IO error occurred")]
+    Io(#[from] io::Error),
+    #[error(
+        "packet {descriptor} would be {len} byte{} long but only packets up to {} byte{} long are permitted",
+        if *len == 1 { "" } else { "s" },
+        descriptor.max_size,
+        if descriptor.max_size == 1 { "" } else { "s" },
+    )]
+    TooLong {
+        descriptor: &'static PacketDescriptor,
+        len: usize,
+    },
+    #[error(
+        "packet {descriptor} cannot be sent from this end of the connection since it is {:?}",
+        descriptor.direction,
+    )]
+    WrongDirection {
+        descriptor: &'static PacketDescriptor,
+    },
+    #[error("packet {descriptor} is not part of protocol {protocol}")]
+    NotInProtocol {
+        descriptor: &'static PacketDescriptor,
+        protocol: &'static Protocol<AnyPacket>,
+    },
+    #[error("compression failed with Zstd error {0}")]
+    Compress(usize),
+    #[error("encoding failed")]
+    Encode(#[from] anyhow::Error),
+}
+
+/// Encodes packets of direction `P`.
+#[derive_where(Debug, Clone)]
+pub struct HytaleEncoder<P> {
+    /// The packet table packets are encoded with, which defaults to the newest.
+    pub protocol: &'static Protocol<AnyPacket>,
+    /// Records every frame this encoder produces.
+    pub tap: Option<CaptureTap>,
+    pub compression: CompressionPolicy,
+    compressor: Compressor,
+    stats: CompressionStats,
+    _ty: PhantomData<fn(P) -> P>,
+}
+
+impl<P> Default for HytaleEncoder<P> {
+    fn default() -> Self {
+        Self {
+            protocol: &packets::PROTOCOL,
+            tap: None,
+            compression: CompressionPolicy::default(),
+            compressor: Compressor::default(),
+            stats: CompressionStats::default(),
+            _ty: PhantomData,
+        }
+    }
+}
+
+impl<P: DirectedPacket> HytaleEncoder<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How well the packets sent so far have compressed.
+    pub fn stats(&self) -> &CompressionStats {
+        &self.stats
+    }
+
+    /// An upper bound on the length of the frame `item` encodes to.
+    pub fn max_frame_len(item: &AnyPacket) -> usize {
+        let len = item.encoded_len();
+
+        match item.descriptor().is_compressed {
+            true => 8 + zstd_safe::compress_bound(len),
+            false => 8 + len,
+        }
+    }
+}
+
+impl<P: DirectedPacket> Encoder<AnyPacket> for HytaleEncoder<P> {
+    type Error = HytaleEncodeError;
+
+    fn encode(&mut self, item: AnyPacket, dst: &mut bytes::BytesMut) -> Result<(), Self::Error> {
+        let descriptor = item.descriptor();
+
+        if !P::accepts(descriptor.direction) {
+            return Err(HytaleEncodeError::WrongDirection { descriptor });
+        }
+
+        if !self.protocol.contains(descriptor) {
+            return Err(HytaleEncodeError::NotInProtocol {
+                descriptor,
+                protocol: self.protocol,
+            });
+        }
+
+        let len = item.encoded_len();
+
+        // The peer checks the decompressed size against the same limit so an oversized packet
+        // would only get us disconnected.
+        if len > descriptor.max_size as usize {
+            return Err(HytaleEncodeError::TooLong { descriptor, len });
+        }
+
+        let header_len_offset = dst.len();
+
+        let settings = self.compression.settings_for(descriptor);
+
+        if let Err(err) = encode_frame(
+            self.protocol,
+            &item,
+            len,
+            settings,
+            &mut self.compressor,
+            dst,
+        ) {
+            dst.truncate(header_len_offset);
+            return Err(err);
+        }
+
+        if let Some(tap) = &self.tap
+            && let Err(err) = tap.record(P::DIRECTION, descriptor.id, &dst[header_len_offset..])
+        {
+            dst.truncate(header_len_offset);
+            return Err(err.into());
+        }
+
+        if descriptor.is_compressed {
+            let frame_len = dst.len() - header_len_offset;
+            self.stats.record(descriptor, len, frame_len - 8);
+        }
+
+        Ok(())
+    }
+}
+
+fn encode_frame(
+    protocol: &Protocol<AnyPacket>,
+    item: &AnyPacket,
+    len: usize,
+    settings: &CompressionSettings,
+    compressor: &mut Compressor,
+    dst: &mut BytesMut,
+) -> Result<(), HytaleEncodeError> {
+    let descriptor = item.descriptor();
+
+    // Write header
+    let header_len_offset = dst.len();
+    dst.put_u32_le(u32::MAX);
+    dst.put_u32_le(descriptor.id);
+
+    // Write payload
+    let start = dst.len();
+
+    if descriptor.is_compressed {
+        // Only write out a zstd payload if the uncompressed payload is non-empty.
+        if len > 0 {
+            let mut uncompressed = BytesMut::with_capacity(len);
+            protocol.encode_static(item, &mut uncompressed)?;
+            debug_assert_eq!(uncompressed.len(), len);
+
+            compressor
+                .compress(settings, &uncompressed, dst)
+                .map_err(HytaleEncodeError::Compress)?;
+        }
+    } else {
+        dst.reserve(len);
+        protocol.encode_static(item, dst)?;
+        debug_assert_eq!(dst.len() - start, len);
+    }
+
+    let packet_len = dst.len() - start;
+
+    // Adjust header
+    dst[header_len_offset..][..4].copy_from_slice(&(packet_len as u32).to_le_bytes());
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum HytaleDecodeError {
+    #[error("an underlying IO error occurred")]
+    Io(
+        #[from]
+        #[source]
+        io::Error,
+    ),
+    #[error("unknown packet ID {0}")]
+    UnknownId(u32),
+    #[error(
+        "packet {descriptor} cannot be received at this end of the connection since it is {:?}",
+        descriptor.direction,
+    )]
+    WrongDirection {
+        descriptor: &'static PacketDescriptor,
+    },
+    #[error(
+        "packet {descriptor} in category {:?} is not yet accepted by filter {allowed:?}",
+        descriptor.category,
+    )]
+    DeniedCategory {
+        descriptor: &'static PacketDescriptor,
+        allowed: PacketCategory,
+    },
+    #[error(
+        "packet is {received} byte{} in length but {descriptor} only permits packets up to {} byte{} long",
+        if *received == 1 { "" } else { "s" },
+        descriptor.max_size,
+        if descriptor.max_size == 1 { "" } else { "s" },
+    )]
+    TooLong {
+        descriptor: &'static PacketDescriptor,
+        received: u32,
+    },
+    #[error("packet {descriptor} exceeds the decoder's memory budget")]
+    OverBudget {
+        descriptor: &'static PacketDescriptor,
+        #[source]
+        kind: DecodeErrorKind,
+    },
+    #[error("compressed payload of {descriptor} ended before its zstd frame did")]
+    TruncatedFrame {
+        descriptor: &'static PacketDescriptor,
+    },
+    #[error("failed to get decompress contents of {descriptor}: {code}")]
+    DecompressContents {
+        descriptor: &'static PacketDescriptor,
+        code: zstd_safe::ErrorCode,
+    },
+    #[error("decoding of {descriptor} failed")]
+    Decode {
+        descriptor: &'static PacketDescriptor,
+        #[source]
+        error: DecodeError,
+    },
+}
+
+/// Decodes packets of direction `P`.
+#[derive_where(Debug)]
+pub struct HytaleDecoder<P> {
+    /// The packet table packets are decoded with, which defaults to the newest.
+    pub protocol: &'static Protocol<AnyPacket>,
+    pub allowed_categories: PacketCategory,
+    /// The memory budget for each packet, covering its buffered or decompressed payload as well as
+    /// everything allocated while decoding it. Several decoders may [share](DecodeLimits::sharing)
+    /// a budget.
+    pub limits: DecodeLimits,
+    /// Records every frame this decoder receives, before it is decoded.
+    pub tap: Option<CaptureTap>,
+    /// Determines which dictionary, if any, each packet type was compressed with.
+    pub compression: CompressionPolicy,
+    pending: Option<PendingPacket>,
+    /// A decompression context kept between packets so that it need not be recreated.
+    #[derive_where(skip)]
+    spare_dctx: Option<zstd_safe::DCtx<'static>>,
+    _ty: PhantomData<fn(P) -> P>,
+}
+
+/// A packet whose header has been read but whose payload has not yet fully arrived.
+#[derive(Debug)]
+struct PendingPacket {
+    descriptor: &'static PacketDescriptor,
+    /// The number of payload bytes which have yet to arrive.
+    remaining: usize,
+    /// The decompressor for compressed payloads, which are decompressed as they arrive rather
+    /// than being buffered in full first.
+    decompressor: Option<Decompressor>,
+    /// The frame received so far, kept only while a tap is recording.
+    frame: Option<BytesMut>,
+}
+
+struct Decompressor {
+    ctx: zstd_safe::DCtx<'static>,
+    /// Kept alive for as long as `ctx` references it.
+    _dictionary: Option<Arc<CompressionDictionary>>,
+    output: Vec<u8>,
+    frame_finished: bool,
+}
+
+impl fmt::Debug for Decompressor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Decompressor")
+            .field("output_len", &self.output.len())
+            .field("frame_finished", &self.frame_finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P: DirectedPacket> HytaleDecoder<P> {
+    pub fn new(allowed_categories: PacketCategory) -> Self {
+        Self {
+            protocol: &packets::PROTOCOL,
+            allowed_categories,
+            limits: DecodeLimits::default(),
+            tap: None,
+            compression: CompressionPolicy::default(),
+            pending: None,
+            spare_dctx: None,
+            _ty: PhantomData,
+        }
+    }
+
+    fn decode_header(&mut self, src: &mut BytesMut) -> Result<bool, HytaleDecodeError> {
+        if src.len() < 8 {
+            return Ok(false);
+        }
+
+        let packet_len = u32::from_le_bytes(src[0..4].try_into().unwrap());
+        let packet_id = u32::from_le_bytes(src[4..8].try_into().unwrap());
+
+        let descriptor = self
+            .protocol
+            .descriptor_for(packet_id)
+            .ok_or(HytaleDecodeError::UnknownId(packet_id))?;
+
+        if !P::accepts(descriptor.direction) {
+            return Err(HytaleDecodeError::WrongDirection { descriptor });
+        }
+
+        if !self.allowed_categories.contains(descriptor.category) {
+            return Err(HytaleDecodeError::DeniedCategory {
+                descriptor,
+                allowed: self.allowed_categories,
+            });
+        }
+
+        if packet_len > descriptor.max_size {
+            return Err(HytaleDecodeError::TooLong {
+                descriptor,
+                received: packet_len,
+            });
+        }
+
+        self.limits.reset();
+
+        // Uncompressed payloads are buffered in full before being decoded.
+        if !descriptor.is_compressed {
+            self.limits
+                .try_charge(packet_len as usize)
+                .map_err(|kind| HytaleDecodeError::OverBudget { descriptor, kind })?;
+        }
+
+        let frame = self.tap.is_some().then(|| BytesMut::from(&src[..8]));
+
+        src.advance(8);
+
+        // Empty uncompressed payloads are encoded as an empty compressed payload.
+        let decompressor = if descriptor.is_compressed && packet_len > 0 {
+            let mut ctx = self
+                .spare_dctx
+                .take()
+                .unwrap_or_else(zstd_safe::DCtx::create);
+            let dictionary = self.compression.settings_for(descriptor).dictionary.clone();
+
+            if let Some(dictionary) = &dictionary {
+                ctx.ref_ddict(dictionary.ddict())
+                    .map_err(|code| HytaleDecodeError::DecompressContents { descriptor, code })?;
+            }
+
+            Some(Decompressor {
+                ctx,
+                _dictionary: dictionary,
+                output: Vec::new(),
+                frame_finished: false,
+            })
+        } else {
+            None
+        };
+
+        self.pending = Some(PendingPacket {
+            descriptor,
+            remaining: packet_len as usize,
+            decompressor,
+            frame,
+        });
+
+        Ok(true)
+    }
+}
+
+impl Decompressor {
+    fn feed(
+        &mut self,
+        descriptor: &'static PacketDescriptor,
+        input: &[u8],
+        limits: &mut DecodeLimits,
+    ) -> Result<(), HytaleDecodeError> {
+        let max_size = descriptor.max_size as usize;
+        let mut input = zstd_safe::InBuffer::around(input);
+
+        loop {
+            // Grow geometrically once full, charging the budget up front. Leave room for one byte
+            // past the maximum so that oversized payloads are detected without ever buffering
+            // more than that.
+            if self.output.len() == self.output.capacity() {
+                let additional = self
+                    .output
+                    .capacity()
+                    .max(zstd_safe::DCtx::out_size())
+                    .min(max_size + 1 - self.output.len());
+
+                limits
+                    .try_charge(additional)
+                    .map_err(|kind| HytaleDecodeError::OverBudget { descriptor, kind })?;
+
+                self.output.reserve_exact(additional);
+            }
+
+            let in_pos = input.pos();
+            let pos = self.output.len();
+            let hint = self
+                .ctx
+                .decompress_stream(
+                    &mut zstd_safe::OutBuffer::around_pos(&mut self.output, pos),
+                    &mut input,
+                )
+                .map_err(|code| HytaleDecodeError::DecompressContents { descriptor, code })?;
+
+            if self.output.len() > max_size {
+                return Err(HytaleDecodeError::TooLong {
+                    descriptor,
+                    received: self.output.len() as u32,
+                });
+            }
+
+            // A call which made no progress reports on the next frame rather than the current one.
+            if input.pos() > in_pos || self.output.len() > pos {
+                self.frame_finished = hint == 0;
+            }
+
+            // The decompressor may still hold buffered output if it filled our buffer.
+            let output_full = self.output.len() == self.output.capacity();
+
+            if input.pos() == input.src.len() && !output_full {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<P: DirectedPacket> Decoder for HytaleDecoder<P> {
+    type Item = P;
+    type Error = HytaleDecodeError;
+
+    fn decode(&mut self, src: &mut bytes::BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if self.pending.is_none() && !self.decode_header(src)? {
+            return Ok(None);
+        }
+
+        let pending = self.pending.as_mut().unwrap();
+        let descriptor = pending.descriptor;
+
+        let packet = match &mut pending.decompressor {
+            Some(decompressor) => {
+                let chunk = src.split_to(src.len().min(pending.remaining));
+                pending.remaining -= chunk.len();
+
+                if let Some(frame) = &mut pending.frame {
+                    frame.extend_from_slice(&chunk);
+                }
+
+                decompressor.feed(descriptor, &chunk, &mut self.limits)?;
+
+                if pending.remaining > 0 {
+                    return Ok(None);
+                }
+
+                if !decompressor.frame_finished {
+                    return Err(HytaleDecodeError::TruncatedFrame { descriptor });
+                }
+
+                Bytes::from(mem::take(&mut decompressor.output))
+            }
+            None => {
+                if src.len() < pending.remaining {
+                    src.reserve(pending.remaining - src.len());
+                    return Ok(None);
+                }
+
+                let payload = src.split_to(pending.remaining).freeze();
+
+                if let Some(frame) = &mut pending.frame {
+                    frame.extend_from_slice(&payload);
+                }
+
+                payload
+            }
+        };
+
+        let pending = self.pending.take().unwrap();
+
+        if let Some(mut decompressor) = pending.decompressor
+            && decompressor
+                .ctx
+                .reset(zstd_safe::ResetDirective::SessionAndParameters)
+                .is_ok()
+        {
+            self.spare_dctx = Some(decompressor.ctx);
+        }
+
+        let frame = pending.frame;
+
+        if let (Some(tap), Some(frame)) = (&self.tap, frame) {
+            tap.record(P::DIRECTION, descriptor.id, &frame)?;
+        }
+
+        let decoded =
+            self.protocol
+                .decode_static_with_limits(descriptor.id, packet, &mut self.limits);
+
+        // The packet is handed off, so whatever it holds no longer counts against a budget which
+        // may be shared with other decoders.
+        self.limits.reset();
+
+        let packet = decoded.map_err(|error| HytaleDecodeError::Decode { descriptor, error })?;
+
+        // The direction was already checked against the header.
+        let packet =
+            P::try_from(packet).map_err(|_| HytaleDecodeError::WrongDirection { descriptor })?;
+
+        Ok(Some(packet))
+    }
+}
@@ -0,0 +1,12980 @@
+// Generated by `customtale-protocol-tools`
+
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+use std::{any::Any, fmt, ops::Deref};
+
+use bytes::{Bytes, BytesMut};
+use enum_ordinalize::Ordinalize;
+use uuid::Uuid;
+
+use crate::{
+    packets::{
+        PacketCategory, PacketDescriptor, PacketDirection, PacketEntry, PacketTable, Protocol,
+        ProtocolPacket, ProtocolVersion, build_packet_table, packet_table_entry, packet_table_len,
+    },
+    serde::*,
+};
+
+#[cfg(test)]
+#[path = "tests.rs"]
+mod tests;
+
+pub trait Packet: Into<AnyPacket> + fmt::Debug + Clone + StaticSerde {
+    const DESCRIPTOR: &'static PacketDescriptor;
+}
+
+/// A view over the [`AnyPacket`]s sent in one direction.
+pub trait DirectedPacket:
+    Into<AnyPacket> + TryFrom<AnyPacket, Error = AnyPacket> + fmt::Debug + Clone
+{
+    /// The direction this view's packets travel in.
+    const DIRECTION: PacketDirection;
+
+    fn accepts(direction: PacketDirection) -> bool;
+}
+
+macro_rules! define_direction_view {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $direction:ident, $is_direction:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone)]
+        pub struct $name(AnyPacket);
+
+        impl $name {
+            pub fn new(packet: AnyPacket) -> Result<Self, AnyPacket> {
+                if Self::accepts(packet.descriptor().direction) {
+                    Ok(Self(packet))
+                } else {
+                    Err(packet)
+                }
+            }
+
+            pub fn into_inner(self) -> AnyPacket {
+                self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = AnyPacket;
+
+            fn deref(&self) -> &AnyPacket {
+                &self.0
+            }
+        }
+
+        impl TryFrom<AnyPacket> for $name {
+            type Error = AnyPacket;
+
+            fn try_from(packet: AnyPacket) -> Result<Self, AnyPacket> {
+                Self::new(packet)
+            }
+        }
+
+        impl From<$name> for AnyPacket {
+            fn from(packet: $name) -> Self {
+                packet.0
+            }
+        }
+
+        impl DirectedPacket for $name {
+            const DIRECTION: PacketDirection = PacketDirection::$direction;
+
+            fn accepts(direction: PacketDirection) -> bool {
+                direction.$is_direction()
+            }
+        }
+    };
+}
+
+define_direction_view! {
+    /// An [`AnyPacket`] which servers may send to clients.
+    ClientboundPacket, Clientbound, is_clientbound
+}
+
+define_direction_view! {
+    /// An [`AnyPacket`] which clients may send to servers.
+    ServerboundPacket, Serverbound, is_serverbound
+}
+
+macro_rules! define_packets {
+    (
+        $($name:ident),*$(,)?
+    ) => {
+        #[derive(Debug, Clone)]
+        #[cfg_attr(feature = "serde-interop", derive(::serde::Serialize, ::serde::Deserialize))]
+        pub enum AnyPacket {
+            $($name (Box<$name>),)*
+        }
+
+        pub static PROTOCOL: Protocol<AnyPacket> = Protocol {
+            module_path: module_path!(),
+            version: PROTOCOL_VERSION,
+            packets: &[$(<self::$name as Packet>::DESCRIPTOR,)*],
+            table: PACKET_TABLE,
+        };
+
+        const PACKET_ENTRIES: &[PacketEntry<AnyPacket>] = &[$(
+            PacketEntry {
+                descriptor: <self::$name as Packet>::DESCRIPTOR,
+                decode: |contents, limits| {
+                    <self::$name as Serde>::decode_with_limits(contents, limits).map(Into::into)
+                },
+                decode_static: |contents, limits| {
+                    <self::$name as StaticSerde>::decode_static_with_limits(contents, limits)
+                        .map(Into::into)
+                },
+                from_dynamic: |value| {
+                    <self::$name as Serde>::codec().from_dynamic(value).map(Into::into)
+                },
+            },
+        )*];
+
+        const PACKET_TABLE: &PacketTable<AnyPacket> =
+            &build_packet_table::<_, { packet_table_len(PACKET_ENTRIES) }>(PACKET_ENTRIES);
+
+        impl AnyPacket {
+            pub const fn descriptor_for(id: u32) -> Option<&'static PacketDescriptor> {
+                match packet_table_entry(PACKET_TABLE, id) {
+                    Some(entry) => Some(entry.descriptor),
+                    None => None,
+                }
+            }
+
+            pub fn decode(id: u32, contents: Bytes) -> DecodeResult<Self> {
+                Self::decode_with_limits(id, contents, &mut DecodeLimits::unlimited())
+            }
+
+            pub fn decode_with_limits(
+                id: u32,
+                contents: Bytes,
+                limits: &mut DecodeLimits,
+            ) -> DecodeResult<Self> {
+                if let Some(entry) = packet_table_entry(PACKET_TABLE, id) {
+                    return (entry.decode)(contents, limits);
+                }
+
+                Err(DecodeError::at(DecodeErrorKind::UnknownPacket(id), contents.len())
+                    .resolve::<Self>(contents.len()))
+            }
+
+            pub fn decode_static(id: u32, contents: Bytes) -> DecodeResult<Self> {
+                Self::decode_static_with_limits(id, contents, &mut DecodeLimits::unlimited())
+            }
+
+            pub fn decode_static_with_limits(
+                id: u32,
+                contents: Bytes,
+                limits: &mut DecodeLimits,
+            ) -> DecodeResult<Self> {
+                if let Some(entry) = packet_table_entry(PACKET_TABLE, id) {
+                    return (entry.decode_static)(contents, limits);
+                }
+
+                Err(DecodeError::at(DecodeErrorKind::UnknownPacket(id), contents.len())
+                    .resolve::<Self>(contents.len()))
+            }
+
+            pub fn to_dynamic(&self) -> anyhow::Result<DynamicValue> {
+                match self {
+                    $(Self::$name(v) => <self::$name as Serde>::codec().to_dynamic(v),)*
+                }
+            }
+
+            pub fn from_dynamic(id: u32, value: &DynamicValue) -> anyhow::Result<Self> {
+                if let Some(entry) = packet_table_entry(PACKET_TABLE, id) {
+                    return (entry.from_dynamic)(value);
+                }
+
+                anyhow::bail!("unknown packet id {id:?}")
+            }
+
+            pub fn schemas() -> Vec<(&'static PacketDescriptor, Schema)> {
+                vec![$((<self::$name as Packet>::DESCRIPTOR, <self::$name as Serde>::schema()),)*]
+            }
+
+            pub fn descriptor(&self) -> &'static PacketDescriptor {
+                match self {
+                    $(Self::$name(_) => <self::$name as Packet>::DESCRIPTOR,)*
+                }
+            }
+
+            /// Extracts the packet if it is a `P`, giving it back otherwise.
+            pub fn downcast<P: Packet + 'static>(self) -> Result<Box<P>, Self> {
+                match self {
+                    $(Self::$name(v) => (v as Box<dyn Any>)
+                        .downcast::<P>()
+                        .map_err(|v| Self::$name(v.downcast().unwrap())),)*
+                }
+            }
+
+            pub fn encode(&self, target: &mut BytesMut) -> anyhow::Result<()> {
+                match self {
+                    $(Self::$name(v) => Serde::encode(v, target),)*
+                }
+            }
+
+            pub fn encode_static(&self, target: &mut BytesMut) -> anyhow::Result<()> {
+                match self {
+                    $(Self::$name(v) => StaticSerde::encode_static(v, target),)*
+                }
+            }
+
+            pub fn encoded_len(&self) -> usize {
+                match self {
+                    $(Self::$name(v) => <self::$name as StaticSerde>::static_codec().encoded_len(v),)*
+                }
+            }
+        }
+
+        impl ProtocolPacket for AnyPacket {
+            fn descriptor(&self) -> &'static PacketDescriptor {
+                AnyPacket::descriptor(self)
+            }
+
+            fn encode_static(&self, target: &mut BytesMut) -> anyhow::Result<()> {
+                AnyPacket::encode_static(self, target)
+            }
+        }
+
+        $(
+            impl From<self::$name> for AnyPacket {
+                fn from(packet: self::$name) -> Self {
+                    AnyPacket::$name(Box::new(packet))
+                }
+            }
+
+            impl From<Box<self::$name>> for AnyPacket {
+                fn from(packet: Box<self::$name>) -> Self {
+                    AnyPacket::$name(packet)
+                }
+            }
+        )*
+    };
+}
+pub const PROTOCOL_VERSION: Option<ProtocolVersion> = None;
+
+define_packets! {    Connect,
+    Disconnect,
+    Ping,
+    Pong,
+    Status,
+    AuthGrant,
+    AuthToken,
+    ServerAuthToken,
+    ConnectAccept,
+    PasswordResponse,
+    PasswordAccepted,
+    PasswordRejected,
+    ClientReferral,
+    WorldSettings,
+    WorldLoadProgress,
+    WorldLoadFinished,
+    RequestAssets,
+    AssetInitialize,
+    AssetPart,
+    AssetFinalize,
+    RemoveAssets,
+    RequestCommonAssetsRebuild,
+    SetUpdateRate,
+    SetTimeDilation,
+    UpdateFeatures,
+    ViewRadius,
+    PlayerOptions,
+    ServerTags,
+    UpdateBlockTypes,
+    UpdateBlockHitboxes,
+    UpdateBlockSoundSets,
+    UpdateItemSoundSets,
+    UpdateBlockParticleSets,
+    UpdateBlockBreakingDecals,
+    UpdateBlockSets,
+    UpdateWeathers,
+    UpdateTrails,
+    UpdateParticleSystems,
+    UpdateParticleSpawners,
+    UpdateEntityEffects,
+    UpdateItemPlayerAnimations,
+    UpdateModelvfxs,
+    UpdateItems,
+    UpdateItemQualities,
+    UpdateItemCategories,
+    UpdateItemReticles,
+    UpdateFieldcraftCategories,
+    UpdateResourceTypes,
+    UpdateRecipes,
+    UpdateEnvironments,
+    UpdateAmbienceFX,
+    UpdateFluidFX,
+    UpdateTranslations,
+    UpdateSoundEvents,
+    UpdateInteractions,
+    CameraShakeEffect,
+    UpdateRootInteractions,
+    UpdateUnarmedInteractions,
+    TrackOrUpdateObjective,
+    UntrackObjective,
+    UpdateObjectiveTask,
+    UpdateEntityStatTypes,
+    UpdateEntityUIComponents,
+    UpdateHitboxCollisionConfig,
+    UpdateRepulsionConfig,
+    UpdateViewBobbing,
+    UpdateCameraShake,
+    UpdateBlockGroups,
+    UpdateSoundSets,
+    UpdateAudioCategories,
+    UpdateReverbEffects,
+    UpdateEqualizerEffects,
+    UpdateFluids,
+    UpdateTagPatterns,
+    UpdateProjectileConfigs,
+    SetClientId,
+    SetGameMode,
+    SetMovementStates,
+    SetBlockPlacementOverride,
+    JoinWorld,
+    ClientReady,
+    LoadHotbar,
+    SaveHotbar,
+    ClientMovement,
+    ClientTeleport,
+    UpdateMovementSettings,
+    MouseInteraction,
+    DamageInfo,
+    ReticleEvent,
+    DisplayDebug,
+    ClearDebugShapes,
+    SyncPlayerPreferences,
+    ClientPlaceBlock,
+    UpdateMemoriesFeatureStatus,
+    RemoveMapMarker,
+    SetChunk,
+    SetChunkHeightmap,
+    SetChunkTintmap,
+    SetChunkEnvironments,
+    UnloadChunk,
+    SetFluids,
+    ServerSetBlock,
+    ServerSetBlocks,
+    ServerSetFluid,
+    ServerSetFluids,
+    UpdateBlockDamage,
+    UpdateTimeSettings,
+    UpdateTime,
+    UpdateEditorTimeOverride,
+    ClearEditorTimeOverride,
+    UpdateWeather,
+    UpdateEditorWeatherOverride,
+    UpdateEnvironmentMusic,
+    SpawnParticleSystem,
+    SpawnBlockParticleSystem,
+    PlaySoundEvent2D,
+    PlaySoundEvent3D,
+    PlaySoundEventEntity,
+    UpdateSleepState,
+    SetPaused,
+    ServerSetPaused,
+    SetEntitySeed,
+    EntityUpdates,
+    PlayAnimation,
+    ChangeVelocity,
+    ApplyKnockback,
+    SpawnModelParticles,
+    MountMovement,
+    UpdatePlayerInventory,
+    SetCreativeItem,
+    DropCreativeItem,
+    SmartGiveCreativeItem,
+    DropItemStack,
+    MoveItemStack,
+    SmartMoveItemStack,
+    SetActiveSlot,
+    SwitchHotbarBlockSet,
+    InventoryAction,
+    OpenWindow,
+    UpdateWindow,
+    CloseWindow,
+    SendWindowAction,
+    ClientOpenWindow,
+    ServerMessage,
+    ChatMessage,
+    Notification,
+    KillFeedMessage,
+    ShowEventTitle,
+    HideEventTitle,
+    SetPage,
+    CustomHud,
+    CustomPage,
+    CustomPageEvent,
+    EditorBlocksChange,
+    ServerInfo,
+    AddToServerPlayerList,
+    RemoveFromServerPlayerList,
+    UpdateServerPlayerList,
+    UpdateServerPlayerListPing,
+    UpdateKnownRecipes,
+    UpdatePortal,
+    UpdateVisibleHudComponents,
+    ResetUserInterfaceState,
+    UpdateLanguage,
+    WorldSavingStatus,
+    OpenChatWithCommand,
+    UpdateWorldMapSettings,
+    UpdateWorldMap,
+    ClearWorldMap,
+    UpdateWorldMapVisible,
+    TeleportToWorldMapMarker,
+    TeleportToWorldMapPosition,
+    RequestServerAccess,
+    UpdateServerAccess,
+    SetServerAccess,
+    RequestMachinimaActorModel,
+    SetMachinimaActorModel,
+    UpdateMachinimaScene,
+    SetServerCamera,
+    RequestFlyCameraMode,
+    SetFlyCameraMode,
+    SyncInteractionChains,
+    CancelInteractionChain,
+    PlayInteractionFor,
+    MountNPC,
+    DismountNPC,
+    FailureReply,
+    SuccessReply,
+    AssetEditorInitialize,
+    AssetEditorAuthorization,
+    AssetEditorCapabilities,
+    AssetEditorSetupSchemas,
+    AssetEditorSetupAssetTypes,
+    AssetEditorCreateDirectory,
+    AssetEditorDeleteDirectory,
+    AssetEditorRenameDirectory,
+    AssetEditorFetchAsset,
+    AssetEditorFetchJsonAssetWithParents,
+    AssetEditorFetchAssetReply,
+    AssetEditorFetchJsonAssetWithParentsReply,
+    AssetEditorAssetPackSetup,
+    AssetEditorUpdateAssetPack,
+    AssetEditorCreateAssetPack,
+    AssetEditorDeleteAssetPack,
+    AssetEditorEnableAssetPack,
+    AssetEditorAssetListSetup,
+    AssetEditorAssetListUpdate,
+    AssetEditorRequestChildrenList,
+    AssetEditorRequestChildrenListReply,
+    AssetEditorUpdateJsonAsset,
+    AssetEditorRebuildCaches,
+    AssetEditorUpdateAsset,
+    AssetEditorJsonAssetUpdated,
+    AssetEditorAssetUpdated,
+    AssetEditorCreateAsset,
+    AssetEditorRenameAsset,
+    AssetEditorDeleteAsset,
+    AssetEditorDiscardChanges,
+    AssetEditorFetchAutoCompleteData,
+    AssetEditorFetchAutoCompleteDataReply,
+    AssetEditorRequestDataset,
+    AssetEditorRequestDatasetReply,
+    AssetEditorActivateButton,
+    AssetEditorSelectAsset,
+    AssetEditorPopupNotification,
+    AssetEditorFetchLastModifiedAssets,
+    AssetEditorLastModifiedAssets,
+    AssetEditorModifiedAssetsCount,
+    AssetEditorSubscribeModifiedAssetsChanges,
+    AssetEditorExportAssets,
+    AssetEditorExportAssetInitialize,
+    AssetEditorExportAssetPart,
+    AssetEditorExportAssetFinalize,
+    AssetEditorExportDeleteAssets,
+    AssetEditorExportComplete,
+    AssetEditorUndoChanges,
+    AssetEditorRedoChanges,
+    AssetEditorUndoRedoReply,
+    AssetEditorSetGameTime,
+    AssetEditorUpdateSecondsPerGameDay,
+    AssetEditorUpdateWeatherPreviewLock,
+    AssetEditorUpdateModelPreview,
+    UpdateSunSettings,
+    UpdatePostFxSettings,
+    BuilderToolArgUpdate,
+    BuilderToolEntityAction,
+    BuilderToolSetEntityTransform,
+    BuilderToolExtrudeAction,
+    BuilderToolStackArea,
+    BuilderToolSelectionTransform,
+    BuilderToolRotateClipboard,
+    BuilderToolPasteClipboard,
+    BuilderToolSetTransformationModeState,
+    BuilderToolSelectionUpdate,
+    BuilderToolSelectionToolAskForClipboard,
+    BuilderToolSelectionToolReplyWithClipboard,
+    BuilderToolGeneralAction,
+    BuilderToolOnUseInteraction,
+    BuilderToolLineAction,
+    BuilderToolShowAnchor,
+    BuilderToolHideAnchors,
+    PrefabUnselectPrefab,
+    BuilderToolsSetSoundSet,
+    BuilderToolLaserPointer,
+    BuilderToolSetEntityScale,
+    BuilderToolSetEntityPickupEnabled,
+    BuilderToolSetEntityLight,
+    BuilderToolSetNPCDebug,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Connect {
+    pub r#protocolCrc: i32,
+    pub r#protocolBuildNumber: i32,
+    #[codec(fixed_string = 20)]
+    pub r#clientVersion: ByteString,
+    pub r#clientType: ClientType,
+    pub r#uuid: Uuid,
+    #[codec(max_len = 16)]
+    pub r#username: ByteString,
+    #[codec(max_len = 8192)]
+    pub r#identityToken: Option<ByteString>,
+    #[codec(max_len = 16)]
+    pub r#language: ByteString,
+    #[codec(max_len = 4096)]
+    pub r#referralData: Option<Bytes>,
+    pub r#referralSource: Option<HostAddress>,
+}
+
+impl Packet for Connect {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "Connect",
+        id: 0,
+        is_compressed: false,
+        max_size: 38013,
+        category: PacketCategory::CONNECTION,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ClientType {
+    #[default]
+    r#Game,
+    r#Editor,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct HostAddress {
+    #[codec(max_len = 256)]
+    pub r#host: ByteString,
+    pub r#port: i16,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Disconnect {
+    pub r#reason: Option<ByteString>,
+    pub r#type: DisconnectType,
+}
+
+impl Packet for Disconnect {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "Disconnect",
+        id: 1,
+        is_compressed: false,
+        max_size: 16384007,
+        category: PacketCategory::CONNECTION,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum DisconnectType {
+    #[default]
+    r#Disconnect,
+    r#Crash,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Ping {
+    pub r#id: i32,
+    pub r#time: Option<InstantData>,
+    pub r#lastPingValueRaw: i32,
+    pub r#lastPingValueDirect: i32,
+    pub r#lastPingValueTick: i32,
+}
+
+impl Packet for Ping {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "Ping",
+        id: 2,
+        is_compressed: false,
+        max_size: 29,
+        category: PacketCategory::CONNECTION,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct InstantData {
+    pub r#seconds: i64,
+    pub r#nanos: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Pong {
+    pub r#id: i32,
+    pub r#time: Option<InstantData>,
+    pub r#type: PongType,
+    pub r#packetQueueSize: i16,
+}
+
+impl Packet for Pong {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "Pong",
+        id: 3,
+        is_compressed: false,
+        max_size: 20,
+        category: PacketCategory::CONNECTION,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum PongType {
+    #[default]
+    r#Raw,
+    r#Direct,
+    r#Tick,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Status {
+    pub r#name: Option<ByteString>,
+    pub r#motd: Option<ByteString>,
+    pub r#playerCount: i32,
+    pub r#maxPlayers: i32,
+}
+
+impl Packet for Status {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "Status",
+        id: 10,
+        is_compressed: false,
+        max_size: 2587,
+        category: PacketCategory::AUTH,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AuthGrant {
+    pub r#authorizationGrant: Option<ByteString>,
+    pub r#serverIdentityToken: Option<ByteString>,
+}
+
+impl Packet for AuthGrant {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AuthGrant",
+        id: 11,
+        is_compressed: false,
+        max_size: 49171,
+        category: PacketCategory::AUTH,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AuthToken {
+    pub r#accessToken: Option<ByteString>,
+    pub r#serverAuthorizationGrant: Option<ByteString>,
+}
+
+impl Packet for AuthToken {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AuthToken",
+        id: 12,
+        is_compressed: false,
+        max_size: 49171,
+        category: PacketCategory::AUTH,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerAuthToken {
+    pub r#serverAccessToken: Option<ByteString>,
+    pub r#passwordChallenge: Option<Bytes>,
+}
+
+impl Packet for ServerAuthToken {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ServerAuthToken",
+        id: 13,
+        is_compressed: false,
+        max_size: 32851,
+        category: PacketCategory::AUTH,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ConnectAccept {
+    pub r#passwordChallenge: Option<Bytes>,
+}
+
+impl Packet for ConnectAccept {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ConnectAccept",
+        id: 14,
+        is_compressed: false,
+        max_size: 70,
+        category: PacketCategory::AUTH,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PasswordResponse {
+    pub r#hash: Option<Bytes>,
+}
+
+impl Packet for PasswordResponse {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PasswordResponse",
+        id: 15,
+        is_compressed: false,
+        max_size: 70,
+        category: PacketCategory::AUTH,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PasswordAccepted {}
+
+impl Packet for PasswordAccepted {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PasswordAccepted",
+        id: 16,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::AUTH,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PasswordRejected {
+    pub r#newChallenge: Option<Bytes>,
+    pub r#attemptsRemaining: i32,
+}
+
+impl Packet for PasswordRejected {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PasswordRejected",
+        id: 17,
+        is_compressed: false,
+        max_size: 74,
+        category: PacketCategory::AUTH,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClientReferral {
+    pub r#hostTo: Option<HostAddress>,
+    pub r#data: Option<Bytes>,
+}
+
+impl Packet for ClientReferral {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ClientReferral",
+        id: 18,
+        is_compressed: false,
+        max_size: 5141,
+        category: PacketCategory::AUTH,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct WorldSettings {
+    pub r#worldHeight: i32,
+    pub r#requiredAssets: Option<Vec<Asset>>,
+}
+
+impl Packet for WorldSettings {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "WorldSettings",
+        id: 20,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Asset {
+    #[codec(fixed_string = 64)]
+    pub r#hash: ByteString,
+    #[codec(max_len = 512)]
+    pub r#name: ByteString,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct WorldLoadProgress {
+    pub r#status: Option<ByteString>,
+    pub r#percentComplete: i32,
+    pub r#percentCompleteSubitem: i32,
+}
+
+impl Packet for WorldLoadProgress {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "WorldLoadProgress",
+        id: 21,
+        is_compressed: false,
+        max_size: 16384014,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct WorldLoadFinished {}
+
+impl Packet for WorldLoadFinished {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "WorldLoadFinished",
+        id: 22,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RequestAssets {
+    pub r#assets: Option<Vec<Asset>>,
+}
+
+impl Packet for RequestAssets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "RequestAssets",
+        id: 23,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetInitialize {
+    pub r#asset: Asset,
+    pub r#size: i32,
+}
+
+impl Packet for AssetInitialize {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetInitialize",
+        id: 24,
+        is_compressed: false,
+        max_size: 2121,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetPart {
+    pub r#part: Option<Bytes>,
+}
+
+impl Packet for AssetPart {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetPart",
+        id: 25,
+        is_compressed: true,
+        max_size: 4096006,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetFinalize {}
+
+impl Packet for AssetFinalize {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetFinalize",
+        id: 26,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RemoveAssets {
+    pub r#asset: Option<Vec<Asset>>,
+}
+
+impl Packet for RemoveAssets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "RemoveAssets",
+        id: 27,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RequestCommonAssetsRebuild {}
+
+impl Packet for RequestCommonAssetsRebuild {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "RequestCommonAssetsRebuild",
+        id: 28,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetUpdateRate {
+    pub r#updatesPerSecond: i32,
+}
+
+impl Packet for SetUpdateRate {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetUpdateRate",
+        id: 29,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetTimeDilation {
+    pub r#timeDilation: f32,
+}
+
+impl Packet for SetTimeDilation {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetTimeDilation",
+        id: 30,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateFeatures {
+    pub r#features: Option<Dictionary<ClientFeature, bool>>,
+}
+
+impl Packet for UpdateFeatures {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateFeatures",
+        id: 31,
+        is_compressed: false,
+        max_size: 8192006,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ClientFeature {
+    #[default]
+    r#SplitVelocity,
+    r#Mantling,
+    r#SprintForce,
+    r#CrouchSlide,
+    r#SafetyRoll,
+    r#DisplayHealthBars,
+    r#DisplayCombatText,
+    r#CanHideHelmet,
+    r#CanHideCuirass,
+    r#CanHideGauntlets,
+    r#CanHidePants,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ViewRadius {
+    pub r#value: i32,
+}
+
+impl Packet for ViewRadius {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ViewRadius",
+        id: 32,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PlayerOptions {
+    pub r#skin: Option<PlayerSkin>,
+}
+
+impl Packet for PlayerOptions {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PlayerOptions",
+        id: 33,
+        is_compressed: false,
+        max_size: 327680184,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PlayerSkin {
+    pub r#bodyCharacteristic: Option<ByteString>,
+    pub r#underwear: Option<ByteString>,
+    pub r#face: Option<ByteString>,
+    pub r#eyes: Option<ByteString>,
+    pub r#ears: Option<ByteString>,
+    pub r#mouth: Option<ByteString>,
+    pub r#facialHair: Option<ByteString>,
+    pub r#haircut: Option<ByteString>,
+    pub r#eyebrows: Option<ByteString>,
+    pub r#pants: Option<ByteString>,
+    pub r#overpants: Option<ByteString>,
+    pub r#undertop: Option<ByteString>,
+    pub r#overtop: Option<ByteString>,
+    pub r#shoes: Option<ByteString>,
+    pub r#headAccessory: Option<ByteString>,
+    pub r#faceAccessory: Option<ByteString>,
+    pub r#earAccessory: Option<ByteString>,
+    pub r#skinFeature: Option<ByteString>,
+    pub r#gloves: Option<ByteString>,
+    pub r#cape: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerTags {
+    pub r#tags: Option<Dictionary<ByteString, i32>>,
+}
+
+impl Packet for ServerTags {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ServerTags",
+        id: 34,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::SETUP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateBlockTypes {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#blockTypes: Option<Dictionary<i32, BlockType>>,
+    pub r#updateBlockTextures: bool,
+    pub r#updateModelTextures: bool,
+    pub r#updateModels: bool,
+    pub r#updateMapGeometry: bool,
+}
+
+impl Packet for UpdateBlockTypes {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateBlockTypes",
+        id: 40,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum UpdateType {
+    #[default]
+    r#Init,
+    r#AddOrUpdate,
+    r#Remove,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockType {
+    pub r#item: Option<ByteString>,
+    pub r#name: Option<ByteString>,
+    pub r#unknown: bool,
+    pub r#drawType: DrawType,
+    pub r#material: BlockMaterial,
+    pub r#opacity: Opacity,
+    pub r#shaderEffect: Option<Vec<ShaderType>>,
+    pub r#hitbox: i32,
+    pub r#interactionHitbox: i32,
+    pub r#model: Option<ByteString>,
+    pub r#modelTexture: Option<Vec<ModelTexture>>,
+    pub r#modelScale: f32,
+    pub r#modelAnimation: Option<ByteString>,
+    pub r#looping: bool,
+    pub r#maxSupportDistance: i32,
+    pub r#blockSupportsRequiredFor: BlockSupportsRequiredForType,
+    pub r#support: Option<Dictionary<BlockNeighbor, Vec<RequiredBlockFaceSupport>>>,
+    pub r#supporting: Option<Dictionary<BlockNeighbor, Vec<BlockFaceSupport>>>,
+    pub r#requiresAlphaBlending: bool,
+    pub r#cubeTextures: Option<Vec<BlockTextures>>,
+    pub r#cubeSideMaskTexture: Option<ByteString>,
+    pub r#cubeShadingMode: ShadingMode,
+    pub r#randomRotation: RandomRotation,
+    pub r#variantRotation: VariantRotation,
+    pub r#rotationYawPlacementOffset: Rotation,
+    pub r#blockSoundSetIndex: i32,
+    pub r#ambientSoundEventIndex: i32,
+    pub r#particles: Option<Vec<ModelParticle>>,
+    pub r#blockParticleSetId: Option<ByteString>,
+    pub r#blockBreakingDecalId: Option<ByteString>,
+    pub r#particleColor: Option<Color>,
+    pub r#light: Option<ColorLight>,
+    pub r#tint: Option<Tint>,
+    pub r#biomeTint: Option<Tint>,
+    pub r#group: i32,
+    pub r#transitionTexture: Option<ByteString>,
+    pub r#transitionToGroups: Option<Vec<i32>>,
+    pub r#movementSettings: Option<BlockMovementSettings>,
+    pub r#flags: Option<BlockFlags>,
+    pub r#interactionHint: Option<ByteString>,
+    pub r#gathering: Option<BlockGathering>,
+    pub r#placementSettings: Option<BlockPlacementSettings>,
+    pub r#display: Option<ModelDisplay>,
+    pub r#rail: Option<RailConfig>,
+    pub r#ignoreSupportWhenPlaced: bool,
+    pub r#interactions: Option<Dictionary<InteractionType, i32>>,
+    pub r#states: Option<Dictionary<ByteString, i32>>,
+    pub r#transitionToTag: i32,
+    pub r#tagIndexes: Option<Vec<i32>>,
+    pub r#bench: Option<Bench>,
+    pub r#connectedBlockRuleSet: Option<ConnectedBlockRuleSet>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum DrawType {
+    #[default]
+    r#Empty,
+    r#GizmoCube,
+    r#Cube,
+    r#Model,
+    r#CubeWithModel,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BlockMaterial {
+    #[default]
+    r#Empty,
+    r#Solid,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum Opacity {
+    #[default]
+    r#Solid,
+    r#Semitransparent,
+    r#Cutout,
+    r#Transparent,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ShaderType {
+    #[default]
+    r#None,
+    r#Wind,
+    r#WindAttached,
+    r#WindRandom,
+    r#WindFractal,
+    r#Ice,
+    r#Water,
+    r#Lava,
+    r#Slime,
+    r#Ripple,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ModelTexture {
+    pub r#texture: Option<ByteString>,
+    pub r#weight: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BlockSupportsRequiredForType {
+    #[default]
+    r#Any,
+    r#All,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BlockNeighbor {
+    #[default]
+    r#Up,
+    r#Down,
+    r#North,
+    r#East,
+    r#South,
+    r#West,
+    r#UpNorth,
+    r#UpSouth,
+    r#UpEast,
+    r#UpWest,
+    r#DownNorth,
+    r#DownSouth,
+    r#DownEast,
+    r#DownWest,
+    r#NorthEast,
+    r#SouthEast,
+    r#SouthWest,
+    r#NorthWest,
+    r#UpNorthEast,
+    r#UpSouthEast,
+    r#UpSouthWest,
+    r#UpNorthWest,
+    r#DownNorthEast,
+    r#DownSouthEast,
+    r#DownSouthWest,
+    r#DownNorthWest,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RequiredBlockFaceSupport {
+    pub r#faceType: Option<ByteString>,
+    pub r#selfFaceType: Option<ByteString>,
+    pub r#blockSetId: Option<ByteString>,
+    pub r#blockTypeId: i32,
+    pub r#tagIndex: i32,
+    pub r#fluidId: i32,
+    pub r#support: SupportMatch,
+    pub r#matchSelf: SupportMatch,
+    pub r#allowSupportPropagation: bool,
+    pub r#rotate: bool,
+    pub r#filler: Option<Vec<Vector3i>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum SupportMatch {
+    #[default]
+    r#Ignored,
+    r#Required,
+    r#Disallowed,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Vector3i {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockFaceSupport {
+    pub r#faceType: Option<ByteString>,
+    pub r#filler: Option<Vec<Vector3i>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockTextures {
+    pub r#top: Option<ByteString>,
+    pub r#bottom: Option<ByteString>,
+    pub r#front: Option<ByteString>,
+    pub r#back: Option<ByteString>,
+    pub r#left: Option<ByteString>,
+    pub r#right: Option<ByteString>,
+    pub r#weight: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ShadingMode {
+    #[default]
+    r#Standard,
+    r#Flat,
+    r#Fullbright,
+    r#Reflective,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum RandomRotation {
+    #[default]
+    r#None,
+    r#YawPitchRollStep1,
+    r#YawStep1,
+    r#YawStep1XZ,
+    r#YawStep90,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum VariantRotation {
+    #[default]
+    r#None,
+    r#Wall,
+    r#UpDown,
+    r#Pipe,
+    r#DoublePipe,
+    r#NESW,
+    r#UpDownNESW,
+    r#All,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum Rotation {
+    #[default]
+    r#None,
+    r#Ninety,
+    r#OneEighty,
+    r#TwoSeventy,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ModelParticle {
+    pub r#systemId: Option<ByteString>,
+    pub r#scale: f32,
+    pub r#color: Option<Color>,
+    pub r#targetEntityPart: EntityPart,
+    pub r#targetNodeName: Option<ByteString>,
+    pub r#positionOffset: Option<Vector3f>,
+    pub r#rotationOffset: Option<Direction>,
+    pub r#detachedFromModel: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Color {
+    pub r#red: i8,
+    pub r#green: i8,
+    pub r#blue: i8,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EntityPart {
+    #[default]
+    Self_,
+    r#Entity,
+    r#PrimaryItem,
+    r#SecondaryItem,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Vector3f {
+    pub r#x: f32,
+    pub r#y: f32,
+    pub r#z: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Direction {
+    pub r#yaw: f32,
+    pub r#pitch: f32,
+    pub r#roll: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct ColorLight {
+    pub r#radius: i8,
+    pub r#red: i8,
+    pub r#green: i8,
+    pub r#blue: i8,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Tint {
+    pub r#top: i32,
+    pub r#bottom: i32,
+    pub r#front: i32,
+    pub r#back: i32,
+    pub r#left: i32,
+    pub r#right: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BlockMovementSettings {
+    pub r#isClimbable: bool,
+    pub r#climbUpSpeedMultiplier: f32,
+    pub r#climbDownSpeedMultiplier: f32,
+    pub r#climbLateralSpeedMultiplier: f32,
+    pub r#isBouncy: bool,
+    pub r#bounceVelocity: f32,
+    pub r#drag: f32,
+    pub r#friction: f32,
+    pub r#terminalVelocityModifier: f32,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#acceleration: f32,
+    pub r#jumpForceMultiplier: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BlockFlags {
+    pub r#isUsable: bool,
+    pub r#isStackable: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockGathering {
+    pub r#breaking: Option<BlockBreaking>,
+    pub r#harvest: Option<Harvesting>,
+    pub r#soft: Option<SoftBlock>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockBreaking {
+    pub r#gatherType: Option<ByteString>,
+    pub r#health: f32,
+    pub r#quantity: i32,
+    pub r#quality: i32,
+    pub r#itemId: Option<ByteString>,
+    pub r#dropListId: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Harvesting {
+    pub r#itemId: Option<ByteString>,
+    pub r#dropListId: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SoftBlock {
+    pub r#itemId: Option<ByteString>,
+    pub r#dropListId: Option<ByteString>,
+    pub r#isWeaponBreakable: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BlockPlacementSettings {
+    pub r#allowRotationKey: bool,
+    pub r#placeInEmptyBlocks: bool,
+    pub r#previewVisibility: BlockPreviewVisibility,
+    pub r#rotationMode: BlockPlacementRotationMode,
+    pub r#wallPlacementOverrideBlockId: i32,
+    pub r#floorPlacementOverrideBlockId: i32,
+    pub r#ceilingPlacementOverrideBlockId: i32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BlockPreviewVisibility {
+    #[default]
+    r#AlwaysVisible,
+    r#AlwaysHidden,
+    r#Default,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BlockPlacementRotationMode {
+    #[default]
+    r#FacingPlayer,
+    r#StairFacingPlayer,
+    r#BlockNormal,
+    r#Default,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ModelDisplay {
+    pub r#node: Option<ByteString>,
+    pub r#attachTo: Option<ByteString>,
+    pub r#translation: Option<Vector3f>,
+    pub r#rotation: Option<Vector3f>,
+    pub r#scale: Option<Vector3f>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RailConfig {
+    pub r#points: Option<Vec<RailPoint>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RailPoint {
+    pub r#point: Option<Vector3f>,
+    pub r#normal: Option<Vector3f>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum InteractionType {
+    #[default]
+    r#Primary,
+    r#Secondary,
+    r#Ability1,
+    r#Ability2,
+    r#Ability3,
+    r#Use,
+    r#Pick,
+    r#Pickup,
+    r#CollisionEnter,
+    r#CollisionLeave,
+    r#Collision,
+    r#EntityStatEffect,
+    r#SwapTo,
+    r#SwapFrom,
+    r#Death,
+    r#Wielding,
+    r#ProjectileSpawn,
+    r#ProjectileHit,
+    r#ProjectileMiss,
+    r#ProjectileBounce,
+    r#Held,
+    r#HeldOffhand,
+    r#Equipped,
+    r#Dodge,
+    r#GameModeSwap,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Bench {
+    pub r#benchTierLevels: Option<Vec<BenchTierLevel>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BenchTierLevel {
+    pub r#benchUpgradeRequirement: Option<BenchUpgradeRequirement>,
+    pub r#craftingTimeReductionModifier: f64,
+    pub r#extraInputSlot: i32,
+    pub r#extraOutputSlot: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BenchUpgradeRequirement {
+    pub r#material: Option<Vec<MaterialQuantity>>,
+    pub r#timeSeconds: f64,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MaterialQuantity {
+    pub r#itemId: Option<ByteString>,
+    pub r#itemTag: i32,
+    pub r#resourceTypeId: Option<ByteString>,
+    pub r#quantity: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ConnectedBlockRuleSet {
+    pub r#type: ConnectedBlockRuleSetType,
+    pub r#stair: Option<StairConnectedBlockRuleSet>,
+    pub r#roof: Option<RoofConnectedBlockRuleSet>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ConnectedBlockRuleSetType {
+    #[default]
+    r#Stair,
+    r#Roof,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct StairConnectedBlockRuleSet {
+    pub r#straightBlockId: i32,
+    pub r#cornerLeftBlockId: i32,
+    pub r#cornerRightBlockId: i32,
+    pub r#invertedCornerLeftBlockId: i32,
+    pub r#invertedCornerRightBlockId: i32,
+    pub r#materialName: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RoofConnectedBlockRuleSet {
+    pub r#regular: Option<StairConnectedBlockRuleSet>,
+    pub r#hollow: Option<StairConnectedBlockRuleSet>,
+    pub r#topperBlockId: i32,
+    pub r#width: i32,
+    pub r#materialName: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateBlockHitboxes {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#blockBaseHitboxes: Option<Dictionary<i32, Vec<Hitbox>>>,
+}
+
+impl Packet for UpdateBlockHitboxes {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateBlockHitboxes",
+        id: 41,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Hitbox {
+    pub r#minX: f32,
+    pub r#minY: f32,
+    pub r#minZ: f32,
+    pub r#maxX: f32,
+    pub r#maxY: f32,
+    pub r#maxZ: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateBlockSoundSets {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#blockSoundSets: Option<Dictionary<i32, BlockSoundSet>>,
+}
+
+impl Packet for UpdateBlockSoundSets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateBlockSoundSets",
+        id: 42,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockSoundSet {
+    pub r#id: Option<ByteString>,
+    pub r#soundEventIndices: Option<Dictionary<BlockSoundEvent, i32>>,
+    pub r#moveInRepeatRange: Option<FloatRange>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BlockSoundEvent {
+    #[default]
+    r#Walk,
+    r#Land,
+    r#MoveIn,
+    r#MoveOut,
+    r#Hit,
+    r#Break,
+    r#Build,
+    r#Clone,
+    r#Harvest,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct FloatRange {
+    pub r#inclusiveMin: f32,
+    pub r#inclusiveMax: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateItemSoundSets {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#itemSoundSets: Option<Dictionary<i32, ItemSoundSet>>,
+}
+
+impl Packet for UpdateItemSoundSets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateItemSoundSets",
+        id: 43,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemSoundSet {
+    pub r#id: Option<ByteString>,
+    pub r#soundEventIndices: Option<Dictionary<ItemSoundEvent, i32>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ItemSoundEvent {
+    #[default]
+    r#Drag,
+    r#Drop,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateBlockParticleSets {
+    pub r#type: UpdateType,
+    pub r#blockParticleSets: Option<Dictionary<ByteString, BlockParticleSet>>,
+}
+
+impl Packet for UpdateBlockParticleSets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateBlockParticleSets",
+        id: 44,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockParticleSet {
+    pub r#id: Option<ByteString>,
+    pub r#color: Option<Color>,
+    pub r#scale: f32,
+    pub r#positionOffset: Option<Vector3f>,
+    pub r#rotationOffset: Option<Direction>,
+    pub r#particleSystemIds: Option<Dictionary<BlockParticleEvent, ByteString>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BlockParticleEvent {
+    #[default]
+    r#Walk,
+    r#Run,
+    r#Sprint,
+    r#SoftLand,
+    r#HardLand,
+    r#MoveOut,
+    r#Hit,
+    r#Break,
+    r#Build,
+    r#Physics,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateBlockBreakingDecals {
+    pub r#type: UpdateType,
+    pub r#blockBreakingDecals: Option<Dictionary<ByteString, BlockBreakingDecal>>,
+}
+
+impl Packet for UpdateBlockBreakingDecals {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateBlockBreakingDecals",
+        id: 45,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockBreakingDecal {
+    pub r#stageTextures: Option<Vec<ByteString>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateBlockSets {
+    pub r#type: UpdateType,
+    pub r#blockSets: Option<Dictionary<ByteString, BlockSet>>,
+}
+
+impl Packet for UpdateBlockSets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateBlockSets",
+        id: 46,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockSet {
+    pub r#name: Option<ByteString>,
+    pub r#blocks: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateWeathers {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#weathers: Option<Dictionary<i32, Weather>>,
+}
+
+impl Packet for UpdateWeathers {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateWeathers",
+        id: 47,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Weather {
+    pub r#id: Option<ByteString>,
+    pub r#tagIndexes: Option<Vec<i32>>,
+    pub r#stars: Option<ByteString>,
+    pub r#moons: Option<Dictionary<i32, ByteString>>,
+    pub r#clouds: Option<Vec<Cloud>>,
+    pub r#sunlightDampingMultiplier: Option<Dictionary<f32, f32>>,
+    pub r#sunlightColors: Option<Dictionary<f32, Color>>,
+    pub r#skyTopColors: Option<Dictionary<f32, ColorAlpha>>,
+    pub r#skyBottomColors: Option<Dictionary<f32, ColorAlpha>>,
+    pub r#skySunsetColors: Option<Dictionary<f32, ColorAlpha>>,
+    pub r#sunColors: Option<Dictionary<f32, Color>>,
+    pub r#sunScales: Option<Dictionary<f32, f32>>,
+    pub r#sunGlowColors: Option<Dictionary<f32, ColorAlpha>>,
+    pub r#moonColors: Option<Dictionary<f32, ColorAlpha>>,
+    pub r#moonScales: Option<Dictionary<f32, f32>>,
+    pub r#moonGlowColors: Option<Dictionary<f32, ColorAlpha>>,
+    pub r#fogColors: Option<Dictionary<f32, Color>>,
+    pub r#fogHeightFalloffs: Option<Dictionary<f32, f32>>,
+    pub r#fogDensities: Option<Dictionary<f32, f32>>,
+    pub r#screenEffect: Option<ByteString>,
+    pub r#screenEffectColors: Option<Dictionary<f32, ColorAlpha>>,
+    pub r#colorFilters: Option<Dictionary<f32, Color>>,
+    pub r#waterTints: Option<Dictionary<f32, Color>>,
+    pub r#particle: Option<WeatherParticle>,
+    pub r#fog: Option<NearFar>,
+    pub r#fogOptions: Option<FogOptions>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Cloud {
+    pub r#texture: Option<ByteString>,
+    pub r#speeds: Option<Dictionary<f32, f32>>,
+    pub r#colors: Option<Dictionary<f32, ColorAlpha>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct ColorAlpha {
+    pub r#alpha: i8,
+    pub r#red: i8,
+    pub r#green: i8,
+    pub r#blue: i8,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct WeatherParticle {
+    pub r#systemId: Option<ByteString>,
+    pub r#color: Option<Color>,
+    pub r#scale: f32,
+    pub r#isOvergroundOnly: bool,
+    pub r#positionOffsetMultiplier: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct NearFar {
+    pub r#near: f32,
+    pub r#far: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct FogOptions {
+    pub r#ignoreFogLimits: bool,
+    pub r#effectiveViewDistanceMultiplier: f32,
+    pub r#fogFarViewDistance: f32,
+    pub r#fogHeightCameraOffset: f32,
+    pub r#fogHeightCameraOverriden: bool,
+    pub r#fogHeightCameraFixed: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateTrails {
+    pub r#type: UpdateType,
+    pub r#trails: Option<Dictionary<ByteString, Trail>>,
+}
+
+impl Packet for UpdateTrails {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateTrails",
+        id: 48,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Trail {
+    pub r#id: Option<ByteString>,
+    pub r#texture: Option<ByteString>,
+    pub r#lifeSpan: i32,
+    pub r#roll: f32,
+    pub r#start: Option<Edge>,
+    pub r#end: Option<Edge>,
+    pub r#lightInfluence: f32,
+    pub r#renderMode: FXRenderMode,
+    pub r#intersectionHighlight: Option<IntersectionHighlight>,
+    pub r#smooth: bool,
+    pub r#frameSize: Option<Vector2i>,
+    pub r#frameRange: Option<Range>,
+    pub r#frameLifeSpan: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Edge {
+    pub r#color: Option<ColorAlpha>,
+    pub r#width: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum FXRenderMode {
+    #[default]
+    r#BlendLinear,
+    r#BlendAdd,
+    r#Erosion,
+    r#Distortion,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct IntersectionHighlight {
+    pub r#highlightThreshold: f32,
+    pub r#highlightColor: Option<Color>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Vector2i {
+    pub r#x: i32,
+    pub r#y: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Range {
+    pub r#min: i32,
+    pub r#max: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateParticleSystems {
+    pub r#type: UpdateType,
+    pub r#particleSystems: Option<Dictionary<ByteString, ParticleSystem>>,
+    pub r#removedParticleSystems: Option<Vec<ByteString>>,
+}
+
+impl Packet for UpdateParticleSystems {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateParticleSystems",
+        id: 49,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ParticleSystem {
+    pub r#id: Option<ByteString>,
+    pub r#spawners: Option<Vec<ParticleSpawnerGroup>>,
+    pub r#lifeSpan: f32,
+    pub r#cullDistance: f32,
+    pub r#boundingRadius: f32,
+    pub r#isImportant: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ParticleSpawnerGroup {
+    pub r#spawnerId: Option<ByteString>,
+    pub r#positionOffset: Option<Vector3f>,
+    pub r#rotationOffset: Option<Direction>,
+    pub r#fixedRotation: bool,
+    pub r#startDelay: f32,
+    pub r#spawnRate: Option<Rangef>,
+    pub r#waveDelay: Option<Rangef>,
+    pub r#totalSpawners: i32,
+    pub r#maxConcurrent: i32,
+    pub r#initialVelocity: Option<InitialVelocity>,
+    pub r#emitOffset: Option<RangeVector3f>,
+    pub r#lifeSpan: Option<Rangef>,
+    pub r#attractors: Option<Vec<ParticleAttractor>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Rangef {
+    pub r#min: f32,
+    pub r#max: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct InitialVelocity {
+    pub r#yaw: Option<Rangef>,
+    pub r#pitch: Option<Rangef>,
+    pub r#speed: Option<Rangef>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct RangeVector3f {
+    pub r#x: Option<Rangef>,
+    pub r#y: Option<Rangef>,
+    pub r#z: Option<Rangef>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ParticleAttractor {
+    pub r#position: Option<Vector3f>,
+    pub r#radialAxis: Option<Vector3f>,
+    pub r#trailPositionMultiplier: f32,
+    pub r#radius: f32,
+    pub r#radialAcceleration: f32,
+    pub r#radialTangentAcceleration: f32,
+    pub r#linearAcceleration: Option<Vector3f>,
+    pub r#radialImpulse: f32,
+    pub r#radialTangentImpulse: f32,
+    pub r#linearImpulse: Option<Vector3f>,
+    pub r#dampingMultiplier: Option<Vector3f>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateParticleSpawners {
+    pub r#type: UpdateType,
+    pub r#particleSpawners: Option<Dictionary<ByteString, ParticleSpawner>>,
+    pub r#removedParticleSpawners: Option<Vec<ByteString>>,
+}
+
+impl Packet for UpdateParticleSpawners {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateParticleSpawners",
+        id: 50,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ParticleSpawner {
+    pub r#id: Option<ByteString>,
+    pub r#particle: Option<Particle>,
+    pub r#shape: EmitShape,
+    pub r#emitOffset: Option<RangeVector3f>,
+    pub r#cameraOffset: f32,
+    pub r#useEmitDirection: bool,
+    pub r#lifeSpan: f32,
+    pub r#spawnRate: Option<Rangef>,
+    pub r#spawnBurst: bool,
+    pub r#waveDelay: Option<Rangef>,
+    pub r#totalParticles: Option<Range>,
+    pub r#maxConcurrentParticles: i32,
+    pub r#initialVelocity: Option<InitialVelocity>,
+    pub r#velocityStretchMultiplier: f32,
+    pub r#particleRotationInfluence: ParticleRotationInfluence,
+    pub r#particleRotateWithSpawner: bool,
+    pub r#isLowRes: bool,
+    pub r#trailSpawnerPositionMultiplier: f32,
+    pub r#trailSpawnerRotationMultiplier: f32,
+    pub r#particleCollision: Option<ParticleCollision>,
+    pub r#renderMode: FXRenderMode,
+    pub r#lightInfluence: f32,
+    pub r#linearFiltering: bool,
+    pub r#particleLifeSpan: Option<Rangef>,
+    pub r#uvMotion: Option<UVMotion>,
+    pub r#attractors: Option<Vec<ParticleAttractor>>,
+    pub r#intersectionHighlight: Option<IntersectionHighlight>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Particle {
+    pub r#texturePath: Option<ByteString>,
+    pub r#frameSize: Option<Size>,
+    pub r#uvOption: ParticleUVOption,
+    pub r#scaleRatioConstraint: ParticleScaleRatioConstraint,
+    pub r#softParticles: SoftParticle,
+    pub r#softParticlesFadeFactor: f32,
+    pub r#useSpriteBlending: bool,
+    pub r#initialAnimationFrame: Option<ParticleAnimationFrame>,
+    pub r#collisionAnimationFrame: Option<ParticleAnimationFrame>,
+    pub r#animationFrames: Option<Dictionary<i32, ParticleAnimationFrame>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Size {
+    pub r#width: i32,
+    pub r#height: i32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ParticleUVOption {
+    #[default]
+    r#None,
+    r#RandomFlipU,
+    r#RandomFlipV,
+    r#RandomFlipUV,
+    r#FlipU,
+    r#FlipV,
+    r#FlipUV,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ParticleScaleRatioConstraint {
+    #[default]
+    r#OneToOne,
+    r#Preserved,
+    r#None,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum SoftParticle {
+    #[default]
+    r#Enable,
+    r#Disable,
+    r#Require,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct ParticleAnimationFrame {
+    pub r#frameIndex: Option<Range>,
+    pub r#scale: Option<RangeVector2f>,
+    pub r#rotation: Option<RangeVector3f>,
+    pub r#color: Option<Color>,
+    pub r#opacity: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct RangeVector2f {
+    pub r#x: Option<Rangef>,
+    pub r#y: Option<Rangef>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EmitShape {
+    #[default]
+    r#Sphere,
+    r#Cube,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ParticleRotationInfluence {
+    #[default]
+    r#None,
+    r#Billboard,
+    r#BillboardY,
+    r#BillboardVelocity,
+    r#Velocity,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct ParticleCollision {
+    pub r#blockType: ParticleCollisionBlockType,
+    pub r#action: ParticleCollisionAction,
+    pub r#particleRotationInfluence: ParticleRotationInfluence,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ParticleCollisionBlockType {
+    #[default]
+    r#None,
+    r#Air,
+    r#Solid,
+    r#All,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ParticleCollisionAction {
+    #[default]
+    r#Expire,
+    r#LastFrame,
+    r#Linger,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UVMotion {
+    pub r#texture: Option<ByteString>,
+    pub r#addRandomUVOffset: bool,
+    pub r#speedX: f32,
+    pub r#speedY: f32,
+    pub r#scale: f32,
+    pub r#strength: f32,
+    pub r#strengthCurveType: UVMotionCurveType,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum UVMotionCurveType {
+    #[default]
+    r#Constant,
+    r#IncreaseLinear,
+    r#IncreaseQuartIn,
+    r#IncreaseQuartInOut,
+    r#IncreaseQuartOut,
+    r#DecreaseLinear,
+    r#DecreaseQuartIn,
+    r#DecreaseQuartInOut,
+    r#DecreaseQuartOut,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateEntityEffects {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#entityEffects: Option<Dictionary<i32, EntityEffect>>,
+}
+
+impl Packet for UpdateEntityEffects {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateEntityEffects",
+        id: 51,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityEffect {
+    pub r#id: Option<ByteString>,
+    pub r#name: Option<ByteString>,
+    pub r#applicationEffects: Option<ApplicationEffects>,
+    pub r#worldRemovalSoundEventIndex: i32,
+    pub r#localRemovalSoundEventIndex: i32,
+    pub r#modelOverride: Option<ModelOverride>,
+    pub r#duration: f32,
+    pub r#infinite: bool,
+    pub r#debuff: bool,
+    pub r#statusEffectIcon: Option<ByteString>,
+    pub r#overlapBehavior: OverlapBehavior,
+    pub r#damageCalculatorCooldown: f64,
+    pub r#statModifiers: Option<Dictionary<i32, f32>>,
+    pub r#valueType: ValueType,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ApplicationEffects {
+    pub r#entityBottomTint: Option<Color>,
+    pub r#entityTopTint: Option<Color>,
+    pub r#entityAnimationId: Option<ByteString>,
+    pub r#particles: Option<Vec<ModelParticle>>,
+    pub r#firstPersonParticles: Option<Vec<ModelParticle>>,
+    pub r#screenEffect: Option<ByteString>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#soundEventIndexLocal: i32,
+    pub r#soundEventIndexWorld: i32,
+    pub r#modelVFXId: Option<ByteString>,
+    pub r#movementEffects: Option<MovementEffects>,
+    pub r#mouseSensitivityAdjustmentTarget: f32,
+    pub r#mouseSensitivityAdjustmentDuration: f32,
+    pub r#abilityEffects: Option<AbilityEffects>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct MovementEffects {
+    pub r#disableForward: bool,
+    pub r#disableBackward: bool,
+    pub r#disableLeft: bool,
+    pub r#disableRight: bool,
+    pub r#disableSprint: bool,
+    pub r#disableJump: bool,
+    pub r#disableCrouch: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AbilityEffects {
+    pub r#disabled: Option<Vec<InteractionType>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ModelOverride {
+    pub r#model: Option<ByteString>,
+    pub r#texture: Option<ByteString>,
+    pub r#animationSets: Option<Dictionary<ByteString, AnimationSet>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AnimationSet {
+    pub r#id: Option<ByteString>,
+    pub r#animations: Option<Vec<Animation>>,
+    pub r#nextAnimationDelay: Option<Rangef>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Animation {
+    pub r#name: Option<ByteString>,
+    pub r#speed: f32,
+    pub r#blendingDuration: f32,
+    pub r#looping: bool,
+    pub r#weight: f32,
+    pub r#footstepIntervals: Option<Vec<i32>>,
+    pub r#soundEventIndex: i32,
+    pub r#passiveLoopCount: i32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum OverlapBehavior {
+    #[default]
+    r#Extend,
+    r#Overwrite,
+    r#Ignore,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ValueType {
+    #[default]
+    r#Percent,
+    r#Absolute,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateItemPlayerAnimations {
+    pub r#type: UpdateType,
+    pub r#itemPlayerAnimations: Option<Dictionary<ByteString, ItemPlayerAnimations>>,
+}
+
+impl Packet for UpdateItemPlayerAnimations {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateItemPlayerAnimations",
+        id: 52,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemPlayerAnimations {
+    pub r#id: Option<ByteString>,
+    pub r#animations: Option<Dictionary<ByteString, ItemAnimation>>,
+    pub r#wiggleWeights: Option<WiggleWeights>,
+    pub r#camera: Option<CameraSettings>,
+    pub r#pullbackConfig: Option<ItemPullbackConfiguration>,
+    pub r#useFirstPersonOverride: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemAnimation {
+    pub r#thirdPerson: Option<ByteString>,
+    pub r#thirdPersonMoving: Option<ByteString>,
+    pub r#thirdPersonFace: Option<ByteString>,
+    pub r#firstPerson: Option<ByteString>,
+    pub r#firstPersonOverride: Option<ByteString>,
+    pub r#keepPreviousFirstPersonAnimation: bool,
+    pub r#speed: f32,
+    pub r#blendingDuration: f32,
+    pub r#looping: bool,
+    pub r#clipsGeometry: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct WiggleWeights {
+    pub r#x: f32,
+    pub r#xDeceleration: f32,
+    pub r#y: f32,
+    pub r#yDeceleration: f32,
+    pub r#z: f32,
+    pub r#zDeceleration: f32,
+    pub r#roll: f32,
+    pub r#rollDeceleration: f32,
+    pub r#pitch: f32,
+    pub r#pitchDeceleration: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CameraSettings {
+    pub r#positionOffset: Option<Vector3f>,
+    pub r#yaw: Option<CameraAxis>,
+    pub r#pitch: Option<CameraAxis>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CameraAxis {
+    pub r#angleRange: Option<Rangef>,
+    pub r#targetNodes: Option<Vec<CameraNode>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CameraNode {
+    #[default]
+    r#None,
+    r#Head,
+    r#LShoulder,
+    r#RShoulder,
+    r#Belly,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct ItemPullbackConfiguration {
+    pub r#leftOffsetOverride: Option<Vector3f>,
+    pub r#leftRotationOverride: Option<Vector3f>,
+    pub r#rightOffsetOverride: Option<Vector3f>,
+    pub r#rightRotationOverride: Option<Vector3f>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateModelvfxs {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#modelVFXs: Option<Dictionary<i32, ModelVFX>>,
+}
+
+impl Packet for UpdateModelvfxs {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateModelvfxs",
+        id: 53,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ModelVFX {
+    pub r#id: Option<ByteString>,
+    pub r#switchTo: SwitchTo,
+    pub r#effectDirection: EffectDirection,
+    pub r#animationDuration: f32,
+    pub r#animationRange: Option<Vector2f>,
+    pub r#loopOption: LoopOption,
+    pub r#curveType: CurveType,
+    pub r#highlightColor: Option<Color>,
+    pub r#highlightThickness: f32,
+    pub r#useBloomOnHighlight: bool,
+    pub r#useProgessiveHighlight: bool,
+    pub r#noiseScale: Option<Vector2f>,
+    pub r#noiseScrollSpeed: Option<Vector2f>,
+    pub r#postColor: Option<Color>,
+    pub r#postColorOpacity: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum SwitchTo {
+    #[default]
+    r#Disappear,
+    r#PostColor,
+    r#Distortion,
+    r#Transparency,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EffectDirection {
+    #[default]
+    r#None,
+    r#BottomUp,
+    r#TopDown,
+    r#ToCenter,
+    r#FromCenter,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Vector2f {
+    pub r#x: f32,
+    pub r#y: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum LoopOption {
+    #[default]
+    r#PlayOnce,
+    r#Loop,
+    r#LoopMirror,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CurveType {
+    #[default]
+    r#Linear,
+    r#QuartIn,
+    r#QuartOut,
+    r#QuartInOut,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateItems {
+    pub r#type: UpdateType,
+    pub r#items: Option<Dictionary<ByteString, ItemBase>>,
+    pub r#removedItems: Option<Vec<ByteString>>,
+    pub r#updateModels: bool,
+    pub r#updateIcons: bool,
+}
+
+impl Packet for UpdateItems {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateItems",
+        id: 54,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemBase {
+    pub r#id: Option<ByteString>,
+    pub r#model: Option<ByteString>,
+    pub r#scale: f32,
+    pub r#texture: Option<ByteString>,
+    pub r#animation: Option<ByteString>,
+    pub r#playerAnimationsId: Option<ByteString>,
+    pub r#usePlayerAnimations: bool,
+    pub r#maxStack: i32,
+    pub r#reticleIndex: i32,
+    pub r#icon: Option<ByteString>,
+    pub r#iconProperties: Option<AssetIconProperties>,
+    pub r#translationProperties: Option<ItemTranslationProperties>,
+    pub r#itemLevel: i32,
+    pub r#qualityIndex: i32,
+    pub r#resourceTypes: Option<Vec<ItemResourceType>>,
+    pub r#consumable: bool,
+    pub r#variant: bool,
+    pub r#blockId: i32,
+    pub r#tool: Option<ItemTool>,
+    pub r#weapon: Option<ItemWeapon>,
+    pub r#armor: Option<ItemArmor>,
+    pub r#gliderConfig: Option<ItemGlider>,
+    pub r#utility: Option<ItemUtility>,
+    pub r#blockSelectorTool: Option<BlockSelectorToolData>,
+    pub r#builderToolData: Option<ItemBuilderToolData>,
+    pub r#itemEntity: Option<ItemEntityConfig>,
+    pub r#set: Option<ByteString>,
+    pub r#categories: Option<Vec<ByteString>>,
+    pub r#particles: Option<Vec<ModelParticle>>,
+    pub r#firstPersonParticles: Option<Vec<ModelParticle>>,
+    pub r#trails: Option<Vec<ModelTrail>>,
+    pub r#light: Option<ColorLight>,
+    pub r#durability: f64,
+    pub r#soundEventIndex: i32,
+    pub r#itemSoundSetIndex: i32,
+    pub r#interactions: Option<Dictionary<InteractionType, i32>>,
+    pub r#interactionVars: Option<Dictionary<ByteString, i32>>,
+    pub r#interactionConfig: Option<InteractionConfiguration>,
+    pub r#droppedItemAnimation: Option<ByteString>,
+    pub r#tagIndexes: Option<Vec<i32>>,
+    pub r#itemAppearanceConditions: Option<Dictionary<i32, Vec<ItemAppearanceCondition>>>,
+    pub r#displayEntityStatsHUD: Option<Vec<i32>>,
+    pub r#pullbackConfig: Option<ItemPullbackConfiguration>,
+    pub r#clipsGeometry: bool,
+    pub r#renderDeployablePreview: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct AssetIconProperties {
+    pub r#scale: f32,
+    pub r#translation: Option<Vector2f>,
+    pub r#rotation: Option<Vector3f>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemTranslationProperties {
+    pub r#name: Option<ByteString>,
+    pub r#description: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemResourceType {
+    pub r#id: Option<ByteString>,
+    pub r#quantity: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemTool {
+    pub r#specs: Option<Vec<ItemToolSpec>>,
+    pub r#speed: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemToolSpec {
+    pub r#gatherType: Option<ByteString>,
+    pub r#power: f32,
+    pub r#quality: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemWeapon {
+    pub r#entityStatsToClear: Option<Vec<i32>>,
+    pub r#statModifiers: Option<Dictionary<i32, Vec<Modifier>>>,
+    pub r#renderDualWielded: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Modifier {
+    pub r#target: ModifierTarget,
+    pub r#calculationType: CalculationType,
+    pub r#amount: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ModifierTarget {
+    #[default]
+    r#Min,
+    r#Max,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CalculationType {
+    #[default]
+    r#Additive,
+    r#Multiplicative,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemArmor {
+    pub r#armorSlot: ItemArmorSlot,
+    pub r#cosmeticsToHide: Option<Vec<Cosmetic>>,
+    pub r#statModifiers: Option<Dictionary<i32, Vec<Modifier>>>,
+    pub r#baseDamageResistance: f64,
+    pub r#damageResistance: Option<Dictionary<ByteString, Vec<Modifier>>>,
+    pub r#damageEnhancement: Option<Dictionary<ByteString, Vec<Modifier>>>,
+    pub r#damageClassEnhancement: Option<Dictionary<ByteString, Vec<Modifier>>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ItemArmorSlot {
+    #[default]
+    r#Head,
+    r#Chest,
+    r#Hands,
+    r#Legs,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum Cosmetic {
+    #[default]
+    r#Haircut,
+    r#FacialHair,
+    r#Undertop,
+    r#Overtop,
+    r#Pants,
+    r#Overpants,
+    r#Shoes,
+    r#Gloves,
+    r#Cape,
+    r#HeadAccessory,
+    r#FaceAccessory,
+    r#EarAccessory,
+    r#Ear,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct ItemGlider {
+    pub r#terminalVelocity: f32,
+    pub r#fallSpeedMultiplier: f32,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#speed: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemUtility {
+    pub r#usable: bool,
+    pub r#compatible: bool,
+    pub r#entityStatsToClear: Option<Vec<i32>>,
+    pub r#statModifiers: Option<Dictionary<i32, Vec<Modifier>>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BlockSelectorToolData {
+    pub r#durabilityLossOnUse: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemBuilderToolData {
+    pub r#ui: Option<Vec<ByteString>>,
+    pub r#tools: Option<Vec<BuilderToolState>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolState {
+    pub r#id: Option<ByteString>,
+    pub r#isBrush: bool,
+    pub r#brushData: Option<BuilderToolBrushData>,
+    pub r#args: Option<Dictionary<ByteString, BuilderToolArg>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolBrushData {
+    pub r#width: Option<BuilderToolIntArg>,
+    pub r#height: Option<BuilderToolIntArg>,
+    pub r#thickness: Option<BuilderToolIntArg>,
+    pub r#capped: Option<BuilderToolBoolArg>,
+    pub r#shape: Option<BuilderToolBrushShapeArg>,
+    pub r#origin: Option<BuilderToolBrushOriginArg>,
+    pub r#originRotation: Option<BuilderToolBoolArg>,
+    pub r#rotationAxis: Option<BuilderToolBrushAxisArg>,
+    pub r#rotationAngle: Option<BuilderToolRotationArg>,
+    pub r#mirrorAxis: Option<BuilderToolBrushAxisArg>,
+    pub r#material: Option<BuilderToolBlockArg>,
+    pub r#favoriteMaterials: Option<Vec<BuilderToolBlockArg>>,
+    pub r#mask: Option<BuilderToolMaskArg>,
+    pub r#maskAbove: Option<BuilderToolMaskArg>,
+    pub r#maskNot: Option<BuilderToolMaskArg>,
+    pub r#maskBelow: Option<BuilderToolMaskArg>,
+    pub r#maskAdjacent: Option<BuilderToolMaskArg>,
+    pub r#maskNeighbor: Option<BuilderToolMaskArg>,
+    pub r#maskCommands: Option<Vec<BuilderToolStringArg>>,
+    pub r#useMaskCommands: Option<BuilderToolBoolArg>,
+    pub r#invertMask: Option<BuilderToolBoolArg>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BuilderToolIntArg {
+    pub r#defaultValue: i32,
+    pub r#min: i32,
+    pub r#max: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BuilderToolBoolArg {
+    pub r#defaultValue: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BuilderToolBrushShapeArg {
+    pub r#defaultValue: BrushShape,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BrushShape {
+    #[default]
+    r#Cube,
+    r#Sphere,
+    r#Cylinder,
+    r#Cone,
+    r#InvertedCone,
+    r#Pyramid,
+    r#InvertedPyramid,
+    r#Dome,
+    r#InvertedDome,
+    r#Diamond,
+    r#Torus,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BuilderToolBrushOriginArg {
+    pub r#defaultValue: BrushOrigin,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BrushOrigin {
+    #[default]
+    r#Center,
+    r#Bottom,
+    r#Top,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BuilderToolBrushAxisArg {
+    pub r#defaultValue: BrushAxis,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BrushAxis {
+    #[default]
+    r#None,
+    r#Auto,
+    r#X,
+    r#Y,
+    r#Z,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BuilderToolRotationArg {
+    pub r#defaultValue: Rotation,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolBlockArg {
+    pub r#defaultValue: Option<ByteString>,
+    pub r#allowPattern: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolMaskArg {
+    pub r#defaultValue: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolStringArg {
+    pub r#defaultValue: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolArg {
+    pub r#required: bool,
+    pub r#argType: BuilderToolArgType,
+    pub r#boolArg: Option<BuilderToolBoolArg>,
+    pub r#floatArg: Option<BuilderToolFloatArg>,
+    pub r#intArg: Option<BuilderToolIntArg>,
+    pub r#stringArg: Option<BuilderToolStringArg>,
+    pub r#blockArg: Option<BuilderToolBlockArg>,
+    pub r#maskArg: Option<BuilderToolMaskArg>,
+    pub r#brushShapeArg: Option<BuilderToolBrushShapeArg>,
+    pub r#brushOriginArg: Option<BuilderToolBrushOriginArg>,
+    pub r#brushAxisArg: Option<BuilderToolBrushAxisArg>,
+    pub r#rotationArg: Option<BuilderToolRotationArg>,
+    pub r#optionArg: Option<BuilderToolOptionArg>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BuilderToolArgType {
+    #[default]
+    r#Bool,
+    r#Float,
+    r#Int,
+    r#ByteString,
+    r#Block,
+    r#Mask,
+    r#BrushShape,
+    r#BrushOrigin,
+    r#BrushAxis,
+    r#Rotation,
+    r#Option,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolFloatArg {
+    pub r#defaultValue: f32,
+    pub r#min: f32,
+    pub r#max: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolOptionArg {
+    pub r#defaultValue: Option<ByteString>,
+    pub r#options: Option<Vec<ByteString>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemEntityConfig {
+    pub r#particleSystemId: Option<ByteString>,
+    pub r#particleColor: Option<Color>,
+    pub r#showItemParticles: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ModelTrail {
+    pub r#trailId: Option<ByteString>,
+    pub r#targetEntityPart: EntityPart,
+    pub r#targetNodeName: Option<ByteString>,
+    pub r#positionOffset: Option<Vector3f>,
+    pub r#rotationOffset: Option<Direction>,
+    pub r#fixedRotation: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionConfiguration {
+    pub r#displayOutlines: bool,
+    pub r#debugOutlines: bool,
+    pub r#useDistance: Option<Dictionary<GameMode, f32>>,
+    pub r#allEntities: bool,
+    pub r#priorities: Option<Dictionary<InteractionType, InteractionPriority>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum GameMode {
+    #[default]
+    r#Adventure,
+    r#Creative,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionPriority {
+    pub r#values: Option<Dictionary<PrioritySlot, i32>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum PrioritySlot {
+    #[default]
+    r#Default,
+    r#MainHand,
+    r#OffHand,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemAppearanceCondition {
+    pub r#particles: Option<Vec<ModelParticle>>,
+    pub r#firstPersonParticles: Option<Vec<ModelParticle>>,
+    pub r#model: Option<ByteString>,
+    pub r#texture: Option<ByteString>,
+    pub r#modelVFXId: Option<ByteString>,
+    pub r#condition: Option<FloatRange>,
+    pub r#conditionValueType: ValueType,
+    pub r#localSoundEventId: i32,
+    pub r#worldSoundEventId: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateItemQualities {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#itemQualities: Option<Dictionary<i32, ItemQuality>>,
+}
+
+impl Packet for UpdateItemQualities {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateItemQualities",
+        id: 55,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemQuality {
+    pub r#id: Option<ByteString>,
+    pub r#itemTooltipTexture: Option<ByteString>,
+    pub r#itemTooltipArrowTexture: Option<ByteString>,
+    pub r#slotTexture: Option<ByteString>,
+    pub r#blockSlotTexture: Option<ByteString>,
+    pub r#specialSlotTexture: Option<ByteString>,
+    pub r#textColor: Option<Color>,
+    pub r#localizationKey: Option<ByteString>,
+    pub r#visibleQualityLabel: bool,
+    pub r#renderSpecialSlot: bool,
+    pub r#hideFromSearch: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateItemCategories {
+    pub r#type: UpdateType,
+    pub r#itemCategories: Option<Vec<ItemCategory>>,
+}
+
+impl Packet for UpdateItemCategories {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateItemCategories",
+        id: 56,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemCategory {
+    pub r#id: Option<ByteString>,
+    pub r#name: Option<ByteString>,
+    pub r#icon: Option<ByteString>,
+    pub r#order: i32,
+    pub r#infoDisplayMode: ItemGridInfoDisplayMode,
+    pub r#children: Option<Vec<ItemCategory>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ItemGridInfoDisplayMode {
+    #[default]
+    r#Tooltip,
+    r#Adjacent,
+    r#None,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateItemReticles {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#itemReticleConfigs: Option<Dictionary<i32, ItemReticleConfig>>,
+}
+
+impl Packet for UpdateItemReticles {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateItemReticles",
+        id: 57,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemReticleConfig {
+    pub r#id: Option<ByteString>,
+    pub r#base: Option<Vec<ByteString>>,
+    pub r#serverEvents: Option<Dictionary<i32, ItemReticle>>,
+    pub r#clientEvents: Option<Dictionary<ItemReticleClientEvent, ItemReticle>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemReticle {
+    pub r#hideBase: bool,
+    pub r#parts: Option<Vec<ByteString>>,
+    pub r#duration: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ItemReticleClientEvent {
+    #[default]
+    r#OnHit,
+    r#Wielding,
+    r#OnMovementLeft,
+    r#OnMovementRight,
+    r#OnMovementBack,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateFieldcraftCategories {
+    pub r#type: UpdateType,
+    pub r#itemCategories: Option<Vec<ItemCategory>>,
+}
+
+impl Packet for UpdateFieldcraftCategories {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateFieldcraftCategories",
+        id: 58,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateResourceTypes {
+    pub r#type: UpdateType,
+    pub r#resourceTypes: Option<Dictionary<ByteString, ResourceType>>,
+}
+
+impl Packet for UpdateResourceTypes {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateResourceTypes",
+        id: 59,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ResourceType {
+    pub r#id: Option<ByteString>,
+    pub r#icon: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateRecipes {
+    pub r#type: UpdateType,
+    pub r#recipes: Option<Dictionary<ByteString, CraftingRecipe>>,
+    pub r#removedRecipes: Option<Vec<ByteString>>,
+}
+
+impl Packet for UpdateRecipes {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateRecipes",
+        id: 60,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CraftingRecipe {
+    pub r#id: Option<ByteString>,
+    pub r#inputs: Option<Vec<MaterialQuantity>>,
+    pub r#outputs: Option<Vec<MaterialQuantity>>,
+    pub r#primaryOutput: Option<MaterialQuantity>,
+    pub r#benchRequirement: Option<Vec<BenchRequirement>>,
+    pub r#knowledgeRequired: bool,
+    pub r#timeSeconds: f32,
+    pub r#requiredMemoriesLevel: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BenchRequirement {
+    pub r#type: BenchType,
+    pub r#id: Option<ByteString>,
+    pub r#categories: Option<Vec<ByteString>>,
+    pub r#requiredTierLevel: i32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BenchType {
+    #[default]
+    r#Crafting,
+    r#Processing,
+    r#DiagramCrafting,
+    r#StructuralCrafting,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateEnvironments {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#environments: Option<Dictionary<i32, WorldEnvironment>>,
+    pub r#rebuildMapGeometry: bool,
+}
+
+impl Packet for UpdateEnvironments {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateEnvironments",
+        id: 61,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct WorldEnvironment {
+    pub r#id: Option<ByteString>,
+    pub r#waterTint: Option<Color>,
+    pub r#fluidParticles: Option<Dictionary<i32, FluidParticle>>,
+    pub r#tagIndexes: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct FluidParticle {
+    pub r#systemId: Option<ByteString>,
+    pub r#color: Option<Color>,
+    pub r#scale: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateAmbienceFX {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#ambienceFX: Option<Dictionary<i32, AmbienceFX>>,
+}
+
+impl Packet for UpdateAmbienceFX {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateAmbienceFX",
+        id: 62,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AmbienceFX {
+    pub r#id: Option<ByteString>,
+    pub r#conditions: Option<AmbienceFXConditions>,
+    pub r#sounds: Option<Vec<AmbienceFXSound>>,
+    pub r#music: Option<AmbienceFXMusic>,
+    pub r#ambientBed: Option<AmbienceFXAmbientBed>,
+    pub r#soundEffect: Option<AmbienceFXSoundEffect>,
+    pub r#priority: i32,
+    pub r#blockedAmbienceFxIndices: Option<Vec<i32>>,
+    pub r#audioCategoryIndex: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AmbienceFXConditions {
+    pub r#never: bool,
+    pub r#environmentIndices: Option<Vec<i32>>,
+    pub r#weatherIndices: Option<Vec<i32>>,
+    pub r#fluidFXIndices: Option<Vec<i32>>,
+    pub r#environmentTagPatternIndex: i32,
+    pub r#weatherTagPatternIndex: i32,
+    pub r#surroundingBlockSoundSets: Option<Vec<AmbienceFXBlockSoundSet>>,
+    pub r#altitude: Option<Range>,
+    pub r#walls: Option<Rangeb>,
+    pub r#roof: bool,
+    pub r#roofMaterialTagPatternIndex: i32,
+    pub r#floor: bool,
+    pub r#sunLightLevel: Option<Rangeb>,
+    pub r#torchLightLevel: Option<Rangeb>,
+    pub r#globalLightLevel: Option<Rangeb>,
+    pub r#dayTime: Option<Rangef>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AmbienceFXBlockSoundSet {
+    pub r#blockSoundSetIndex: i32,
+    pub r#percent: Option<Rangef>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Rangeb {
+    pub r#min: i8,
+    pub r#max: i8,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AmbienceFXSound {
+    pub r#soundEventIndex: i32,
+    pub r#play3D: AmbienceFXSoundPlay3D,
+    pub r#blockSoundSetIndex: i32,
+    pub r#altitude: AmbienceFXAltitude,
+    pub r#frequency: Option<Rangef>,
+    pub r#radius: Option<Range>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum AmbienceFXSoundPlay3D {
+    #[default]
+    r#Random,
+    r#LocationName,
+    r#No,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum AmbienceFXAltitude {
+    #[default]
+    r#Normal,
+    r#Lowest,
+    r#Highest,
+    r#Random,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AmbienceFXMusic {
+    pub r#tracks: Option<Vec<ByteString>>,
+    pub r#volume: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AmbienceFXAmbientBed {
+    pub r#track: Option<ByteString>,
+    pub r#volume: f32,
+    pub r#transitionSpeed: AmbienceTransitionSpeed,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum AmbienceTransitionSpeed {
+    #[default]
+    r#Default,
+    r#Fast,
+    r#Instant,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct AmbienceFXSoundEffect {
+    pub r#reverbEffectIndex: i32,
+    pub r#equalizerEffectIndex: i32,
+    pub r#isInstant: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateFluidFX {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#fluidFX: Option<Dictionary<i32, FluidFX>>,
+}
+
+impl Packet for UpdateFluidFX {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateFluidFX",
+        id: 63,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct FluidFX {
+    pub r#id: Option<ByteString>,
+    pub r#shader: ShaderType,
+    pub r#fogMode: FluidFog,
+    pub r#fogColor: Option<Color>,
+    pub r#fogDistance: Option<NearFar>,
+    pub r#fogDepthStart: f32,
+    pub r#fogDepthFalloff: f32,
+    pub r#colorFilter: Option<Color>,
+    pub r#colorSaturation: f32,
+    pub r#distortionAmplitude: f32,
+    pub r#distortionFrequency: f32,
+    pub r#particle: Option<FluidParticle>,
+    pub r#movementSettings: Option<FluidFXMovementSettings>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum FluidFog {
+    #[default]
+    r#Color,
+    r#ColorLight,
+    r#EnvironmentTint,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct FluidFXMovementSettings {
+    pub r#swimUpSpeed: f32,
+    pub r#swimDownSpeed: f32,
+    pub r#sinkSpeed: f32,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#fieldOfViewMultiplier: f32,
+    pub r#entryVelocityMultiplier: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateTranslations {
+    pub r#type: UpdateType,
+    pub r#translations: Option<Dictionary<ByteString, ByteString>>,
+}
+
+impl Packet for UpdateTranslations {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateTranslations",
+        id: 64,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateSoundEvents {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#soundEvents: Option<Dictionary<i32, SoundEvent>>,
+}
+
+impl Packet for UpdateSoundEvents {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateSoundEvents",
+        id: 65,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SoundEvent {
+    pub r#id: Option<ByteString>,
+    pub r#volume: f32,
+    pub r#pitch: f32,
+    pub r#musicDuckingVolume: f32,
+    pub r#ambientDuckingVolume: f32,
+    pub r#maxInstance: i32,
+    pub r#preventSoundInterruption: bool,
+    pub r#startAttenuationDistance: f32,
+    pub r#maxDistance: f32,
+    pub r#layers: Option<Vec<SoundEventLayer>>,
+    pub r#audioCategory: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SoundEventLayer {
+    pub r#volume: f32,
+    pub r#startDelay: f32,
+    pub r#looping: bool,
+    pub r#probability: i32,
+    pub r#probabilityRerollDelay: f32,
+    pub r#roundRobinHistorySize: i32,
+    pub r#randomSettings: Option<SoundEventLayerRandomSettings>,
+    pub r#files: Option<Vec<ByteString>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct SoundEventLayerRandomSettings {
+    pub r#minVolume: f32,
+    pub r#maxVolume: f32,
+    pub r#minPitch: f32,
+    pub r#maxPitch: f32,
+    pub r#maxStartOffset: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateInteractions {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#interactions: Option<Dictionary<i32, Interaction>>,
+}
+
+impl Packet for UpdateInteractions {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateInteractions",
+        id: 66,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub enum Interaction {
+    SimpleBlockInteraction(Box<SimpleBlockInteraction>),
+    SimpleInteraction(Box<SimpleInteraction>),
+    PlaceBlockInteraction(Box<PlaceBlockInteraction>),
+    BreakBlockInteraction(Box<BreakBlockInteraction>),
+    PickBlockInteraction(Box<PickBlockInteraction>),
+    UseBlockInteraction(Box<UseBlockInteraction>),
+    UseEntityInteraction(Box<UseEntityInteraction>),
+    BuilderToolInteraction(Box<BuilderToolInteraction>),
+    ModifyInventoryInteraction(Box<ModifyInventoryInteraction>),
+    ChargingInteraction(Box<ChargingInteraction>),
+    WieldingInteraction(Box<WieldingInteraction>),
+    ChainingInteraction(Box<ChainingInteraction>),
+    ConditionInteraction(Box<ConditionInteraction>),
+    StatsConditionInteraction(Box<StatsConditionInteraction>),
+    BlockConditionInteraction(Box<BlockConditionInteraction>),
+    ReplaceInteraction(Box<ReplaceInteraction>),
+    ChangeBlockInteraction(Box<ChangeBlockInteraction>),
+    ChangeStateInteraction(Box<ChangeStateInteraction>),
+    FirstClickInteraction(Box<FirstClickInteraction>),
+    SelectInteraction(Box<SelectInteraction>),
+    DamageEntityInteraction(Box<DamageEntityInteraction>),
+    RepeatInteraction(Box<RepeatInteraction>),
+    ParallelInteraction(Box<ParallelInteraction>),
+    ChangeActiveSlotInteraction(Box<ChangeActiveSlotInteraction>),
+    EffectConditionInteraction(Box<EffectConditionInteraction>),
+    ApplyForceInteraction(Box<ApplyForceInteraction>),
+    ApplyEffectInteraction(Box<ApplyEffectInteraction>),
+    ClearEntityEffectInteraction(Box<ClearEntityEffectInteraction>),
+    SerialInteraction(Box<SerialInteraction>),
+    ChangeStatInteraction(Box<ChangeStatInteraction>),
+    MovementConditionInteraction(Box<MovementConditionInteraction>),
+    ProjectileInteraction(Box<ProjectileInteraction>),
+    RemoveEntityInteraction(Box<RemoveEntityInteraction>),
+    ResetCooldownInteraction(Box<ResetCooldownInteraction>),
+    TriggerCooldownInteraction(Box<TriggerCooldownInteraction>),
+    CooldownConditionInteraction(Box<CooldownConditionInteraction>),
+    ChainFlagInteraction(Box<ChainFlagInteraction>),
+    IncrementCooldownInteraction(Box<IncrementCooldownInteraction>),
+    CancelChainInteraction(Box<CancelChainInteraction>),
+    RunRootInteraction(Box<RunRootInteraction>),
+    CameraInteraction(Box<CameraInteraction>),
+    SpawnDeployableFromRaycastInteraction(Box<SpawnDeployableFromRaycastInteraction>),
+    MemoriesConditionInteraction(Box<MemoriesConditionInteraction>),
+    ToggleGliderInteraction(Box<ToggleGliderInteraction>),
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SimpleBlockInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#useLatestTarget: bool,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum WaitForDataFrom {
+    #[default]
+    r#Client,
+    r#Server,
+    r#None,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionEffects {
+    pub r#particles: Option<Vec<ModelParticle>>,
+    pub r#firstPersonParticles: Option<Vec<ModelParticle>>,
+    pub r#worldSoundEventIndex: i32,
+    pub r#localSoundEventIndex: i32,
+    pub r#trails: Option<Vec<ModelTrail>>,
+    pub r#waitForAnimationToFinish: bool,
+    pub r#itemPlayerAnimationsId: Option<ByteString>,
+    pub r#itemAnimationId: Option<ByteString>,
+    pub r#clearAnimationOnFinish: bool,
+    pub r#clearSoundEventOnFinish: bool,
+    pub r#cameraShake: Option<CameraShakeEffect>,
+    pub r#movementEffects: Option<MovementEffects>,
+    pub r#startDelay: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct CameraShakeEffect {
+    pub r#cameraShakeId: i32,
+    pub r#intensity: f32,
+    pub r#mode: AccumulationMode,
+}
+
+impl Packet for CameraShakeEffect {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "CameraShakeEffect",
+        id: 281,
+        is_compressed: false,
+        max_size: 9,
+        category: PacketCategory::CAMERA,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum AccumulationMode {
+    #[default]
+    r#Set,
+    r#Sum,
+    r#Average,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionSettings {
+    pub r#allowSkipOnClick: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionRules {
+    pub r#blockedBy: Option<Vec<InteractionType>>,
+    pub r#blocking: Option<Vec<InteractionType>>,
+    pub r#interruptedBy: Option<Vec<InteractionType>>,
+    pub r#interrupting: Option<Vec<InteractionType>>,
+    pub r#blockedByBypassIndex: i32,
+    pub r#blockingBypassIndex: i32,
+    pub r#interruptedByBypassIndex: i32,
+    pub r#interruptingBypassIndex: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionCameraSettings {
+    pub r#firstPerson: Option<Vec<InteractionCamera>>,
+    pub r#thirdPerson: Option<Vec<InteractionCamera>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionCamera {
+    pub r#time: f32,
+    pub r#position: Option<Vector3f>,
+    pub r#rotation: Option<Direction>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SimpleInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PlaceBlockInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#blockId: i32,
+    pub r#removeItemInHand: bool,
+    pub r#allowDragPlacement: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BreakBlockInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#useLatestTarget: bool,
+    pub r#harvest: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PickBlockInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#useLatestTarget: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UseBlockInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#useLatestTarget: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UseEntityInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ModifyInventoryInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#requiredGameMode: Option<GameMode>,
+    pub r#itemToRemove: Option<ItemWithAllMetadata>,
+    pub r#adjustHeldItemQuantity: i32,
+    pub r#itemToAdd: Option<ItemWithAllMetadata>,
+    pub r#brokenItem: Option<ByteString>,
+    pub r#adjustHeldItemDurability: f64,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemWithAllMetadata {
+    pub r#itemId: ByteString,
+    pub r#quantity: i32,
+    pub r#durability: f64,
+    pub r#maxDurability: f64,
+    pub r#overrideDroppedItemAnimation: bool,
+    pub r#metadata: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChargingInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#failed: i32,
+    pub r#allowIndefiniteHold: bool,
+    pub r#displayProgress: bool,
+    pub r#cancelOnOtherClick: bool,
+    pub r#failOnDamage: bool,
+    pub r#mouseSensitivityAdjustmentTarget: f32,
+    pub r#mouseSensitivityAdjustmentDuration: f32,
+    pub r#chargedNext: Option<Dictionary<f32, i32>>,
+    pub r#forks: Option<Dictionary<InteractionType, i32>>,
+    pub r#chargingDelay: Option<ChargingDelay>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChargingDelay {
+    pub r#minDelay: f32,
+    pub r#maxDelay: f32,
+    pub r#maxTotalDelay: f32,
+    pub r#minHealth: f32,
+    pub r#maxHealth: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct WieldingInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#failed: i32,
+    pub r#allowIndefiniteHold: bool,
+    pub r#displayProgress: bool,
+    pub r#cancelOnOtherClick: bool,
+    pub r#failOnDamage: bool,
+    pub r#mouseSensitivityAdjustmentTarget: f32,
+    pub r#mouseSensitivityAdjustmentDuration: f32,
+    pub r#chargedNext: Option<Dictionary<f32, i32>>,
+    pub r#forks: Option<Dictionary<InteractionType, i32>>,
+    pub r#chargingDelay: Option<ChargingDelay>,
+    pub r#blockedEffects: Option<DamageEffects>,
+    pub r#hasModifiers: bool,
+    pub r#angledWielding: Option<AngledWielding>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DamageEffects {
+    pub r#modelParticles: Option<Vec<ModelParticle>>,
+    pub r#worldParticles: Option<Vec<WorldParticle>>,
+    pub r#soundEventIndex: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct WorldParticle {
+    pub r#systemId: Option<ByteString>,
+    pub r#scale: f32,
+    pub r#color: Option<Color>,
+    pub r#positionOffset: Option<Vector3f>,
+    pub r#rotationOffset: Option<Direction>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AngledWielding {
+    pub r#angleRad: f32,
+    pub r#angleDistanceRad: f32,
+    pub r#hasModifiers: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChainingInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#chainId: Option<ByteString>,
+    pub r#chainingAllowance: f32,
+    pub r#chainingNext: Option<Vec<i32>>,
+    pub r#flags: Option<Dictionary<ByteString, i32>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ConditionInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#requiredGameMode: Option<GameMode>,
+    pub r#jumping: Option<bool>,
+    pub r#swimming: Option<bool>,
+    pub r#crouching: Option<bool>,
+    pub r#running: Option<bool>,
+    pub r#flying: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct StatsConditionInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#costs: Option<Dictionary<i32, f32>>,
+    pub r#lessThan: bool,
+    pub r#lenient: bool,
+    pub r#valueType: ValueType,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockConditionInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#useLatestTarget: bool,
+    pub r#matchers: Option<Vec<BlockMatcher>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockMatcher {
+    pub r#block: Option<BlockIdMatcher>,
+    pub r#face: BlockFace,
+    pub r#staticFace: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockIdMatcher {
+    pub r#id: Option<ByteString>,
+    pub r#state: Option<ByteString>,
+    pub r#tagIndex: i32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BlockFace {
+    #[default]
+    r#None,
+    r#Up,
+    r#Down,
+    r#North,
+    r#South,
+    r#East,
+    r#West,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ReplaceInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#defaultValue: i32,
+    pub r#variable: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChangeBlockInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#useLatestTarget: bool,
+    pub r#blockChanges: Option<Dictionary<i32, i32>>,
+    pub r#worldSoundEventIndex: i32,
+    pub r#requireNotBroken: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChangeStateInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#useLatestTarget: bool,
+    pub r#stateChanges: Option<Dictionary<ByteString, ByteString>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct FirstClickInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#click: i32,
+    pub r#held: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SelectInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#selector: Option<Selector>,
+    pub r#ignoreOwner: bool,
+    pub r#hitEntity: i32,
+    pub r#hitEntityRules: Option<Vec<HitEntity>>,
+    pub r#failOn: FailOnType,
+}
+
+#[derive(Debug, Clone, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub enum Selector {
+    AOECircleSelector(Box<AOECircleSelector>),
+    AOECylinderSelector(Box<AOECylinderSelector>),
+    RaycastSelector(Box<RaycastSelector>),
+    HorizontalSelector(Box<HorizontalSelector>),
+    StabSelector(Box<StabSelector>),
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AOECircleSelector {
+    pub r#range: f32,
+    pub r#offset: Option<Vector3f>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AOECylinderSelector {
+    pub r#range: f32,
+    pub r#height: f32,
+    pub r#offset: Option<Vector3f>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RaycastSelector {
+    pub r#offset: Option<Vector3f>,
+    pub r#distance: i32,
+    pub r#blockTagIndex: i32,
+    pub r#ignoreFluids: bool,
+    pub r#ignoreEmptyCollisionMaterial: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct HorizontalSelector {
+    pub r#extendTop: f32,
+    pub r#extendBottom: f32,
+    pub r#yawLength: f32,
+    pub r#yawStartOffset: f32,
+    pub r#pitchOffset: f32,
+    pub r#rollOffset: f32,
+    pub r#startDistance: f32,
+    pub r#endDistance: f32,
+    pub r#direction: HorizontalSelectorDirection,
+    pub r#testLineOfSight: bool,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum HorizontalSelectorDirection {
+    #[default]
+    r#ToLeft,
+    r#ToRight,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct StabSelector {
+    pub r#extendTop: f32,
+    pub r#extendBottom: f32,
+    pub r#extendLeft: f32,
+    pub r#extendRight: f32,
+    pub r#yawOffset: f32,
+    pub r#pitchOffset: f32,
+    pub r#rollOffset: f32,
+    pub r#startDistance: f32,
+    pub r#endDistance: f32,
+    pub r#testLineOfSight: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct HitEntity {
+    pub r#next: i32,
+    pub r#matchers: Option<Vec<EntityMatcher>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityMatcher {
+    pub r#type: EntityMatcherType,
+    pub r#invert: bool,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EntityMatcherType {
+    #[default]
+    r#Server,
+    r#VulnerableMatcher,
+    r#Player,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum FailOnType {
+    #[default]
+    r#Neither,
+    r#Entity,
+    r#Block,
+    r#Either,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DamageEntityInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#blocked: i32,
+    pub r#damageEffects: Option<DamageEffects>,
+    pub r#angledDamage: Option<Vec<AngledDamage>>,
+    pub r#targetedDamage: Option<Dictionary<ByteString, TargetedDamage>>,
+    pub r#entityStatsOnHit: Option<Vec<EntityStatOnHit>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AngledDamage {
+    pub r#angle: f64,
+    pub r#angleDistance: f64,
+    pub r#damageEffects: Option<DamageEffects>,
+    pub r#next: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct TargetedDamage {
+    pub r#index: i32,
+    pub r#damageEffects: Option<DamageEffects>,
+    pub r#next: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityStatOnHit {
+    pub r#entityStatIndex: i32,
+    pub r#amount: f32,
+    pub r#multipliersPerEntitiesHit: Option<Vec<f32>>,
+    pub r#multiplierPerExtraEntityHit: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RepeatInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#forkInteractions: i32,
+    pub r#repeat: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ParallelInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChangeActiveSlotInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#targetSlot: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EffectConditionInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#entityEffects: Option<Vec<i32>>,
+    pub r#match: Match,
+    pub r#entityTarget: InteractionTarget,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum Match {
+    #[default]
+    r#All,
+    r#None,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum InteractionTarget {
+    #[default]
+    r#User,
+    r#Owner,
+    r#Target,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ApplyForceInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#velocityConfig: Option<VelocityConfig>,
+    pub r#changeVelocityType: ChangeVelocityType,
+    pub r#forces: Option<Vec<AppliedForce>>,
+    pub r#duration: f32,
+    pub r#verticalClamp: Option<FloatRange>,
+    pub r#waitForGround: bool,
+    pub r#waitForCollision: bool,
+    pub r#groundCheckDelay: f32,
+    pub r#collisionCheckDelay: f32,
+    pub r#groundNext: i32,
+    pub r#collisionNext: i32,
+    pub r#raycastDistance: f32,
+    pub r#raycastHeightOffset: f32,
+    pub r#raycastMode: RaycastMode,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct VelocityConfig {
+    pub r#groundResistance: f32,
+    pub r#groundResistanceMax: f32,
+    pub r#airResistance: f32,
+    pub r#airResistanceMax: f32,
+    pub r#threshold: f32,
+    pub r#style: VelocityThresholdStyle,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum VelocityThresholdStyle {
+    #[default]
+    r#Linear,
+    r#Exp,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ChangeVelocityType {
+    #[default]
+    r#Add,
+    r#Set,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AppliedForce {
+    pub r#direction: Option<Vector3f>,
+    pub r#adjustVertical: bool,
+    pub r#force: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum RaycastMode {
+    #[default]
+    r#FollowMotion,
+    r#FollowLook,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ApplyEffectInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#effectId: i32,
+    pub r#entityTarget: InteractionTarget,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClearEntityEffectInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#effectId: i32,
+    pub r#entityTarget: InteractionTarget,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SerialInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#serialInteractions: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChangeStatInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#entityTarget: InteractionTarget,
+    pub r#valueType: ValueType,
+    pub r#statModifiers: Option<Dictionary<i32, f32>>,
+    pub r#changeStatBehaviour: ChangeStatBehaviour,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ChangeStatBehaviour {
+    #[default]
+    r#Add,
+    r#Set,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MovementConditionInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#forward: i32,
+    pub r#back: i32,
+    pub r#left: i32,
+    pub r#right: i32,
+    pub r#forwardLeft: i32,
+    pub r#forwardRight: i32,
+    pub r#backLeft: i32,
+    pub r#backRight: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ProjectileInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#configId: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RemoveEntityInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#entityTarget: InteractionTarget,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ResetCooldownInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#cooldown: Option<InteractionCooldown>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionCooldown {
+    pub r#cooldownId: Option<ByteString>,
+    pub r#cooldown: f32,
+    pub r#clickBypass: bool,
+    pub r#chargeTimes: Option<Vec<f32>>,
+    pub r#skipCooldownReset: bool,
+    pub r#interruptRecharge: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct TriggerCooldownInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#cooldown: Option<InteractionCooldown>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CooldownConditionInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#cooldownId: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChainFlagInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#chainId: Option<ByteString>,
+    pub r#flag: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct IncrementCooldownInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#cooldownId: Option<ByteString>,
+    pub r#cooldownIncrementTime: f32,
+    pub r#cooldownIncrementCharge: i32,
+    pub r#cooldownIncrementChargeTime: f32,
+    pub r#cooldownIncrementInterrupt: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CancelChainInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#chainId: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RunRootInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#rootInteraction: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CameraInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#cameraAction: CameraActionType,
+    pub r#cameraPerspective: CameraPerspectiveType,
+    pub r#cameraPersist: bool,
+    pub r#cameraInteractionTime: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CameraActionType {
+    #[default]
+    r#ForcePerspective,
+    r#Orbit,
+    r#Transition,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CameraPerspectiveType {
+    #[default]
+    r#First,
+    r#Third,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SpawnDeployableFromRaycastInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+    pub r#deployableConfig: Option<DeployableConfig>,
+    pub r#maxDistance: f32,
+    pub r#costs: Option<Dictionary<i32, f32>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DeployableConfig {
+    pub r#model: Option<Model>,
+    pub r#modelPreview: Option<Model>,
+    pub r#allowPlaceOnWalls: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Model {
+    pub r#assetId: Option<ByteString>,
+    pub r#path: Option<ByteString>,
+    pub r#texture: Option<ByteString>,
+    pub r#gradientSet: Option<ByteString>,
+    pub r#gradientId: Option<ByteString>,
+    pub r#camera: Option<CameraSettings>,
+    pub r#scale: f32,
+    pub r#eyeHeight: f32,
+    pub r#crouchOffset: f32,
+    pub r#animationSets: Option<Dictionary<ByteString, AnimationSet>>,
+    pub r#attachments: Option<Vec<ModelAttachment>>,
+    pub r#hitbox: Option<Hitbox>,
+    pub r#particles: Option<Vec<ModelParticle>>,
+    pub r#trails: Option<Vec<ModelTrail>>,
+    pub r#light: Option<ColorLight>,
+    pub r#detailBoxes: Option<Dictionary<ByteString, Vec<DetailBox>>>,
+    pub r#phobia: Phobia,
+    pub r#phobiaModel: Option<Box<Model>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ModelAttachment {
+    pub r#model: Option<ByteString>,
+    pub r#texture: Option<ByteString>,
+    pub r#gradientSet: Option<ByteString>,
+    pub r#gradientId: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DetailBox {
+    pub r#offset: Option<Vector3f>,
+    pub r#box: Option<Hitbox>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum Phobia {
+    #[default]
+    r#None,
+    r#Arachnophobia,
+    r#Ophidiophobia,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MemoriesConditionInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#memoriesNext: Option<Dictionary<i32, i32>>,
+    pub r#failed: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ToggleGliderInteraction {
+    pub r#waitForDataFrom: WaitForDataFrom,
+    pub r#effects: Option<InteractionEffects>,
+    pub r#horizontalSpeedMultiplier: f32,
+    pub r#runTime: f32,
+    pub r#cancelOnItemChange: bool,
+    pub r#settings: Option<Dictionary<GameMode, InteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#camera: Option<InteractionCameraSettings>,
+    pub r#next: i32,
+    pub r#failed: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateRootInteractions {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#interactions: Option<Dictionary<i32, RootInteraction>>,
+}
+
+impl Packet for UpdateRootInteractions {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateRootInteractions",
+        id: 67,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RootInteraction {
+    pub r#id: Option<ByteString>,
+    pub r#interactions: Option<Vec<i32>>,
+    pub r#cooldown: Option<InteractionCooldown>,
+    pub r#settings: Option<Dictionary<GameMode, RootInteractionSettings>>,
+    pub r#rules: Option<InteractionRules>,
+    pub r#tags: Option<Vec<i32>>,
+    pub r#clickQueuingTimeout: f32,
+    pub r#requireNewClick: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RootInteractionSettings {
+    pub r#allowSkipChainOnClick: bool,
+    pub r#cooldown: Option<InteractionCooldown>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateUnarmedInteractions {
+    pub r#type: UpdateType,
+    pub r#interactions: Option<Dictionary<InteractionType, i32>>,
+}
+
+impl Packet for UpdateUnarmedInteractions {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateUnarmedInteractions",
+        id: 68,
+        is_compressed: true,
+        max_size: 20480007,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct TrackOrUpdateObjective {
+    pub r#objective: Option<Objective>,
+}
+
+impl Packet for TrackOrUpdateObjective {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "TrackOrUpdateObjective",
+        id: 69,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Objective {
+    pub r#objectiveUuid: Uuid,
+    pub r#objectiveTitleKey: Option<ByteString>,
+    pub r#objectiveDescriptionKey: Option<ByteString>,
+    pub r#objectiveLineId: Option<ByteString>,
+    pub r#tasks: Option<Vec<ObjectiveTask>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ObjectiveTask {
+    pub r#taskDescriptionKey: Option<ByteString>,
+    pub r#currentCompletion: i32,
+    pub r#completionNeeded: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UntrackObjective {
+    pub r#objectiveUuid: Uuid,
+}
+
+impl Packet for UntrackObjective {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UntrackObjective",
+        id: 70,
+        is_compressed: false,
+        max_size: 16,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateObjectiveTask {
+    pub r#objectiveUuid: Uuid,
+    pub r#taskIndex: i32,
+    pub r#task: Option<ObjectiveTask>,
+}
+
+impl Packet for UpdateObjectiveTask {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateObjectiveTask",
+        id: 71,
+        is_compressed: false,
+        max_size: 16384035,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateEntityStatTypes {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#types: Option<Dictionary<i32, EntityStatType>>,
+}
+
+impl Packet for UpdateEntityStatTypes {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateEntityStatTypes",
+        id: 72,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityStatType {
+    pub r#id: Option<ByteString>,
+    pub r#value: f32,
+    pub r#min: f32,
+    pub r#max: f32,
+    pub r#minValueEffects: Option<EntityStatEffects>,
+    pub r#maxValueEffects: Option<EntityStatEffects>,
+    pub r#resetBehavior: EntityStatResetBehavior,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityStatEffects {
+    pub r#triggerAtZero: bool,
+    pub r#soundEventIndex: i32,
+    pub r#particles: Option<Vec<ModelParticle>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EntityStatResetBehavior {
+    #[default]
+    r#InitialValue,
+    r#MaxValue,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateEntityUIComponents {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#components: Option<Dictionary<i32, EntityUIComponent>>,
+}
+
+impl Packet for UpdateEntityUIComponents {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateEntityUIComponents",
+        id: 73,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityUIComponent {
+    pub r#type: EntityUIType,
+    pub r#hitboxOffset: Option<Vector2f>,
+    pub r#unknown: bool,
+    pub r#entityStatIndex: i32,
+    pub r#combatTextRandomPositionOffsetRange: Option<RangeVector2f>,
+    pub r#combatTextViewportMargin: f32,
+    pub r#combatTextDuration: f32,
+    pub r#combatTextHitAngleModifierStrength: f32,
+    pub r#combatTextFontSize: f32,
+    pub r#combatTextColor: Option<Color>,
+    pub r#combatTextAnimationEvents: Option<Vec<CombatTextEntityUIComponentAnimationEvent>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EntityUIType {
+    #[default]
+    r#EntityStat,
+    r#CombatText,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CombatTextEntityUIComponentAnimationEvent {
+    pub r#type: CombatTextEntityUIAnimationEventType,
+    pub r#startAt: f32,
+    pub r#endAt: f32,
+    pub r#startScale: f32,
+    pub r#endScale: f32,
+    pub r#positionOffset: Option<Vector2f>,
+    pub r#startOpacity: f32,
+    pub r#endOpacity: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CombatTextEntityUIAnimationEventType {
+    #[default]
+    r#Scale,
+    r#Position,
+    r#Opacity,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateHitboxCollisionConfig {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#hitboxCollisionConfigs: Option<Dictionary<i32, HitboxCollisionConfig>>,
+}
+
+impl Packet for UpdateHitboxCollisionConfig {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateHitboxCollisionConfig",
+        id: 74,
+        is_compressed: true,
+        max_size: 36864011,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct HitboxCollisionConfig {
+    pub r#collisionType: CollisionType,
+    pub r#softCollisionOffsetRatio: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CollisionType {
+    #[default]
+    r#Hard,
+    r#Soft,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateRepulsionConfig {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#repulsionConfigs: Option<Dictionary<i32, RepulsionConfig>>,
+}
+
+impl Packet for UpdateRepulsionConfig {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateRepulsionConfig",
+        id: 75,
+        is_compressed: true,
+        max_size: 65536011,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RepulsionConfig {
+    pub r#radius: f32,
+    pub r#minForce: f32,
+    pub r#maxForce: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateViewBobbing {
+    pub r#type: UpdateType,
+    pub r#profiles: Option<Dictionary<MovementType, ViewBobbing>>,
+}
+
+impl Packet for UpdateViewBobbing {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateViewBobbing",
+        id: 76,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum MovementType {
+    #[default]
+    r#None,
+    r#Idle,
+    r#Crouching,
+    r#Walking,
+    r#Running,
+    r#Sprinting,
+    r#Climbing,
+    r#Swimming,
+    r#Flying,
+    r#Sliding,
+    r#Rolling,
+    r#Mounting,
+    r#SprintMounting,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ViewBobbing {
+    pub r#firstPerson: Option<CameraShakeConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CameraShakeConfig {
+    pub r#duration: f32,
+    pub r#startTime: f32,
+    pub r#continuous: bool,
+    pub r#easeIn: Option<EasingConfig>,
+    pub r#easeOut: Option<EasingConfig>,
+    pub r#offset: Option<OffsetNoise>,
+    pub r#rotation: Option<RotationNoise>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct EasingConfig {
+    pub r#time: f32,
+    pub r#type: EasingType,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EasingType {
+    #[default]
+    r#Linear,
+    r#QuadIn,
+    r#QuadOut,
+    r#QuadInOut,
+    r#CubicIn,
+    r#CubicOut,
+    r#CubicInOut,
+    r#QuartIn,
+    r#QuartOut,
+    r#QuartInOut,
+    r#QuintIn,
+    r#QuintOut,
+    r#QuintInOut,
+    r#SineIn,
+    r#SineOut,
+    r#SineInOut,
+    r#ExpoIn,
+    r#ExpoOut,
+    r#ExpoInOut,
+    r#CircIn,
+    r#CircOut,
+    r#CircInOut,
+    r#ElasticIn,
+    r#ElasticOut,
+    r#ElasticInOut,
+    r#BackIn,
+    r#BackOut,
+    r#BackInOut,
+    r#BounceIn,
+    r#BounceOut,
+    r#BounceInOut,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct OffsetNoise {
+    pub r#x: Option<Vec<NoiseConfig>>,
+    pub r#y: Option<Vec<NoiseConfig>>,
+    pub r#z: Option<Vec<NoiseConfig>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct NoiseConfig {
+    pub r#seed: i32,
+    pub r#type: NoiseType,
+    pub r#frequency: f32,
+    pub r#amplitude: f32,
+    pub r#clamp: Option<ClampConfig>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum NoiseType {
+    #[default]
+    r#Sin,
+    r#Cos,
+    r#Perlin_Linear,
+    r#Perlin_Hermite,
+    r#Perlin_Quintic,
+    r#Random,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct ClampConfig {
+    pub r#min: f32,
+    pub r#max: f32,
+    pub r#normalize: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RotationNoise {
+    pub r#pitch: Option<Vec<NoiseConfig>>,
+    pub r#yaw: Option<Vec<NoiseConfig>>,
+    pub r#roll: Option<Vec<NoiseConfig>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateCameraShake {
+    pub r#type: UpdateType,
+    pub r#profiles: Option<Dictionary<i32, CameraShake>>,
+}
+
+impl Packet for UpdateCameraShake {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateCameraShake",
+        id: 77,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CameraShake {
+    pub r#firstPerson: Option<CameraShakeConfig>,
+    pub r#thirdPerson: Option<CameraShakeConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateBlockGroups {
+    pub r#type: UpdateType,
+    pub r#groups: Option<Dictionary<ByteString, BlockGroup>>,
+}
+
+impl Packet for UpdateBlockGroups {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateBlockGroups",
+        id: 78,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockGroup {
+    pub r#names: Option<Vec<ByteString>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateSoundSets {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#soundSets: Option<Dictionary<i32, SoundSet>>,
+}
+
+impl Packet for UpdateSoundSets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateSoundSets",
+        id: 79,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SoundSet {
+    pub r#id: Option<ByteString>,
+    pub r#sounds: Option<Dictionary<ByteString, i32>>,
+    pub r#category: SoundCategory,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum SoundCategory {
+    #[default]
+    r#Music,
+    r#Ambient,
+    r#SFX,
+    r#UI,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateAudioCategories {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#categories: Option<Dictionary<i32, AudioCategory>>,
+}
+
+impl Packet for UpdateAudioCategories {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateAudioCategories",
+        id: 80,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AudioCategory {
+    pub r#id: Option<ByteString>,
+    pub r#volume: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateReverbEffects {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#effects: Option<Dictionary<i32, ReverbEffect>>,
+}
+
+impl Packet for UpdateReverbEffects {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateReverbEffects",
+        id: 81,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ReverbEffect {
+    pub r#id: Option<ByteString>,
+    pub r#dryGain: f32,
+    pub r#modalDensity: f32,
+    pub r#diffusion: f32,
+    pub r#gain: f32,
+    pub r#highFrequencyGain: f32,
+    pub r#decayTime: f32,
+    pub r#highFrequencyDecayRatio: f32,
+    pub r#reflectionGain: f32,
+    pub r#reflectionDelay: f32,
+    pub r#lateReverbGain: f32,
+    pub r#lateReverbDelay: f32,
+    pub r#roomRolloffFactor: f32,
+    pub r#airAbsorptionHighFrequencyGain: f32,
+    pub r#limitDecayHighFrequency: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateEqualizerEffects {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#effects: Option<Dictionary<i32, EqualizerEffect>>,
+}
+
+impl Packet for UpdateEqualizerEffects {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateEqualizerEffects",
+        id: 82,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EqualizerEffect {
+    pub r#id: Option<ByteString>,
+    pub r#lowGain: f32,
+    pub r#lowCutOff: f32,
+    pub r#lowMidGain: f32,
+    pub r#lowMidCenter: f32,
+    pub r#lowMidWidth: f32,
+    pub r#highMidGain: f32,
+    pub r#highMidCenter: f32,
+    pub r#highMidWidth: f32,
+    pub r#highGain: f32,
+    pub r#highCutOff: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateFluids {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#fluids: Option<Dictionary<i32, Fluid>>,
+}
+
+impl Packet for UpdateFluids {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateFluids",
+        id: 83,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Fluid {
+    pub r#id: Option<ByteString>,
+    pub r#maxFluidLevel: i32,
+    pub r#cubeTextures: Option<Vec<BlockTextures>>,
+    pub r#requiresAlphaBlending: bool,
+    pub r#opacity: Opacity,
+    pub r#shaderEffect: Option<Vec<ShaderType>>,
+    pub r#light: Option<ColorLight>,
+    pub r#fluidFXIndex: i32,
+    pub r#blockSoundSetIndex: i32,
+    pub r#blockParticleSetId: Option<ByteString>,
+    pub r#particleColor: Option<Color>,
+    pub r#tagIndexes: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateTagPatterns {
+    pub r#type: UpdateType,
+    pub r#maxId: i32,
+    pub r#patterns: Option<Dictionary<i32, TagPattern>>,
+}
+
+impl Packet for UpdateTagPatterns {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateTagPatterns",
+        id: 84,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct TagPattern {
+    pub r#type: TagPatternType,
+    pub r#tagIndex: i32,
+    pub r#operands: Option<Vec<TagPattern>>,
+    pub r#not: Option<Box<TagPattern>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum TagPatternType {
+    #[default]
+    r#Equals,
+    r#And,
+    r#Or,
+    r#Not,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateProjectileConfigs {
+    pub r#type: UpdateType,
+    pub r#configs: Option<Dictionary<ByteString, ProjectileConfig>>,
+    pub r#removedConfigs: Option<Vec<ByteString>>,
+}
+
+impl Packet for UpdateProjectileConfigs {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateProjectileConfigs",
+        id: 85,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSETS,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ProjectileConfig {
+    pub r#physicsConfig: Option<PhysicsConfig>,
+    pub r#model: Option<Model>,
+    pub r#launchForce: f64,
+    pub r#spawnOffset: Option<Vector3f>,
+    pub r#rotationOffset: Option<Direction>,
+    pub r#interactions: Option<Dictionary<InteractionType, i32>>,
+    pub r#launchLocalSoundEventIndex: i32,
+    pub r#projectileSoundEventIndex: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct PhysicsConfig {
+    pub r#type: PhysicsType,
+    pub r#density: f64,
+    pub r#gravity: f64,
+    pub r#bounciness: f64,
+    pub r#bounceCount: i32,
+    pub r#bounceLimit: f64,
+    pub r#sticksVertically: bool,
+    pub r#computeYaw: bool,
+    pub r#computePitch: bool,
+    pub r#rotationMode: RotationMode,
+    pub r#moveOutOfSolidSpeed: f64,
+    pub r#terminalVelocityAir: f64,
+    pub r#densityAir: f64,
+    pub r#terminalVelocityWater: f64,
+    pub r#densityWater: f64,
+    pub r#hitWaterImpulseLoss: f64,
+    pub r#rotationForce: f64,
+    pub r#speedRotationFactor: f32,
+    pub r#swimmingDampingFactor: f64,
+    pub r#allowRolling: bool,
+    pub r#rollingFrictionFactor: f64,
+    pub r#rollingSpeed: f32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum PhysicsType {
+    #[default]
+    r#Standard,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum RotationMode {
+    #[default]
+    r#None,
+    r#Velocity,
+    r#VelocityDamped,
+    r#VelocityRoll,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetClientId {
+    pub r#clientId: i32,
+}
+
+impl Packet for SetClientId {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetClientId",
+        id: 100,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetGameMode {
+    pub r#gameMode: GameMode,
+}
+
+impl Packet for SetGameMode {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetGameMode",
+        id: 101,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetMovementStates {
+    pub r#movementStates: Option<SavedMovementStates>,
+}
+
+impl Packet for SetMovementStates {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetMovementStates",
+        id: 102,
+        is_compressed: false,
+        max_size: 2,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct SavedMovementStates {
+    pub r#flying: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetBlockPlacementOverride {
+    pub r#enabled: bool,
+}
+
+impl Packet for SetBlockPlacementOverride {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetBlockPlacementOverride",
+        id: 103,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct JoinWorld {
+    pub r#clearWorld: bool,
+    pub r#fadeInOut: bool,
+    pub r#worldUuid: Uuid,
+}
+
+impl Packet for JoinWorld {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "JoinWorld",
+        id: 104,
+        is_compressed: false,
+        max_size: 18,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClientReady {
+    pub r#readyForChunks: bool,
+    pub r#readyForGameplay: bool,
+}
+
+impl Packet for ClientReady {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ClientReady",
+        id: 105,
+        is_compressed: false,
+        max_size: 2,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct LoadHotbar {
+    pub r#inventoryRow: i8,
+}
+
+impl Packet for LoadHotbar {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "LoadHotbar",
+        id: 106,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SaveHotbar {
+    pub r#inventoryRow: i8,
+}
+
+impl Packet for SaveHotbar {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SaveHotbar",
+        id: 107,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClientMovement {
+    pub r#movementStates: Option<MovementStates>,
+    pub r#relativePosition: Option<HalfFloatPosition>,
+    pub r#absolutePosition: Option<Position>,
+    pub r#bodyOrientation: Option<Direction>,
+    pub r#lookOrientation: Option<Direction>,
+    pub r#teleportAck: Option<TeleportAck>,
+    pub r#wishMovement: Option<Position>,
+    pub r#velocity: Option<Vector3d>,
+    pub r#mountedTo: i32,
+    pub r#riderMovementStates: Option<MovementStates>,
+}
+
+impl Packet for ClientMovement {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ClientMovement",
+        id: 108,
+        is_compressed: false,
+        max_size: 153,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct MovementStates {
+    pub r#idle: bool,
+    pub r#horizontalIdle: bool,
+    pub r#jumping: bool,
+    pub r#flying: bool,
+    pub r#walking: bool,
+    pub r#running: bool,
+    pub r#sprinting: bool,
+    pub r#crouching: bool,
+    pub r#forcedCrouching: bool,
+    pub r#falling: bool,
+    pub r#climbing: bool,
+    pub r#inFluid: bool,
+    pub r#swimming: bool,
+    pub r#swimJumping: bool,
+    pub r#onGround: bool,
+    pub r#mantling: bool,
+    pub r#sliding: bool,
+    pub r#mounting: bool,
+    pub r#rolling: bool,
+    pub r#sitting: bool,
+    pub r#gliding: bool,
+    pub r#sleeping: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct HalfFloatPosition {
+    pub r#x: half::f16,
+    pub r#y: half::f16,
+    pub r#z: half::f16,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Position {
+    pub r#x: f64,
+    pub r#y: f64,
+    pub r#z: f64,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct TeleportAck {
+    pub r#teleportId: i8,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Vector3d {
+    pub r#x: f64,
+    pub r#y: f64,
+    pub r#z: f64,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClientTeleport {
+    pub r#teleportId: i8,
+    pub r#modelTransform: Option<ModelTransform>,
+    pub r#resetVelocity: bool,
+}
+
+impl Packet for ClientTeleport {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ClientTeleport",
+        id: 109,
+        is_compressed: false,
+        max_size: 52,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct ModelTransform {
+    pub r#position: Option<Position>,
+    pub r#bodyOrientation: Option<Direction>,
+    pub r#lookOrientation: Option<Direction>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateMovementSettings {
+    pub r#movementSettings: Option<MovementSettings>,
+}
+
+impl Packet for UpdateMovementSettings {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateMovementSettings",
+        id: 110,
+        is_compressed: false,
+        max_size: 252,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct MovementSettings {
+    pub r#mass: f32,
+    pub r#dragCoefficient: f32,
+    pub r#invertedGravity: bool,
+    pub r#velocityResistance: f32,
+    pub r#jumpForce: f32,
+    pub r#swimJumpForce: f32,
+    pub r#jumpBufferDuration: f32,
+    pub r#jumpBufferMaxYVelocity: f32,
+    pub r#acceleration: f32,
+    pub r#airDragMin: f32,
+    pub r#airDragMax: f32,
+    pub r#airDragMinSpeed: f32,
+    pub r#airDragMaxSpeed: f32,
+    pub r#airFrictionMin: f32,
+    pub r#airFrictionMax: f32,
+    pub r#airFrictionMinSpeed: f32,
+    pub r#airFrictionMaxSpeed: f32,
+    pub r#airSpeedMultiplier: f32,
+    pub r#airControlMinSpeed: f32,
+    pub r#airControlMaxSpeed: f32,
+    pub r#airControlMinMultiplier: f32,
+    pub r#airControlMaxMultiplier: f32,
+    pub r#comboAirSpeedMultiplier: f32,
+    pub r#baseSpeed: f32,
+    pub r#climbSpeed: f32,
+    pub r#climbSpeedLateral: f32,
+    pub r#climbUpSprintSpeed: f32,
+    pub r#climbDownSprintSpeed: f32,
+    pub r#horizontalFlySpeed: f32,
+    pub r#verticalFlySpeed: f32,
+    pub r#maxSpeedMultiplier: f32,
+    pub r#minSpeedMultiplier: f32,
+    pub r#wishDirectionGravityX: f32,
+    pub r#wishDirectionGravityY: f32,
+    pub r#wishDirectionWeightX: f32,
+    pub r#wishDirectionWeightY: f32,
+    pub r#canFly: bool,
+    pub r#collisionExpulsionForce: f32,
+    pub r#forwardWalkSpeedMultiplier: f32,
+    pub r#backwardWalkSpeedMultiplier: f32,
+    pub r#strafeWalkSpeedMultiplier: f32,
+    pub r#forwardRunSpeedMultiplier: f32,
+    pub r#backwardRunSpeedMultiplier: f32,
+    pub r#strafeRunSpeedMultiplier: f32,
+    pub r#forwardCrouchSpeedMultiplier: f32,
+    pub r#backwardCrouchSpeedMultiplier: f32,
+    pub r#strafeCrouchSpeedMultiplier: f32,
+    pub r#forwardSprintSpeedMultiplier: f32,
+    pub r#variableJumpFallForce: f32,
+    pub r#fallEffectDuration: f32,
+    pub r#fallJumpForce: f32,
+    pub r#fallMomentumLoss: f32,
+    pub r#autoJumpObstacleSpeedLoss: f32,
+    pub r#autoJumpObstacleSprintSpeedLoss: f32,
+    pub r#autoJumpObstacleEffectDuration: f32,
+    pub r#autoJumpObstacleSprintEffectDuration: f32,
+    pub r#autoJumpObstacleMaxAngle: f32,
+    pub r#autoJumpDisableJumping: bool,
+    pub r#minSlideEntrySpeed: f32,
+    pub r#slideExitSpeed: f32,
+    pub r#minFallSpeedToEngageRoll: f32,
+    pub r#maxFallSpeedToEngageRoll: f32,
+    pub r#rollStartSpeedModifier: f32,
+    pub r#rollExitSpeedModifier: f32,
+    pub r#rollTimeToComplete: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MouseInteraction {
+    pub r#clientTimestamp: i64,
+    pub r#activeSlot: i32,
+    pub r#itemInHandId: Option<ByteString>,
+    pub r#screenPoint: Option<Vector2f>,
+    pub r#mouseButton: Option<MouseButtonEvent>,
+    pub r#mouseMotion: Option<MouseMotionEvent>,
+    pub r#worldInteraction: Option<WorldInteraction>,
+}
+
+impl Packet for MouseInteraction {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "MouseInteraction",
+        id: 111,
+        is_compressed: false,
+        max_size: 20480071,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct MouseButtonEvent {
+    pub r#mouseButtonType: MouseButtonType,
+    pub r#state: MouseButtonState,
+    pub r#clicks: i8,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum MouseButtonType {
+    #[default]
+    r#Left,
+    r#Middle,
+    r#Right,
+    r#X1,
+    r#X2,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum MouseButtonState {
+    #[default]
+    r#Pressed,
+    r#Released,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MouseMotionEvent {
+    pub r#mouseButtonType: Option<Vec<MouseButtonType>>,
+    pub r#relativeMotion: Option<Vector2i>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct WorldInteraction {
+    pub r#entityId: i32,
+    pub r#blockPosition: Option<BlockPosition>,
+    pub r#blockRotation: Option<BlockRotation>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BlockPosition {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BlockRotation {
+    pub r#rotationYaw: Rotation,
+    pub r#rotationPitch: Rotation,
+    pub r#rotationRoll: Rotation,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DamageInfo {
+    pub r#damageSourcePosition: Option<Vector3d>,
+    pub r#damageAmount: f32,
+    pub r#damageCause: Option<DamageCause>,
+}
+
+impl Packet for DamageInfo {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "DamageInfo",
+        id: 112,
+        is_compressed: false,
+        max_size: 32768048,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DamageCause {
+    pub r#id: Option<ByteString>,
+    pub r#damageTextColor: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ReticleEvent {
+    pub r#eventIndex: i32,
+}
+
+impl Packet for ReticleEvent {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ReticleEvent",
+        id: 113,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DisplayDebug {
+    pub r#shape: DebugShape,
+    pub r#matrix: Option<Vec<f32>>,
+    pub r#color: Option<Vector3f>,
+    pub r#time: f32,
+    pub r#fade: bool,
+    pub r#frustumProjection: Option<Vec<f32>>,
+}
+
+impl Packet for DisplayDebug {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "DisplayDebug",
+        id: 114,
+        is_compressed: false,
+        max_size: 32768037,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum DebugShape {
+    #[default]
+    r#Sphere,
+    r#Cylinder,
+    r#Cone,
+    r#Cube,
+    r#Frustum,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClearDebugShapes {}
+
+impl Packet for ClearDebugShapes {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ClearDebugShapes",
+        id: 115,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SyncPlayerPreferences {
+    pub r#showEntityMarkers: bool,
+    pub r#armorItemsPreferredPickupLocation: PickupLocation,
+    pub r#weaponAndToolItemsPreferredPickupLocation: PickupLocation,
+    pub r#usableItemsItemsPreferredPickupLocation: PickupLocation,
+    pub r#solidBlockItemsPreferredPickupLocation: PickupLocation,
+    pub r#miscItemsPreferredPickupLocation: PickupLocation,
+    pub r#allowNPCDetection: bool,
+    pub r#respondToHit: bool,
+    pub r#hideHelmet: bool,
+    pub r#hideCuirass: bool,
+    pub r#hideGauntlets: bool,
+    pub r#hidePants: bool,
+}
+
+impl Packet for SyncPlayerPreferences {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SyncPlayerPreferences",
+        id: 116,
+        is_compressed: false,
+        max_size: 12,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum PickupLocation {
+    #[default]
+    r#Hotbar,
+    r#Storage,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClientPlaceBlock {
+    pub r#position: Option<BlockPosition>,
+    pub r#rotation: Option<BlockRotation>,
+    pub r#placedBlockId: i32,
+}
+
+impl Packet for ClientPlaceBlock {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ClientPlaceBlock",
+        id: 117,
+        is_compressed: false,
+        max_size: 20,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateMemoriesFeatureStatus {
+    pub r#isFeatureUnlocked: bool,
+}
+
+impl Packet for UpdateMemoriesFeatureStatus {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateMemoriesFeatureStatus",
+        id: 118,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RemoveMapMarker {
+    pub r#markerId: Option<ByteString>,
+}
+
+impl Packet for RemoveMapMarker {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "RemoveMapMarker",
+        id: 119,
+        is_compressed: false,
+        max_size: 16384006,
+        category: PacketCategory::PLAYER,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetChunk {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#localLight: Option<Bytes>,
+    pub r#globalLight: Option<Bytes>,
+    pub r#data: Option<Bytes>,
+}
+
+impl Packet for SetChunk {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetChunk",
+        id: 131,
+        is_compressed: true,
+        max_size: 12288040,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetChunkHeightmap {
+    pub r#x: i32,
+    pub r#z: i32,
+    pub r#heightmap: Option<Bytes>,
+}
+
+impl Packet for SetChunkHeightmap {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetChunkHeightmap",
+        id: 132,
+        is_compressed: true,
+        max_size: 4096014,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetChunkTintmap {
+    pub r#x: i32,
+    pub r#z: i32,
+    pub r#tintmap: Option<Bytes>,
+}
+
+impl Packet for SetChunkTintmap {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetChunkTintmap",
+        id: 133,
+        is_compressed: true,
+        max_size: 4096014,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetChunkEnvironments {
+    pub r#x: i32,
+    pub r#z: i32,
+    pub r#environments: Option<Bytes>,
+}
+
+impl Packet for SetChunkEnvironments {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetChunkEnvironments",
+        id: 134,
+        is_compressed: true,
+        max_size: 4096014,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UnloadChunk {
+    pub r#chunkX: i32,
+    pub r#chunkZ: i32,
+}
+
+impl Packet for UnloadChunk {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UnloadChunk",
+        id: 135,
+        is_compressed: false,
+        max_size: 8,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetFluids {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#data: Option<Bytes>,
+}
+
+impl Packet for SetFluids {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetFluids",
+        id: 136,
+        is_compressed: true,
+        max_size: 4096018,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerSetBlock {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#blockId: i32,
+    pub r#filler: i16,
+    pub r#rotation: i8,
+}
+
+impl Packet for ServerSetBlock {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ServerSetBlock",
+        id: 140,
+        is_compressed: false,
+        max_size: 19,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerSetBlocks {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#cmds: Vec<SetBlockCmd>,
+}
+
+impl Packet for ServerSetBlocks {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ServerSetBlocks",
+        id: 141,
+        is_compressed: false,
+        max_size: 36864017,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetBlockCmd {
+    pub r#index: i16,
+    pub r#blockId: i32,
+    pub r#filler: i16,
+    pub r#rotation: i8,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerSetFluid {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#fluidId: i32,
+    pub r#fluidLevel: i8,
+}
+
+impl Packet for ServerSetFluid {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ServerSetFluid",
+        id: 142,
+        is_compressed: false,
+        max_size: 17,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerSetFluids {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#cmds: Vec<SetFluidCmd>,
+}
+
+impl Packet for ServerSetFluids {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ServerSetFluids",
+        id: 143,
+        is_compressed: false,
+        max_size: 28672017,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetFluidCmd {
+    pub r#index: i16,
+    pub r#fluidId: i32,
+    pub r#fluidLevel: i8,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateBlockDamage {
+    pub r#blockPosition: Option<BlockPosition>,
+    pub r#damage: f32,
+    pub r#delta: f32,
+}
+
+impl Packet for UpdateBlockDamage {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateBlockDamage",
+        id: 144,
+        is_compressed: false,
+        max_size: 21,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateTimeSettings {
+    pub r#daytimeDurationSeconds: i32,
+    pub r#nighttimeDurationSeconds: i32,
+    pub r#totalMoonPhases: i8,
+    pub r#timePaused: bool,
+}
+
+impl Packet for UpdateTimeSettings {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateTimeSettings",
+        id: 145,
+        is_compressed: false,
+        max_size: 10,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateTime {
+    pub r#gameTime: Option<InstantData>,
+}
+
+impl Packet for UpdateTime {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateTime",
+        id: 146,
+        is_compressed: false,
+        max_size: 13,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateEditorTimeOverride {
+    pub r#gameTime: Option<InstantData>,
+    pub r#paused: bool,
+}
+
+impl Packet for UpdateEditorTimeOverride {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateEditorTimeOverride",
+        id: 147,
+        is_compressed: false,
+        max_size: 14,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClearEditorTimeOverride {}
+
+impl Packet for ClearEditorTimeOverride {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ClearEditorTimeOverride",
+        id: 148,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateWeather {
+    pub r#weatherIndex: i32,
+    pub r#transitionSeconds: f32,
+}
+
+impl Packet for UpdateWeather {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateWeather",
+        id: 149,
+        is_compressed: false,
+        max_size: 8,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateEditorWeatherOverride {
+    pub r#weatherIndex: i32,
+}
+
+impl Packet for UpdateEditorWeatherOverride {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateEditorWeatherOverride",
+        id: 150,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateEnvironmentMusic {
+    pub r#environmentIndex: i32,
+}
+
+impl Packet for UpdateEnvironmentMusic {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateEnvironmentMusic",
+        id: 151,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SpawnParticleSystem {
+    pub r#particleSystemId: Option<ByteString>,
+    pub r#position: Option<Position>,
+    pub r#rotation: Option<Direction>,
+    pub r#scale: f32,
+    pub r#color: Option<Color>,
+}
+
+impl Packet for SpawnParticleSystem {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SpawnParticleSystem",
+        id: 152,
+        is_compressed: false,
+        max_size: 16384049,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SpawnBlockParticleSystem {
+    pub r#blockId: i32,
+    pub r#particleType: BlockParticleEvent,
+    pub r#position: Option<Position>,
+}
+
+impl Packet for SpawnBlockParticleSystem {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SpawnBlockParticleSystem",
+        id: 153,
+        is_compressed: false,
+        max_size: 30,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PlaySoundEvent2D {
+    pub r#soundEventIndex: i32,
+    pub r#category: SoundCategory,
+    pub r#volumeModifier: f32,
+    pub r#pitchModifier: f32,
+}
+
+impl Packet for PlaySoundEvent2D {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PlaySoundEvent2D",
+        id: 154,
+        is_compressed: false,
+        max_size: 13,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PlaySoundEvent3D {
+    pub r#soundEventIndex: i32,
+    pub r#category: SoundCategory,
+    pub r#position: Option<Position>,
+    pub r#volumeModifier: f32,
+    pub r#pitchModifier: f32,
+}
+
+impl Packet for PlaySoundEvent3D {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PlaySoundEvent3D",
+        id: 155,
+        is_compressed: false,
+        max_size: 38,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PlaySoundEventEntity {
+    pub r#soundEventIndex: i32,
+    pub r#networkId: i32,
+    pub r#volumeModifier: f32,
+    pub r#pitchModifier: f32,
+}
+
+impl Packet for PlaySoundEventEntity {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PlaySoundEventEntity",
+        id: 156,
+        is_compressed: false,
+        max_size: 16,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateSleepState {
+    pub r#grayFade: bool,
+    pub r#sleepUi: bool,
+    pub r#clock: Option<SleepClock>,
+    pub r#multiplayer: Option<SleepMultiplayer>,
+}
+
+impl Packet for UpdateSleepState {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateSleepState",
+        id: 157,
+        is_compressed: false,
+        max_size: 65536050,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct SleepClock {
+    pub r#startGametime: Option<InstantData>,
+    pub r#targetGametime: Option<InstantData>,
+    pub r#progress: f32,
+    pub r#durationSeconds: f32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SleepMultiplayer {
+    pub r#sleepersCount: i32,
+    pub r#awakeCount: i32,
+    pub r#awakeSample: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetPaused {
+    pub r#paused: bool,
+}
+
+impl Packet for SetPaused {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetPaused",
+        id: 158,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerSetPaused {
+    pub r#paused: bool,
+}
+
+impl Packet for ServerSetPaused {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ServerSetPaused",
+        id: 159,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetEntitySeed {
+    pub r#entitySeed: i32,
+}
+
+impl Packet for SetEntitySeed {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetEntitySeed",
+        id: 160,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::ENTITIES,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityUpdates {
+    pub r#removed: Option<Vec<i32>>,
+    pub r#updates: Option<Vec<EntityUpdate>>,
+}
+
+impl Packet for EntityUpdates {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "EntityUpdates",
+        id: 161,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ENTITIES,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityUpdate {
+    pub r#networkId: i32,
+    pub r#removed: Option<Vec<ComponentUpdateType>>,
+    pub r#updates: Option<Vec<ComponentUpdate>>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ComponentUpdateType {
+    #[default]
+    r#Nameplate,
+    r#UIComponents,
+    r#CombatText,
+    r#Model,
+    r#PlayerSkin,
+    r#Item,
+    r#Block,
+    r#Equipment,
+    r#EntityStats,
+    r#Transform,
+    r#MovementStates,
+    r#EntityEffects,
+    r#Interactions,
+    r#DynamicLight,
+    r#Interactable,
+    r#Intangible,
+    r#Invulnerable,
+    r#RespondToHit,
+    r#HitboxCollision,
+    r#Repulsion,
+    r#Prediction,
+    r#Audio,
+    r#Mounted,
+    r#NewSpawn,
+    r#ActiveAnimations,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ComponentUpdate {
+    pub r#type: ComponentUpdateType,
+    pub r#nameplate: Option<Nameplate>,
+    pub r#entityUIComponents: Option<Vec<i32>>,
+    pub r#combatTextUpdate: Option<CombatTextUpdate>,
+    pub r#model: Option<Model>,
+    pub r#skin: Option<PlayerSkin>,
+    pub r#item: Option<ItemWithAllMetadata>,
+    pub r#blockId: i32,
+    pub r#entityScale: f32,
+    pub r#equipment: Option<Equipment>,
+    pub r#entityStatUpdates: Option<Dictionary<i32, Vec<EntityStatUpdate>>>,
+    pub r#transform: Option<ModelTransform>,
+    pub r#movementStates: Option<MovementStates>,
+    pub r#entityEffectUpdates: Option<Vec<EntityEffectUpdate>>,
+    pub r#interactions: Option<Dictionary<InteractionType, i32>>,
+    pub r#dynamicLight: Option<ColorLight>,
+    pub r#hitboxCollisionConfigIndex: i32,
+    pub r#repulsionConfigIndex: i32,
+    pub r#predictionId: Uuid,
+    pub r#soundEventIds: Option<Vec<i32>>,
+    pub r#interactionHint: Option<ByteString>,
+    pub r#mounted: Option<MountedUpdate>,
+    pub r#activeAnimations: Option<Vec<ByteString>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Nameplate {
+    pub r#text: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CombatTextUpdate {
+    pub r#hitAngleDeg: f32,
+    pub r#text: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Equipment {
+    pub r#armorIds: Option<Vec<ByteString>>,
+    pub r#rightHandItemId: Option<ByteString>,
+    pub r#leftHandItemId: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityStatUpdate {
+    pub r#op: EntityStatOp,
+    pub r#predictable: bool,
+    pub r#value: f32,
+    pub r#modifiers: Option<Dictionary<ByteString, Modifier>>,
+    pub r#modifierKey: Option<ByteString>,
+    pub r#modifier: Option<Modifier>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EntityStatOp {
+    #[default]
+    r#Init,
+    r#Remove,
+    r#PutModifier,
+    r#RemoveModifier,
+    r#Add,
+    r#Set,
+    r#Minimize,
+    r#Maximize,
+    r#Reset,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EntityEffectUpdate {
+    pub r#type: EffectOp,
+    pub r#id: i32,
+    pub r#remainingTime: f32,
+    pub r#infinite: bool,
+    pub r#debuff: bool,
+    pub r#statusEffectIcon: Option<ByteString>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EffectOp {
+    #[default]
+    r#Add,
+    r#Remove,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct MountedUpdate {
+    pub r#mountedToEntity: i32,
+    pub r#attachmentOffset: Option<Vector3f>,
+    pub r#controller: MountController,
+    pub r#block: Option<BlockMount>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum MountController {
+    #[default]
+    r#Minecart,
+    r#BlockMount,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct BlockMount {
+    pub r#type: BlockMountType,
+    pub r#position: Option<Vector3f>,
+    pub r#orientation: Option<Vector3f>,
+    pub r#blockTypeId: i32,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BlockMountType {
+    #[default]
+    r#Seat,
+    r#Bed,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PlayAnimation {
+    pub r#entityId: i32,
+    pub r#itemAnimationsId: Option<ByteString>,
+    pub r#animationId: Option<ByteString>,
+    pub r#slot: AnimationSlot,
+}
+
+impl Packet for PlayAnimation {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PlayAnimation",
+        id: 162,
+        is_compressed: false,
+        max_size: 32768024,
+        category: PacketCategory::ENTITIES,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum AnimationSlot {
+    #[default]
+    r#Movement,
+    r#Status,
+    r#Action,
+    r#Face,
+    r#Emote,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChangeVelocity {
+    pub r#x: f32,
+    pub r#y: f32,
+    pub r#z: f32,
+    pub r#changeType: ChangeVelocityType,
+    pub r#config: Option<VelocityConfig>,
+}
+
+impl Packet for ChangeVelocity {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ChangeVelocity",
+        id: 163,
+        is_compressed: false,
+        max_size: 35,
+        category: PacketCategory::ENTITIES,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ApplyKnockback {
+    pub r#hitPosition: Option<Position>,
+    pub r#x: f32,
+    pub r#y: f32,
+    pub r#z: f32,
+    pub r#changeType: ChangeVelocityType,
+}
+
+impl Packet for ApplyKnockback {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ApplyKnockback",
+        id: 164,
+        is_compressed: false,
+        max_size: 38,
+        category: PacketCategory::ENTITIES,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SpawnModelParticles {
+    pub r#entityId: i32,
+    pub r#modelParticles: Option<Vec<ModelParticle>>,
+}
+
+impl Packet for SpawnModelParticles {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SpawnModelParticles",
+        id: 165,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ENTITIES,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MountMovement {
+    pub r#absolutePosition: Option<Position>,
+    pub r#bodyOrientation: Option<Direction>,
+    pub r#movementStates: Option<MovementStates>,
+}
+
+impl Packet for MountMovement {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "MountMovement",
+        id: 166,
+        is_compressed: false,
+        max_size: 59,
+        category: PacketCategory::ENTITIES,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdatePlayerInventory {
+    pub r#storage: Option<InventorySection>,
+    pub r#armor: Option<InventorySection>,
+    pub r#hotbar: Option<InventorySection>,
+    pub r#utility: Option<InventorySection>,
+    pub r#builderMaterial: Option<InventorySection>,
+    pub r#tools: Option<InventorySection>,
+    pub r#backpack: Option<InventorySection>,
+    pub r#sortType: SortType,
+}
+
+impl Packet for UpdatePlayerInventory {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdatePlayerInventory",
+        id: 170,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InventorySection {
+    pub r#items: Option<Dictionary<i32, ItemWithAllMetadata>>,
+    pub r#capacity: i16,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum SortType {
+    #[default]
+    r#Name,
+    r#Type,
+    r#Rarity,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetCreativeItem {
+    pub r#inventorySectionId: i32,
+    pub r#slotId: i32,
+    pub r#item: ItemQuantity,
+    pub r#override: bool,
+}
+
+impl Packet for SetCreativeItem {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetCreativeItem",
+        id: 171,
+        is_compressed: false,
+        max_size: 16384019,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ItemQuantity {
+    pub r#itemId: Option<ByteString>,
+    pub r#quantity: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DropCreativeItem {
+    pub r#item: ItemQuantity,
+}
+
+impl Packet for DropCreativeItem {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "DropCreativeItem",
+        id: 172,
+        is_compressed: false,
+        max_size: 16384010,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SmartGiveCreativeItem {
+    pub r#item: ItemQuantity,
+    pub r#moveType: SmartMoveType,
+}
+
+impl Packet for SmartGiveCreativeItem {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SmartGiveCreativeItem",
+        id: 173,
+        is_compressed: false,
+        max_size: 16384011,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum SmartMoveType {
+    #[default]
+    r#EquipOrMergeStack,
+    r#PutInHotbarOrWindow,
+    r#PutInHotbarOrBackpack,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DropItemStack {
+    pub r#inventorySectionId: i32,
+    pub r#slotId: i32,
+    pub r#quantity: i32,
+}
+
+impl Packet for DropItemStack {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "DropItemStack",
+        id: 174,
+        is_compressed: false,
+        max_size: 12,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MoveItemStack {
+    pub r#fromSectionId: i32,
+    pub r#fromSlotId: i32,
+    pub r#quantity: i32,
+    pub r#toSectionId: i32,
+    pub r#toSlotId: i32,
+}
+
+impl Packet for MoveItemStack {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "MoveItemStack",
+        id: 175,
+        is_compressed: false,
+        max_size: 20,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SmartMoveItemStack {
+    pub r#fromSectionId: i32,
+    pub r#fromSlotId: i32,
+    pub r#quantity: i32,
+    pub r#moveType: SmartMoveType,
+}
+
+impl Packet for SmartMoveItemStack {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SmartMoveItemStack",
+        id: 176,
+        is_compressed: false,
+        max_size: 13,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetActiveSlot {
+    pub r#inventorySectionId: i32,
+    pub r#activeSlot: i32,
+}
+
+impl Packet for SetActiveSlot {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetActiveSlot",
+        id: 177,
+        is_compressed: false,
+        max_size: 8,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SwitchHotbarBlockSet {
+    pub r#itemId: Option<ByteString>,
+}
+
+impl Packet for SwitchHotbarBlockSet {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SwitchHotbarBlockSet",
+        id: 178,
+        is_compressed: false,
+        max_size: 16384006,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InventoryAction {
+    pub r#inventorySectionId: i32,
+    pub r#inventoryActionType: InventoryActionType,
+    pub r#actionData: i8,
+}
+
+impl Packet for InventoryAction {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "InventoryAction",
+        id: 179,
+        is_compressed: false,
+        max_size: 6,
+        category: PacketCategory::INVENTORY,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum InventoryActionType {
+    #[default]
+    r#TakeAll,
+    r#PutAll,
+    r#QuickStack,
+    r#Sort,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct OpenWindow {
+    pub r#id: i32,
+    pub r#windowType: WindowType,
+    pub r#windowData: Option<ByteString>,
+    pub r#inventory: Option<InventorySection>,
+    pub r#extraResources: Option<ExtraResources>,
+}
+
+impl Packet for OpenWindow {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "OpenWindow",
+        id: 200,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::WINDOW,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum WindowType {
+    #[default]
+    r#Container,
+    r#PocketCrafting,
+    r#BasicCrafting,
+    r#DiagramCrafting,
+    r#StructuralCrafting,
+    r#Processing,
+    r#Memories,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ExtraResources {
+    pub r#resources: Option<Vec<ItemQuantity>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateWindow {
+    pub r#id: i32,
+    pub r#windowData: Option<ByteString>,
+    pub r#inventory: Option<InventorySection>,
+    pub r#extraResources: Option<ExtraResources>,
+}
+
+impl Packet for UpdateWindow {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateWindow",
+        id: 201,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::WINDOW,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CloseWindow {
+    pub r#id: i32,
+}
+
+impl Packet for CloseWindow {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "CloseWindow",
+        id: 202,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::WINDOW,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SendWindowAction {
+    pub r#id: i32,
+    pub r#action: WindowAction,
+}
+
+impl Packet for SendWindowAction {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SendWindowAction",
+        id: 203,
+        is_compressed: false,
+        max_size: 32768027,
+        category: PacketCategory::WINDOW,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub enum WindowAction {
+    CraftRecipeAction(Box<CraftRecipeAction>),
+    TierUpgradeAction(Box<TierUpgradeAction>),
+    SelectSlotAction(Box<SelectSlotAction>),
+    ChangeBlockAction(Box<ChangeBlockAction>),
+    SetActiveAction(Box<SetActiveAction>),
+    CraftItemAction(Box<CraftItemAction>),
+    UpdateCategoryAction(Box<UpdateCategoryAction>),
+    CancelCraftingAction(Box<CancelCraftingAction>),
+    SortItemsAction(Box<SortItemsAction>),
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CraftRecipeAction {
+    pub r#recipeId: Option<ByteString>,
+    pub r#quantity: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct TierUpgradeAction {}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SelectSlotAction {
+    pub r#slot: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChangeBlockAction {
+    pub r#down: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetActiveAction {
+    pub r#state: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CraftItemAction {}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateCategoryAction {
+    pub r#category: ByteString,
+    pub r#itemCategory: ByteString,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CancelCraftingAction {}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SortItemsAction {
+    pub r#sortType: SortType,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClientOpenWindow {
+    pub r#type: WindowType,
+}
+
+impl Packet for ClientOpenWindow {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ClientOpenWindow",
+        id: 204,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::WINDOW,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerMessage {
+    pub r#type: ChatType,
+    pub r#message: Option<FormattedMessage>,
+}
+
+impl Packet for ServerMessage {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ServerMessage",
+        id: 210,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ChatType {
+    #[default]
+    r#Chat,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct FormattedMessage {
+    pub r#rawText: Option<ByteString>,
+    pub r#messageId: Option<ByteString>,
+    pub r#children: Option<Vec<FormattedMessage>>,
+    pub r#params: Option<Dictionary<ByteString, ParamValue>>,
+    pub r#messageParams: Option<Dictionary<ByteString, FormattedMessage>>,
+    pub r#color: Option<ByteString>,
+    pub r#bold: MaybeBool,
+    pub r#italic: MaybeBool,
+    pub r#monospace: MaybeBool,
+    pub r#underlined: MaybeBool,
+    pub r#link: Option<ByteString>,
+    pub r#markupEnabled: bool,
+}
+
+#[derive(Debug, Clone, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub enum ParamValue {
+    StringParamValue(Box<StringParamValue>),
+    BoolParamValue(Box<BoolParamValue>),
+    DoubleParamValue(Box<DoubleParamValue>),
+    IntParamValue(Box<IntParamValue>),
+    LongParamValue(Box<LongParamValue>),
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct StringParamValue {
+    pub r#value: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BoolParamValue {
+    pub r#value: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DoubleParamValue {
+    pub r#value: f64,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct IntParamValue {
+    pub r#value: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct LongParamValue {
+    pub r#value: i64,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum MaybeBool {
+    #[default]
+    r#Null,
+    r#False,
+    r#True,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ChatMessage {
+    pub r#message: Option<ByteString>,
+}
+
+impl Packet for ChatMessage {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ChatMessage",
+        id: 211,
+        is_compressed: false,
+        max_size: 16384006,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct Notification {
+    pub r#message: Option<FormattedMessage>,
+    pub r#secondaryMessage: Option<FormattedMessage>,
+    pub r#icon: Option<ByteString>,
+    pub r#item: Option<ItemWithAllMetadata>,
+    pub r#style: NotificationStyle,
+}
+
+impl Packet for Notification {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "Notification",
+        id: 212,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum NotificationStyle {
+    #[default]
+    r#Default,
+    r#Danger,
+    r#Warning,
+    r#Success,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct KillFeedMessage {
+    pub r#killer: Option<FormattedMessage>,
+    pub r#decedent: Option<FormattedMessage>,
+    pub r#icon: Option<ByteString>,
+}
+
+impl Packet for KillFeedMessage {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "KillFeedMessage",
+        id: 213,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ShowEventTitle {
+    pub r#fadeInDuration: f32,
+    pub r#fadeOutDuration: f32,
+    pub r#duration: f32,
+    pub r#icon: Option<ByteString>,
+    pub r#isMajor: bool,
+    pub r#primaryTitle: Option<FormattedMessage>,
+    pub r#secondaryTitle: Option<FormattedMessage>,
+}
+
+impl Packet for ShowEventTitle {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ShowEventTitle",
+        id: 214,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct HideEventTitle {
+    pub r#fadeOutDuration: f32,
+}
+
+impl Packet for HideEventTitle {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "HideEventTitle",
+        id: 215,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetPage {
+    pub r#page: Page,
+    pub r#canCloseThroughInteraction: bool,
+}
+
+impl Packet for SetPage {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetPage",
+        id: 216,
+        is_compressed: false,
+        max_size: 2,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum Page {
+    #[default]
+    r#None,
+    r#Bench,
+    r#Inventory,
+    r#ToolsSettings,
+    r#Map,
+    r#MachinimaEditor,
+    r#ContentCreation,
+    r#Custom,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CustomHud {
+    pub r#clear: bool,
+    pub r#commands: Option<Vec<CustomUICommand>>,
+}
+
+impl Packet for CustomHud {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "CustomHud",
+        id: 217,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CustomUICommand {
+    pub r#type: CustomUICommandType,
+    pub r#selector: Option<ByteString>,
+    pub r#data: Option<ByteString>,
+    pub r#text: Option<ByteString>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CustomUICommandType {
+    #[default]
+    r#Append,
+    r#AppendInline,
+    r#InsertBefore,
+    r#InsertBeforeInline,
+    r#Remove,
+    r#Set,
+    r#Clear,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CustomPage {
+    pub r#key: Option<ByteString>,
+    pub r#isInitial: bool,
+    pub r#clear: bool,
+    pub r#lifetime: CustomPageLifetime,
+    pub r#commands: Option<Vec<CustomUICommand>>,
+    pub r#eventBindings: Option<Vec<CustomUIEventBinding>>,
+}
+
+impl Packet for CustomPage {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "CustomPage",
+        id: 218,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CustomPageLifetime {
+    #[default]
+    r#CantClose,
+    r#CanDismiss,
+    r#CanDismissOrCloseThroughInteraction,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CustomUIEventBinding {
+    pub r#type: CustomUIEventBindingType,
+    pub r#selector: Option<ByteString>,
+    pub r#data: Option<ByteString>,
+    pub r#locksInterface: bool,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CustomUIEventBindingType {
+    #[default]
+    r#Activating,
+    r#RightClicking,
+    r#DoubleClicking,
+    r#MouseEntered,
+    r#MouseExited,
+    r#ValueChanged,
+    r#ElementReordered,
+    r#Validating,
+    r#Dismissing,
+    r#FocusGained,
+    r#FocusLost,
+    r#KeyDown,
+    r#MouseButtonReleased,
+    r#SlotClicking,
+    r#SlotDoubleClicking,
+    r#SlotMouseEntered,
+    r#SlotMouseExited,
+    r#DragCancelled,
+    r#Dropped,
+    r#SlotMouseDragCompleted,
+    r#SlotMouseDragExited,
+    r#SlotClickReleaseWhileDragging,
+    r#SlotClickPressWhileDragging,
+    r#SelectedTabChanged,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CustomPageEvent {
+    pub r#type: CustomPageEventType,
+    pub r#data: Option<ByteString>,
+}
+
+impl Packet for CustomPageEvent {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "CustomPageEvent",
+        id: 219,
+        is_compressed: false,
+        max_size: 16384007,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CustomPageEventType {
+    #[default]
+    r#Acknowledge,
+    r#Data,
+    r#Dismiss,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct EditorBlocksChange {
+    pub r#selection: Option<EditorSelection>,
+    pub r#blocksChange: Option<Vec<BlockChange>>,
+    pub r#fluidsChange: Option<Vec<FluidChange>>,
+    pub r#blocksCount: i32,
+    pub r#advancedPreview: bool,
+}
+
+impl Packet for EditorBlocksChange {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "EditorBlocksChange",
+        id: 222,
+        is_compressed: true,
+        max_size: 139264048,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct EditorSelection {
+    pub r#minX: i32,
+    pub r#minY: i32,
+    pub r#minZ: i32,
+    pub r#maxX: i32,
+    pub r#maxY: i32,
+    pub r#maxZ: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BlockChange {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#block: i32,
+    pub r#rotation: i8,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct FluidChange {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#fluidId: i32,
+    pub r#fluidLevel: i8,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerInfo {
+    pub r#serverName: Option<ByteString>,
+    pub r#motd: Option<ByteString>,
+    pub r#maxPlayers: i32,
+}
+
+impl Packet for ServerInfo {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ServerInfo",
+        id: 223,
+        is_compressed: false,
+        max_size: 32768023,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AddToServerPlayerList {
+    pub r#players: Option<Vec<ServerPlayerListPlayer>>,
+}
+
+impl Packet for AddToServerPlayerList {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AddToServerPlayerList",
+        id: 224,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerPlayerListPlayer {
+    pub r#uuid: Uuid,
+    pub r#username: Option<ByteString>,
+    pub r#worldUuid: Option<Uuid>,
+    pub r#ping: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RemoveFromServerPlayerList {
+    pub r#players: Option<Vec<Uuid>>,
+}
+
+impl Packet for RemoveFromServerPlayerList {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "RemoveFromServerPlayerList",
+        id: 225,
+        is_compressed: false,
+        max_size: 65536006,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateServerPlayerList {
+    pub r#players: Option<Vec<ServerPlayerListUpdate>>,
+}
+
+impl Packet for UpdateServerPlayerList {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateServerPlayerList",
+        id: 226,
+        is_compressed: false,
+        max_size: 131072006,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ServerPlayerListUpdate {
+    pub r#uuid: Uuid,
+    pub r#worldUuid: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateServerPlayerListPing {
+    pub r#players: Option<Dictionary<Uuid, i32>>,
+}
+
+impl Packet for UpdateServerPlayerListPing {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateServerPlayerListPing",
+        id: 227,
+        is_compressed: false,
+        max_size: 81920006,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateKnownRecipes {
+    pub r#known: Option<Dictionary<ByteString, CraftingRecipe>>,
+}
+
+impl Packet for UpdateKnownRecipes {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateKnownRecipes",
+        id: 228,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdatePortal {
+    pub r#state: Option<PortalState>,
+    pub r#definition: Option<PortalDef>,
+}
+
+impl Packet for UpdatePortal {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdatePortal",
+        id: 229,
+        is_compressed: false,
+        max_size: 16384020,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct PortalState {
+    pub r#remainingSeconds: i32,
+    pub r#breaching: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PortalDef {
+    pub r#nameKey: Option<ByteString>,
+    pub r#explorationSeconds: i32,
+    pub r#breachSeconds: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateVisibleHudComponents {
+    pub r#visibleComponents: Option<Vec<HudComponent>>,
+}
+
+impl Packet for UpdateVisibleHudComponents {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateVisibleHudComponents",
+        id: 230,
+        is_compressed: false,
+        max_size: 4096006,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum HudComponent {
+    #[default]
+    r#Hotbar,
+    r#StatusIcons,
+    r#Reticle,
+    r#Chat,
+    r#Requests,
+    r#Notifications,
+    r#KillFeed,
+    r#InputBindings,
+    r#PlayerList,
+    r#EventTitle,
+    r#Compass,
+    r#ObjectivePanel,
+    r#PortalPanel,
+    r#BuilderToolsLegend,
+    r#Speedometer,
+    r#UtilitySlotSelector,
+    r#BlockVariantSelector,
+    r#BuilderToolsMaterialSlotSelector,
+    r#Stamina,
+    r#AmmoIndicator,
+    r#Health,
+    r#Mana,
+    r#Oxygen,
+    r#Sleep,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ResetUserInterfaceState {}
+
+impl Packet for ResetUserInterfaceState {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ResetUserInterfaceState",
+        id: 231,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateLanguage {
+    pub r#language: Option<ByteString>,
+}
+
+impl Packet for UpdateLanguage {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateLanguage",
+        id: 232,
+        is_compressed: false,
+        max_size: 16384006,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct WorldSavingStatus {
+    pub r#isWorldSaving: bool,
+}
+
+impl Packet for WorldSavingStatus {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "WorldSavingStatus",
+        id: 233,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct OpenChatWithCommand {
+    pub r#command: Option<ByteString>,
+}
+
+impl Packet for OpenChatWithCommand {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "OpenChatWithCommand",
+        id: 234,
+        is_compressed: false,
+        max_size: 16384006,
+        category: PacketCategory::INTERFACE,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateWorldMapSettings {
+    pub r#enabled: bool,
+    pub r#biomeDataMap: Option<Dictionary<i16, BiomeData>>,
+    pub r#allowTeleportToCoordinates: bool,
+    pub r#allowTeleportToMarkers: bool,
+    pub r#defaultScale: f32,
+    pub r#minScale: f32,
+    pub r#maxScale: f32,
+}
+
+impl Packet for UpdateWorldMapSettings {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateWorldMapSettings",
+        id: 240,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::WORLD_MAP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BiomeData {
+    pub r#zoneId: i32,
+    pub r#zoneName: Option<ByteString>,
+    pub r#biomeName: Option<ByteString>,
+    pub r#biomeColor: i32,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateWorldMap {
+    pub r#chunks: Option<Vec<MapChunk>>,
+    pub r#addedMarkers: Option<Vec<MapMarker>>,
+    pub r#removedMarkers: Option<Vec<ByteString>>,
+}
+
+impl Packet for UpdateWorldMap {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateWorldMap",
+        id: 241,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::WORLD_MAP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MapChunk {
+    pub r#chunkX: i32,
+    pub r#chunkZ: i32,
+    pub r#image: Option<MapImage>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MapImage {
+    pub r#width: i32,
+    pub r#height: i32,
+    pub r#data: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MapMarker {
+    pub r#id: Option<ByteString>,
+    pub r#name: Option<ByteString>,
+    pub r#markerImage: Option<ByteString>,
+    pub r#transform: Option<Transform>,
+    pub r#contextMenuItems: Option<Vec<ContextMenuItem>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct Transform {
+    pub r#position: Option<Position>,
+    pub r#orientation: Option<Direction>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ContextMenuItem {
+    pub r#name: ByteString,
+    pub r#command: ByteString,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ClearWorldMap {}
+
+impl Packet for ClearWorldMap {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "ClearWorldMap",
+        id: 242,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::WORLD_MAP,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateWorldMapVisible {
+    pub r#visible: bool,
+}
+
+impl Packet for UpdateWorldMapVisible {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateWorldMapVisible",
+        id: 243,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::WORLD_MAP,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct TeleportToWorldMapMarker {
+    pub r#id: Option<ByteString>,
+}
+
+impl Packet for TeleportToWorldMapMarker {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "TeleportToWorldMapMarker",
+        id: 244,
+        is_compressed: false,
+        max_size: 16384006,
+        category: PacketCategory::WORLD_MAP,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct TeleportToWorldMapPosition {
+    pub r#x: i32,
+    pub r#y: i32,
+}
+
+impl Packet for TeleportToWorldMapPosition {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "TeleportToWorldMapPosition",
+        id: 245,
+        is_compressed: false,
+        max_size: 8,
+        category: PacketCategory::WORLD_MAP,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RequestServerAccess {
+    pub r#access: Access,
+    pub r#externalPort: i16,
+}
+
+impl Packet for RequestServerAccess {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "RequestServerAccess",
+        id: 250,
+        is_compressed: false,
+        max_size: 3,
+        category: PacketCategory::SERVER_ACCESS,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum Access {
+    #[default]
+    r#Private,
+    r#LAN,
+    r#Friend,
+    r#Open,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateServerAccess {
+    pub r#access: Access,
+    pub r#hosts: Option<Vec<HostAddress>>,
+}
+
+impl Packet for UpdateServerAccess {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateServerAccess",
+        id: 251,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::SERVER_ACCESS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetServerAccess {
+    pub r#access: Access,
+    pub r#password: Option<ByteString>,
+}
+
+impl Packet for SetServerAccess {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetServerAccess",
+        id: 252,
+        is_compressed: false,
+        max_size: 16384007,
+        category: PacketCategory::SERVER_ACCESS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RequestMachinimaActorModel {
+    pub r#modelId: Option<ByteString>,
+    pub r#sceneName: Option<ByteString>,
+    pub r#actorName: Option<ByteString>,
+}
+
+impl Packet for RequestMachinimaActorModel {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "RequestMachinimaActorModel",
+        id: 260,
+        is_compressed: false,
+        max_size: 49152028,
+        category: PacketCategory::MACHINIMA,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetMachinimaActorModel {
+    pub r#model: Option<Model>,
+    pub r#sceneName: Option<ByteString>,
+    pub r#actorName: Option<ByteString>,
+}
+
+impl Packet for SetMachinimaActorModel {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetMachinimaActorModel",
+        id: 261,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::MACHINIMA,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateMachinimaScene {
+    pub r#player: Option<ByteString>,
+    pub r#sceneName: Option<ByteString>,
+    pub r#frame: f32,
+    pub r#updateType: SceneUpdateType,
+    pub r#scene: Option<Bytes>,
+}
+
+impl Packet for UpdateMachinimaScene {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateMachinimaScene",
+        id: 262,
+        is_compressed: true,
+        max_size: 36864033,
+        category: PacketCategory::MACHINIMA,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum SceneUpdateType {
+    #[default]
+    r#Update,
+    r#Play,
+    r#Stop,
+    r#Frame,
+    r#Save,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetServerCamera {
+    pub r#clientCameraView: ClientCameraView,
+    pub r#isLocked: bool,
+    pub r#cameraSettings: Option<ServerCameraSettings>,
+}
+
+impl Packet for SetServerCamera {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetServerCamera",
+        id: 280,
+        is_compressed: false,
+        max_size: 157,
+        category: PacketCategory::CAMERA,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ClientCameraView {
+    #[default]
+    r#FirstPerson,
+    r#ThirdPerson,
+    r#Custom,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct ServerCameraSettings {
+    pub r#positionLerpSpeed: f32,
+    pub r#rotationLerpSpeed: f32,
+    pub r#distance: f32,
+    pub r#speedModifier: f32,
+    pub r#allowPitchControls: bool,
+    pub r#displayCursor: bool,
+    pub r#displayReticle: bool,
+    pub r#mouseInputTargetType: MouseInputTargetType,
+    pub r#sendMouseMotion: bool,
+    pub r#skipCharacterPhysics: bool,
+    pub r#isFirstPerson: bool,
+    pub r#movementForceRotationType: MovementForceRotationType,
+    pub r#movementForceRotation: Option<Direction>,
+    pub r#attachedToType: AttachedToType,
+    pub r#attachedToEntityId: i32,
+    pub r#eyeOffset: bool,
+    pub r#positionDistanceOffsetType: PositionDistanceOffsetType,
+    pub r#positionOffset: Option<Position>,
+    pub r#rotationOffset: Option<Direction>,
+    pub r#positionType: PositionType,
+    pub r#position: Option<Position>,
+    pub r#rotationType: RotationType,
+    pub r#rotation: Option<Direction>,
+    pub r#canMoveType: CanMoveType,
+    pub r#applyMovementType: ApplyMovementType,
+    pub r#movementMultiplier: Option<Vector3f>,
+    pub r#applyLookType: ApplyLookType,
+    pub r#lookMultiplier: Option<Vector2f>,
+    pub r#mouseInputType: MouseInputType,
+    pub r#planeNormal: Option<Vector3f>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum MouseInputTargetType {
+    #[default]
+    r#Any,
+    r#Block,
+    r#Entity,
+    r#None,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum MovementForceRotationType {
+    #[default]
+    r#AttachedToHead,
+    r#CameraRotation,
+    r#Custom,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum AttachedToType {
+    #[default]
+    r#LocalPlayer,
+    r#EntityId,
+    r#None,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum PositionDistanceOffsetType {
+    #[default]
+    r#DistanceOffset,
+    r#DistanceOffsetRaycast,
+    r#None,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum PositionType {
+    #[default]
+    r#AttachedToPlusOffset,
+    r#Custom,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum RotationType {
+    #[default]
+    r#AttachedToPlusOffset,
+    r#Custom,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum CanMoveType {
+    #[default]
+    r#AttachedToLocalPlayer,
+    r#Always,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ApplyMovementType {
+    #[default]
+    r#CharacterController,
+    r#Position,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ApplyLookType {
+    #[default]
+    r#LocalPlayerLookOrientation,
+    r#Rotation,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum MouseInputType {
+    #[default]
+    r#LookAtTarget,
+    r#LookAtTargetBlock,
+    r#LookAtTargetEntity,
+    r#LookAtPlane,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct RequestFlyCameraMode {
+    pub r#entering: bool,
+}
+
+impl Packet for RequestFlyCameraMode {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "RequestFlyCameraMode",
+        id: 282,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::CAMERA,
+        direction: PacketDirection::Serverbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SetFlyCameraMode {
+    pub r#entering: bool,
+}
+
+impl Packet for SetFlyCameraMode {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SetFlyCameraMode",
+        id: 283,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::CAMERA,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SyncInteractionChains {
+    pub r#updates: Vec<SyncInteractionChain>,
+}
+
+impl Packet for SyncInteractionChains {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SyncInteractionChains",
+        id: 290,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::INTERACTION,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SyncInteractionChain {
+    pub r#activeHotbarSlot: i32,
+    pub r#activeUtilitySlot: i32,
+    pub r#activeToolsSlot: i32,
+    pub r#itemInHandId: Option<ByteString>,
+    pub r#utilityItemId: Option<ByteString>,
+    pub r#toolsItemId: Option<ByteString>,
+    pub r#initial: bool,
+    pub r#desync: bool,
+    pub r#overrideRootInteraction: i32,
+    pub r#interactionType: InteractionType,
+    pub r#equipSlot: i32,
+    pub r#chainId: i32,
+    pub r#forkedId: Option<ForkedChainId>,
+    pub r#data: Option<InteractionChainData>,
+    pub r#state: InteractionState,
+    pub r#newForks: Option<Vec<SyncInteractionChain>>,
+    pub r#operationBaseIndex: i32,
+    pub r#interactionData: Option<Vec<InteractionSyncData>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct ForkedChainId {
+    pub r#entryIndex: i32,
+    pub r#subIndex: i32,
+    pub r#forkedId: Option<Box<ForkedChainId>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionChainData {
+    pub r#entityId: i32,
+    pub r#proxyId: Uuid,
+    pub r#hitLocation: Option<Vector3f>,
+    pub r#hitDetail: Option<ByteString>,
+    pub r#blockPosition: Option<BlockPosition>,
+    pub r#targetSlot: i32,
+    pub r#hitNormal: Option<Vector3f>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum InteractionState {
+    #[default]
+    r#Finished,
+    r#Skip,
+    r#ItemChanged,
+    r#Failed,
+    r#NotFinished,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct InteractionSyncData {
+    pub r#state: InteractionState,
+    pub r#progress: f32,
+    pub r#operationCounter: i32,
+    pub r#rootInteraction: i32,
+    pub r#totalForks: i32,
+    pub r#entityId: i32,
+    pub r#enteredRootInteraction: i32,
+    pub r#blockPosition: Option<BlockPosition>,
+    pub r#blockFace: BlockFace,
+    pub r#blockRotation: Option<BlockRotation>,
+    pub r#placedBlockId: i32,
+    pub r#chargeValue: f32,
+    pub r#forkCounts: Option<Dictionary<InteractionType, i32>>,
+    pub r#chainingIndex: i32,
+    pub r#flagIndex: i32,
+    pub r#hitEntities: Option<Vec<SelectedHitEntity>>,
+    pub r#attackerPos: Option<Position>,
+    pub r#attackerRot: Option<Direction>,
+    pub r#raycastHit: Option<Position>,
+    pub r#raycastDistance: f32,
+    pub r#raycastNormal: Option<Vector3f>,
+    pub r#movementDirection: MovementDirection,
+    pub r#applyForceState: ApplyForceState,
+    pub r#nextLabel: i32,
+    pub r#generatedUUID: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SelectedHitEntity {
+    pub r#networkId: i32,
+    pub r#hitLocation: Option<Vector3f>,
+    pub r#position: Option<Position>,
+    pub r#bodyRotation: Option<Direction>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum MovementDirection {
+    #[default]
+    r#None,
+    r#Forward,
+    r#Back,
+    r#Left,
+    r#Right,
+    r#ForwardLeft,
+    r#ForwardRight,
+    r#BackLeft,
+    r#BackRight,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum ApplyForceState {
+    #[default]
+    r#Waiting,
+    r#Ground,
+    r#Collision,
+    r#Timer,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct CancelInteractionChain {
+    pub r#chainId: i32,
+    pub r#forkedId: Option<ForkedChainId>,
+}
+
+impl Packet for CancelInteractionChain {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "CancelInteractionChain",
+        id: 291,
+        is_compressed: false,
+        max_size: 1038,
+        category: PacketCategory::INTERACTION,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PlayInteractionFor {
+    pub r#entityId: i32,
+    pub r#chainId: i32,
+    pub r#forkedId: Option<ForkedChainId>,
+    pub r#operationIndex: i32,
+    pub r#interactionId: i32,
+    pub r#interactedItemId: Option<ByteString>,
+    pub r#interactionType: InteractionType,
+    pub r#cancel: bool,
+}
+
+impl Packet for PlayInteractionFor {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PlayInteractionFor",
+        id: 292,
+        is_compressed: false,
+        max_size: 16385065,
+        category: PacketCategory::INTERACTION,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct MountNPC {
+    pub r#anchorX: f32,
+    pub r#anchorY: f32,
+    pub r#anchorZ: f32,
+    pub r#entityId: i32,
+}
+
+impl Packet for MountNPC {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "MountNPC",
+        id: 293,
+        is_compressed: false,
+        max_size: 16,
+        category: PacketCategory::INTERACTION,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct DismountNPC {}
+
+impl Packet for DismountNPC {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "DismountNPC",
+        id: 294,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::INTERACTION,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct FailureReply {
+    pub r#token: i32,
+    pub r#message: Option<FormattedMessage>,
+}
+
+impl Packet for FailureReply {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "FailureReply",
+        id: 300,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SuccessReply {
+    pub r#token: i32,
+    pub r#message: Option<FormattedMessage>,
+}
+
+impl Packet for SuccessReply {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "SuccessReply",
+        id: 301,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorInitialize {}
+
+impl Packet for AssetEditorInitialize {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorInitialize",
+        id: 302,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorAuthorization {
+    pub r#canUse: bool,
+}
+
+impl Packet for AssetEditorAuthorization {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorAuthorization",
+        id: 303,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorCapabilities {
+    pub r#canDiscardAssets: bool,
+    pub r#canEditAssets: bool,
+    pub r#canCreateAssetPacks: bool,
+    pub r#canEditAssetPacks: bool,
+    pub r#canDeleteAssetPacks: bool,
+}
+
+impl Packet for AssetEditorCapabilities {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorCapabilities",
+        id: 304,
+        is_compressed: false,
+        max_size: 5,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorSetupSchemas {
+    pub r#schemas: Option<Vec<SchemaFile>>,
+}
+
+impl Packet for AssetEditorSetupSchemas {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorSetupSchemas",
+        id: 305,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct SchemaFile {
+    pub r#content: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorSetupAssetTypes {
+    pub r#assetTypes: Option<Vec<AssetEditorAssetType>>,
+}
+
+impl Packet for AssetEditorSetupAssetTypes {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorSetupAssetTypes",
+        id: 306,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorAssetType {
+    pub r#id: Option<ByteString>,
+    pub r#icon: Option<ByteString>,
+    pub r#isColoredIcon: bool,
+    pub r#path: Option<ByteString>,
+    pub r#fileExtension: Option<ByteString>,
+    pub r#editorType: AssetEditorEditorType,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum AssetEditorEditorType {
+    #[default]
+    r#None,
+    r#Text,
+    r#JsonSource,
+    r#JsonConfig,
+    r#Model,
+    r#Texture,
+    r#Animation,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorCreateDirectory {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+}
+
+impl Packet for AssetEditorCreateDirectory {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorCreateDirectory",
+        id: 307,
+        is_compressed: false,
+        max_size: 32768024,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetPath {
+    pub r#pack: Option<ByteString>,
+    pub r#path: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorDeleteDirectory {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+}
+
+impl Packet for AssetEditorDeleteDirectory {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorDeleteDirectory",
+        id: 308,
+        is_compressed: false,
+        max_size: 32768024,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorRenameDirectory {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+    pub r#newPath: Option<AssetPath>,
+}
+
+impl Packet for AssetEditorRenameDirectory {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorRenameDirectory",
+        id: 309,
+        is_compressed: false,
+        max_size: 65536051,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorFetchAsset {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+    pub r#isFromOpenedTab: bool,
+}
+
+impl Packet for AssetEditorFetchAsset {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorFetchAsset",
+        id: 310,
+        is_compressed: false,
+        max_size: 32768025,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorFetchJsonAssetWithParents {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+    pub r#isFromOpenedTab: bool,
+}
+
+impl Packet for AssetEditorFetchJsonAssetWithParents {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorFetchJsonAssetWithParents",
+        id: 311,
+        is_compressed: false,
+        max_size: 32768025,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorFetchAssetReply {
+    pub r#token: i32,
+    pub r#contents: Option<Bytes>,
+}
+
+impl Packet for AssetEditorFetchAssetReply {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorFetchAssetReply",
+        id: 312,
+        is_compressed: false,
+        max_size: 4096010,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorFetchJsonAssetWithParentsReply {
+    pub r#token: i32,
+    pub r#assets: Option<Dictionary<AssetPath, ByteString>>,
+}
+
+impl Packet for AssetEditorFetchJsonAssetWithParentsReply {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorFetchJsonAssetWithParentsReply",
+        id: 313,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorAssetPackSetup {
+    pub r#packs: Option<Dictionary<ByteString, AssetPackManifest>>,
+}
+
+impl Packet for AssetEditorAssetPackSetup {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorAssetPackSetup",
+        id: 314,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetPackManifest {
+    pub r#name: Option<ByteString>,
+    pub r#group: Option<ByteString>,
+    pub r#website: Option<ByteString>,
+    pub r#description: Option<ByteString>,
+    pub r#version: Option<ByteString>,
+    pub r#authors: Option<Vec<AuthorInfo>>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AuthorInfo {
+    pub r#name: Option<ByteString>,
+    pub r#email: Option<ByteString>,
+    pub r#url: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorUpdateAssetPack {
+    pub r#id: Option<ByteString>,
+    pub r#manifest: Option<AssetPackManifest>,
+}
+
+impl Packet for AssetEditorUpdateAssetPack {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorUpdateAssetPack",
+        id: 315,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorCreateAssetPack {
+    pub r#token: i32,
+    pub r#manifest: Option<AssetPackManifest>,
+}
+
+impl Packet for AssetEditorCreateAssetPack {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorCreateAssetPack",
+        id: 316,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorDeleteAssetPack {
+    pub r#id: Option<ByteString>,
+}
+
+impl Packet for AssetEditorDeleteAssetPack {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorDeleteAssetPack",
+        id: 317,
+        is_compressed: false,
+        max_size: 16384006,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorEnableAssetPack {
+    pub r#id: Option<ByteString>,
+    pub r#enabled: bool,
+}
+
+impl Packet for AssetEditorEnableAssetPack {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorEnableAssetPack",
+        id: 318,
+        is_compressed: false,
+        max_size: 16384007,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorAssetListSetup {
+    pub r#pack: Option<ByteString>,
+    pub r#isReadOnly: bool,
+    pub r#canBeDeleted: bool,
+    pub r#tree: AssetEditorFileTree,
+    pub r#paths: Option<Vec<AssetEditorFileEntry>>,
+}
+
+impl Packet for AssetEditorAssetListSetup {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorAssetListSetup",
+        id: 319,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum AssetEditorFileTree {
+    #[default]
+    r#Server,
+    r#Common,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorFileEntry {
+    pub r#path: Option<ByteString>,
+    pub r#isDirectory: bool,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorAssetListUpdate {
+    pub r#pack: Option<ByteString>,
+    pub r#additions: Option<Vec<AssetEditorFileEntry>>,
+    pub r#deletions: Option<Vec<AssetEditorFileEntry>>,
+}
+
+impl Packet for AssetEditorAssetListUpdate {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorAssetListUpdate",
+        id: 320,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorRequestChildrenList {
+    pub r#path: Option<AssetPath>,
+}
+
+impl Packet for AssetEditorRequestChildrenList {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorRequestChildrenList",
+        id: 321,
+        is_compressed: false,
+        max_size: 32768020,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorRequestChildrenListReply {
+    pub r#path: Option<AssetPath>,
+    pub r#childrenIds: Option<Vec<ByteString>>,
+}
+
+impl Packet for AssetEditorRequestChildrenListReply {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorRequestChildrenListReply",
+        id: 322,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorUpdateJsonAsset {
+    pub r#token: i32,
+    pub r#assetType: Option<ByteString>,
+    pub r#path: Option<AssetPath>,
+    pub r#assetIndex: i32,
+    pub r#commands: Option<Vec<JsonUpdateCommand>>,
+}
+
+impl Packet for AssetEditorUpdateJsonAsset {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorUpdateJsonAsset",
+        id: 323,
+        is_compressed: true,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct JsonUpdateCommand {
+    pub r#type: JsonUpdateType,
+    pub r#path: Option<Vec<ByteString>>,
+    pub r#value: Option<ByteString>,
+    pub r#previousValue: Option<ByteString>,
+    pub r#firstCreatedProperty: Option<Vec<ByteString>>,
+    pub r#rebuildCaches: Option<AssetEditorRebuildCaches>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum JsonUpdateType {
+    #[default]
+    r#SetProperty,
+    r#InsertProperty,
+    r#RemoveProperty,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct AssetEditorRebuildCaches {
+    pub r#blockTextures: bool,
+    pub r#models: bool,
+    pub r#modelTextures: bool,
+    pub r#mapGeometry: bool,
+    pub r#itemIcons: bool,
+}
+
+impl Packet for AssetEditorRebuildCaches {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorRebuildCaches",
+        id: 348,
+        is_compressed: false,
+        max_size: 5,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorUpdateAsset {
+    pub r#token: i32,
+    pub r#assetType: Option<ByteString>,
+    pub r#path: Option<AssetPath>,
+    pub r#assetIndex: i32,
+    pub r#data: Option<Bytes>,
+}
+
+impl Packet for AssetEditorUpdateAsset {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorUpdateAsset",
+        id: 324,
+        is_compressed: false,
+        max_size: 53248050,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorJsonAssetUpdated {
+    pub r#path: Option<AssetPath>,
+    pub r#commands: Option<Vec<JsonUpdateCommand>>,
+}
+
+impl Packet for AssetEditorJsonAssetUpdated {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorJsonAssetUpdated",
+        id: 325,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorAssetUpdated {
+    pub r#path: Option<AssetPath>,
+    pub r#data: Option<Bytes>,
+}
+
+impl Packet for AssetEditorAssetUpdated {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorAssetUpdated",
+        id: 326,
+        is_compressed: false,
+        max_size: 36864033,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorCreateAsset {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+    pub r#data: Option<Bytes>,
+    pub r#rebuildCaches: Option<AssetEditorRebuildCaches>,
+    pub r#buttonId: Option<ByteString>,
+}
+
+impl Packet for AssetEditorCreateAsset {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorCreateAsset",
+        id: 327,
+        is_compressed: false,
+        max_size: 53248051,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorRenameAsset {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+    pub r#newPath: Option<AssetPath>,
+}
+
+impl Packet for AssetEditorRenameAsset {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorRenameAsset",
+        id: 328,
+        is_compressed: false,
+        max_size: 65536051,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorDeleteAsset {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+}
+
+impl Packet for AssetEditorDeleteAsset {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorDeleteAsset",
+        id: 329,
+        is_compressed: false,
+        max_size: 32768024,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorDiscardChanges {
+    pub r#assets: Option<Vec<TimestampedAssetReference>>,
+}
+
+impl Packet for AssetEditorDiscardChanges {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorDiscardChanges",
+        id: 330,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct TimestampedAssetReference {
+    pub r#path: Option<AssetPath>,
+    pub r#timestamp: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorFetchAutoCompleteData {
+    pub r#token: i32,
+    pub r#dataset: Option<ByteString>,
+    pub r#query: Option<ByteString>,
+}
+
+impl Packet for AssetEditorFetchAutoCompleteData {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorFetchAutoCompleteData",
+        id: 331,
+        is_compressed: false,
+        max_size: 32768023,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorFetchAutoCompleteDataReply {
+    pub r#token: i32,
+    pub r#results: Option<Vec<ByteString>>,
+}
+
+impl Packet for AssetEditorFetchAutoCompleteDataReply {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorFetchAutoCompleteDataReply",
+        id: 332,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorRequestDataset {
+    pub r#name: Option<ByteString>,
+}
+
+impl Packet for AssetEditorRequestDataset {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorRequestDataset",
+        id: 333,
+        is_compressed: false,
+        max_size: 16384006,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorRequestDatasetReply {
+    pub r#name: Option<ByteString>,
+    pub r#ids: Option<Vec<ByteString>>,
+}
+
+impl Packet for AssetEditorRequestDatasetReply {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorRequestDatasetReply",
+        id: 334,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorActivateButton {
+    pub r#buttonId: Option<ByteString>,
+}
+
+impl Packet for AssetEditorActivateButton {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorActivateButton",
+        id: 335,
+        is_compressed: false,
+        max_size: 16384006,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorSelectAsset {
+    pub r#path: Option<AssetPath>,
+}
+
+impl Packet for AssetEditorSelectAsset {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorSelectAsset",
+        id: 336,
+        is_compressed: false,
+        max_size: 32768020,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorPopupNotification {
+    pub r#type: AssetEditorPopupNotificationType,
+    pub r#message: Option<FormattedMessage>,
+}
+
+impl Packet for AssetEditorPopupNotification {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorPopupNotification",
+        id: 337,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum AssetEditorPopupNotificationType {
+    #[default]
+    r#Info,
+    r#Success,
+    r#Error,
+    r#Warning,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorFetchLastModifiedAssets {}
+
+impl Packet for AssetEditorFetchLastModifiedAssets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorFetchLastModifiedAssets",
+        id: 338,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorLastModifiedAssets {
+    pub r#assets: Option<Vec<AssetInfo>>,
+}
+
+impl Packet for AssetEditorLastModifiedAssets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorLastModifiedAssets",
+        id: 339,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetInfo {
+    pub r#path: Option<AssetPath>,
+    pub r#oldPath: Option<AssetPath>,
+    pub r#isDeleted: bool,
+    pub r#isNew: bool,
+    pub r#lastModificationDate: i64,
+    pub r#lastModificationUsername: Option<ByteString>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorModifiedAssetsCount {
+    pub r#count: i32,
+}
+
+impl Packet for AssetEditorModifiedAssetsCount {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorModifiedAssetsCount",
+        id: 340,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorSubscribeModifiedAssetsChanges {
+    pub r#subscribe: bool,
+}
+
+impl Packet for AssetEditorSubscribeModifiedAssetsChanges {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorSubscribeModifiedAssetsChanges",
+        id: 341,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorExportAssets {
+    pub r#paths: Option<Vec<AssetPath>>,
+}
+
+impl Packet for AssetEditorExportAssets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorExportAssets",
+        id: 342,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorExportAssetInitialize {
+    pub r#asset: Option<AssetEditorAsset>,
+    pub r#oldPath: Option<AssetPath>,
+    pub r#size: i32,
+    pub r#failed: bool,
+}
+
+impl Packet for AssetEditorExportAssetInitialize {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorExportAssetInitialize",
+        id: 343,
+        is_compressed: false,
+        max_size: 81920066,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorAsset {
+    pub r#hash: Option<ByteString>,
+    pub r#path: Option<AssetPath>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorExportAssetPart {
+    pub r#part: Option<Bytes>,
+}
+
+impl Packet for AssetEditorExportAssetPart {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorExportAssetPart",
+        id: 344,
+        is_compressed: true,
+        max_size: 4096006,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorExportAssetFinalize {}
+
+impl Packet for AssetEditorExportAssetFinalize {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorExportAssetFinalize",
+        id: 345,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorExportDeleteAssets {
+    pub r#asset: Option<Vec<AssetEditorAsset>>,
+}
+
+impl Packet for AssetEditorExportDeleteAssets {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorExportDeleteAssets",
+        id: 346,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorExportComplete {
+    pub r#assets: Option<Vec<TimestampedAssetReference>>,
+}
+
+impl Packet for AssetEditorExportComplete {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorExportComplete",
+        id: 347,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorUndoChanges {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+}
+
+impl Packet for AssetEditorUndoChanges {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorUndoChanges",
+        id: 349,
+        is_compressed: false,
+        max_size: 32768024,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorRedoChanges {
+    pub r#token: i32,
+    pub r#path: Option<AssetPath>,
+}
+
+impl Packet for AssetEditorRedoChanges {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorRedoChanges",
+        id: 350,
+        is_compressed: false,
+        max_size: 32768024,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorUndoRedoReply {
+    pub r#token: i32,
+    pub r#command: Option<JsonUpdateCommand>,
+}
+
+impl Packet for AssetEditorUndoRedoReply {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorUndoRedoReply",
+        id: 351,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorSetGameTime {
+    pub r#gameTime: Option<InstantData>,
+    pub r#paused: bool,
+}
+
+impl Packet for AssetEditorSetGameTime {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorSetGameTime",
+        id: 352,
+        is_compressed: false,
+        max_size: 14,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorUpdateSecondsPerGameDay {
+    pub r#daytimeDurationSeconds: i32,
+    pub r#nighttimeDurationSeconds: i32,
+}
+
+impl Packet for AssetEditorUpdateSecondsPerGameDay {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorUpdateSecondsPerGameDay",
+        id: 353,
+        is_compressed: false,
+        max_size: 8,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorUpdateWeatherPreviewLock {
+    pub r#locked: bool,
+}
+
+impl Packet for AssetEditorUpdateWeatherPreviewLock {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorUpdateWeatherPreviewLock",
+        id: 354,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct AssetEditorUpdateModelPreview {
+    pub r#assetPath: Option<AssetPath>,
+    pub r#model: Option<Model>,
+    pub r#block: Option<BlockType>,
+    pub r#camera: Option<AssetEditorPreviewCameraSettings>,
+}
+
+impl Packet for AssetEditorUpdateModelPreview {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "AssetEditorUpdateModelPreview",
+        id: 355,
+        is_compressed: false,
+        max_size: 1677721600,
+        category: PacketCategory::ASSET_EDITOR,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[codec(small)]
+pub struct AssetEditorPreviewCameraSettings {
+    pub r#modelScale: f32,
+    pub r#cameraPosition: Option<Vector3f>,
+    pub r#cameraOrientation: Option<Vector3f>,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdateSunSettings {
+    pub r#heightPercentage: f32,
+    pub r#angleRadians: f32,
+}
+
+impl Packet for UpdateSunSettings {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdateSunSettings",
+        id: 360,
+        is_compressed: false,
+        max_size: 8,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct UpdatePostFxSettings {
+    pub r#globalIntensity: f32,
+    pub r#power: f32,
+    pub r#sunshaftScale: f32,
+    pub r#sunIntensity: f32,
+    pub r#sunshaftIntensity: f32,
+}
+
+impl Packet for UpdatePostFxSettings {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "UpdatePostFxSettings",
+        id: 361,
+        is_compressed: false,
+        max_size: 20,
+        category: PacketCategory::WORLD,
+        direction: PacketDirection::Clientbound,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolArgUpdate {
+    pub r#token: i32,
+    pub r#section: i32,
+    pub r#slot: i32,
+    pub r#group: BuilderToolArgGroup,
+    pub r#id: Option<ByteString>,
+    pub r#value: Option<ByteString>,
+}
+
+impl Packet for BuilderToolArgUpdate {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolArgUpdate",
+        id: 400,
+        is_compressed: false,
+        max_size: 32768032,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BuilderToolArgGroup {
+    #[default]
+    r#Tool,
+    r#Brush,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolEntityAction {
+    pub r#entityId: i32,
+    pub r#action: EntityToolAction,
+}
+
+impl Packet for BuilderToolEntityAction {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolEntityAction",
+        id: 401,
+        is_compressed: false,
+        max_size: 5,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum EntityToolAction {
+    #[default]
+    r#Remove,
+    r#Clone,
+    r#Freeze,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSetEntityTransform {
+    pub r#entityId: i32,
+    pub r#modelTransform: Option<ModelTransform>,
+}
+
+impl Packet for BuilderToolSetEntityTransform {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSetEntityTransform",
+        id: 402,
+        is_compressed: false,
+        max_size: 54,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolExtrudeAction {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#xNormal: i32,
+    pub r#yNormal: i32,
+    pub r#zNormal: i32,
+}
+
+impl Packet for BuilderToolExtrudeAction {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolExtrudeAction",
+        id: 403,
+        is_compressed: false,
+        max_size: 24,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolStackArea {
+    pub r#selectionMin: Option<BlockPosition>,
+    pub r#selectionMax: Option<BlockPosition>,
+    pub r#xNormal: i32,
+    pub r#yNormal: i32,
+    pub r#zNormal: i32,
+    pub r#numStacks: i32,
+}
+
+impl Packet for BuilderToolStackArea {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolStackArea",
+        id: 404,
+        is_compressed: false,
+        max_size: 41,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSelectionTransform {
+    pub r#transformationMatrix: Option<Vec<f32>>,
+    pub r#initialSelectionMin: Option<BlockPosition>,
+    pub r#initialSelectionMax: Option<BlockPosition>,
+    pub r#initialRotationOrigin: Option<Vector3f>,
+    pub r#cutOriginal: bool,
+    pub r#applyTransformationToSelectionMinMax: bool,
+    pub r#isExitingTransformMode: bool,
+    pub r#initialPastePointForClipboardPaste: Option<BlockPosition>,
+}
+
+impl Packet for BuilderToolSelectionTransform {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSelectionTransform",
+        id: 405,
+        is_compressed: false,
+        max_size: 16384057,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolRotateClipboard {
+    pub r#angle: i32,
+    pub r#axis: Axis,
+}
+
+impl Packet for BuilderToolRotateClipboard {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolRotateClipboard",
+        id: 406,
+        is_compressed: false,
+        max_size: 5,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum Axis {
+    #[default]
+    r#X,
+    r#Y,
+    r#Z,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolPasteClipboard {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+}
+
+impl Packet for BuilderToolPasteClipboard {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolPasteClipboard",
+        id: 407,
+        is_compressed: false,
+        max_size: 12,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSetTransformationModeState {
+    pub r#enabled: bool,
+}
+
+impl Packet for BuilderToolSetTransformationModeState {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSetTransformationModeState",
+        id: 408,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSelectionUpdate {
+    pub r#xMin: i32,
+    pub r#yMin: i32,
+    pub r#zMin: i32,
+    pub r#xMax: i32,
+    pub r#yMax: i32,
+    pub r#zMax: i32,
+}
+
+impl Packet for BuilderToolSelectionUpdate {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSelectionUpdate",
+        id: 409,
+        is_compressed: false,
+        max_size: 24,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSelectionToolAskForClipboard {}
+
+impl Packet for BuilderToolSelectionToolAskForClipboard {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSelectionToolAskForClipboard",
+        id: 410,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSelectionToolReplyWithClipboard {
+    pub r#blocksChange: Option<Vec<BlockChange>>,
+    pub r#fluidsChange: Option<Vec<FluidChange>>,
+}
+
+impl Packet for BuilderToolSelectionToolReplyWithClipboard {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSelectionToolReplyWithClipboard",
+        id: 411,
+        is_compressed: true,
+        max_size: 139264019,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolGeneralAction {
+    pub r#action: BuilderToolAction,
+}
+
+impl Packet for BuilderToolGeneralAction {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolGeneralAction",
+        id: 412,
+        is_compressed: false,
+        max_size: 1,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Ordinalize, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[repr(u8)]
+pub enum BuilderToolAction {
+    #[default]
+    r#SelectionPosition1,
+    r#SelectionPosition2,
+    r#SelectionCopy,
+    r#HistoryUndo,
+    r#HistoryRedo,
+    r#ActivateToolMode,
+    r#DeactivateToolMode,
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolOnUseInteraction {
+    pub r#type: InteractionType,
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+    pub r#offsetForPaintModeX: i32,
+    pub r#offsetForPaintModeY: i32,
+    pub r#offsetForPaintModeZ: i32,
+    pub r#isAltPlaySculptBrushModDown: bool,
+    pub r#isHoldDownInteraction: bool,
+    pub r#isDoServerRaytraceForPosition: bool,
+    pub r#isShowEditNotifications: bool,
+    pub r#maxLengthToolIgnoreHistory: i32,
+    pub r#raycastOriginX: f32,
+    pub r#raycastOriginY: f32,
+    pub r#raycastOriginZ: f32,
+    pub r#raycastDirectionX: f32,
+    pub r#raycastDirectionY: f32,
+    pub r#raycastDirectionZ: f32,
+}
+
+impl Packet for BuilderToolOnUseInteraction {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolOnUseInteraction",
+        id: 413,
+        is_compressed: false,
+        max_size: 57,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolLineAction {
+    pub r#xStart: i32,
+    pub r#yStart: i32,
+    pub r#zStart: i32,
+    pub r#xEnd: i32,
+    pub r#yEnd: i32,
+    pub r#zEnd: i32,
+}
+
+impl Packet for BuilderToolLineAction {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolLineAction",
+        id: 414,
+        is_compressed: false,
+        max_size: 24,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolShowAnchor {
+    pub r#x: i32,
+    pub r#y: i32,
+    pub r#z: i32,
+}
+
+impl Packet for BuilderToolShowAnchor {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolShowAnchor",
+        id: 415,
+        is_compressed: false,
+        max_size: 12,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolHideAnchors {}
+
+impl Packet for BuilderToolHideAnchors {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolHideAnchors",
+        id: 416,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct PrefabUnselectPrefab {}
+
+impl Packet for PrefabUnselectPrefab {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "PrefabUnselectPrefab",
+        id: 417,
+        is_compressed: false,
+        max_size: 0,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolsSetSoundSet {
+    pub r#soundSetIndex: i32,
+}
+
+impl Packet for BuilderToolsSetSoundSet {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolsSetSoundSet",
+        id: 418,
+        is_compressed: false,
+        max_size: 4,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolLaserPointer {
+    pub r#playerNetworkId: i32,
+    pub r#startX: f32,
+    pub r#startY: f32,
+    pub r#startZ: f32,
+    pub r#endX: f32,
+    pub r#endY: f32,
+    pub r#endZ: f32,
+    pub r#color: i32,
+    pub r#durationMs: i32,
+}
+
+impl Packet for BuilderToolLaserPointer {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolLaserPointer",
+        id: 419,
+        is_compressed: false,
+        max_size: 36,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSetEntityScale {
+    pub r#entityId: i32,
+    pub r#scale: f32,
+}
+
+impl Packet for BuilderToolSetEntityScale {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSetEntityScale",
+        id: 420,
+        is_compressed: false,
+        max_size: 8,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSetEntityPickupEnabled {
+    pub r#entityId: i32,
+    pub r#enabled: bool,
+}
+
+impl Packet for BuilderToolSetEntityPickupEnabled {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSetEntityPickupEnabled",
+        id: 421,
+        is_compressed: false,
+        max_size: 5,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSetEntityLight {
+    pub r#entityId: i32,
+    pub r#light: Option<ColorLight>,
+}
+
+impl Packet for BuilderToolSetEntityLight {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSetEntityLight",
+        id: 422,
+        is_compressed: false,
+        max_size: 9,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
+
+#[derive(Debug, Clone, Default, Serde)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+pub struct BuilderToolSetNPCDebug {
+    pub r#entityId: i32,
+    pub r#enabled: bool,
+}
+
+impl Packet for BuilderToolSetNPCDebug {
+    const DESCRIPTOR: &'static PacketDescriptor = &PacketDescriptor {
+        name: "BuilderToolSetNPCDebug",
+        id: 423,
+        is_compressed: false,
+        max_size: 5,
+        category: PacketCategory::BUILDER_TOOLS,
+        direction: PacketDirection::Both,
+    };
+}
@@ -1,8 +1,14 @@
+extern crate self as customtale_protocol;
+
 pub mod serde;
 
-#[path = "generated/packets.rs"]
 pub mod packets;
 
-#[path = "generated/tests.rs"]
-#[cfg(test)]
-mod tests;
+#[cfg(feature = "framed")]
+pub mod framed;
+
+#[cfg(feature = "framed")]
+pub mod capture;
+
+#[cfg(feature = "framed")]
+pub mod compression;
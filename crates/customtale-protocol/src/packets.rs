@@ -0,0 +1,333 @@
+//! Packet definitions for every supported protocol version.
+//!
+//! Each version's packet table is generated into its own module by `customtale-protocol-tools`.
+//! The definitions of the newest version are re-exported here.
+
+use std::fmt;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::serde::{DecodeError, DecodeErrorKind, DecodeLimits, DecodeResult, DynamicValue};
+
+#[path = "generated/v1/packets.rs"]
+pub mod v1;
+
+pub use self::v1::*;
+
+// === PacketDescriptor === //
+
+bitflags::bitflags! {
+    #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+    pub struct PacketCategory: u32 {
+        const ASSET_EDITOR = 1 << 0;
+        const ASSETS = 1 << 1;
+        const AUTH = 1 << 2;
+        const BUILDER_TOOLS = 1 << 3;
+        const CAMERA = 1 << 4;
+        const CONNECTION = 1 << 5;
+        const ENTITIES = 1 << 6;
+        const INTERACTION = 1 << 7;
+        const INTERFACE = 1 << 8;
+        const INVENTORY = 1 << 9;
+        const MACHINIMA = 1 << 10;
+        const PLAYER = 1 << 11;
+        const SERVER_ACCESS = 1 << 12;
+        const SETUP = 1 << 13;
+        const WINDOW = 1 << 14;
+        const WORLD = 1 << 15;
+        const WORLD_MAP = 1 << 16;
+    }
+}
+
+/// The side of a connection which sends a given packet.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum PacketDirection {
+    /// Sent by servers to clients.
+    Clientbound,
+    /// Sent by clients to servers.
+    Serverbound,
+    /// Sent by both sides.
+    Both,
+}
+
+impl PacketDirection {
+    pub const fn is_clientbound(self) -> bool {
+        matches!(self, Self::Clientbound | Self::Both)
+    }
+
+    pub const fn is_serverbound(self) -> bool {
+        matches!(self, Self::Serverbound | Self::Both)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PacketDescriptor {
+    pub name: &'static str,
+    pub id: u32,
+    pub is_compressed: bool,
+    pub max_size: u32,
+    pub category: PacketCategory,
+    pub direction: PacketDirection,
+}
+
+impl fmt::Display for PacketDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", self.name, self.id)
+    }
+}
+
+// === PacketTable === //
+
+/// The per-packet operations of a generated packet table, type-erased over the packet type `P`.
+pub struct PacketEntry<P: 'static> {
+    pub descriptor: &'static PacketDescriptor,
+    pub decode: fn(Bytes, &mut DecodeLimits) -> DecodeResult<P>,
+    pub decode_static: fn(Bytes, &mut DecodeLimits) -> DecodeResult<P>,
+    pub from_dynamic: fn(&DynamicValue) -> anyhow::Result<P>,
+}
+
+impl<P> fmt::Debug for PacketEntry<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PacketEntry")
+            .field("descriptor", &self.descriptor)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A lookup table from packet ID to [`PacketEntry`], indexed directly by ID.
+pub type PacketTable<P> = [Option<&'static PacketEntry<P>>];
+
+/// Determines the length of the [`PacketTable`] needed to index every entry in `entries`.
+pub const fn packet_table_len<P>(entries: &[PacketEntry<P>]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < entries.len() {
+        let id = entries[i].descriptor.id as usize;
+        if id >= len {
+            len = id + 1;
+        }
+        i += 1;
+    }
+
+    len
+}
+
+/// Builds a [`PacketTable`] from `entries`, failing const evaluation if two entries share an ID.
+pub const fn build_packet_table<P, const N: usize>(
+    entries: &'static [PacketEntry<P>],
+) -> [Option<&'static PacketEntry<P>>; N] {
+    let mut table = [None; N];
+    let mut i = 0;
+
+    while i < entries.len() {
+        let entry = &entries[i];
+        let id = entry.descriptor.id as usize;
+        assert!(table[id].is_none(), "two packets share the same ID");
+        table[id] = Some(entry);
+        i += 1;
+    }
+
+    table
+}
+
+/// Looks up the entry for `id` in `table`.
+pub const fn packet_table_entry<P>(
+    table: &'static PacketTable<P>,
+    id: u32,
+) -> Option<&'static PacketEntry<P>> {
+    let id = id as usize;
+    if id < table.len() { table[id] } else { None }
+}
+
+// === Protocol === //
+
+/// Identifies a protocol version by the `protocolCrc` and `protocolBuildNumber` which clients send
+/// in their `Connect` packet.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct ProtocolVersion {
+    pub crc: i32,
+    pub build_number: i32,
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "build {} (CRC {:08x})", self.build_number, self.crc)
+    }
+}
+
+/// A packet type which generated tables decode into.
+///
+/// Every table of a [`ProtocolRegistry`] decodes into the same packet type, so that the rest of an
+/// endpoint need not care which version a peer speaks.
+pub trait ProtocolPacket: fmt::Debug + Sized + 'static {
+    fn descriptor(&self) -> &'static PacketDescriptor;
+
+    fn encode_static(&self, target: &mut BytesMut) -> anyhow::Result<()>;
+}
+
+/// Describes one of the generated packet tables, which decodes packets into `P`.
+#[derive(Debug)]
+pub struct Protocol<P: 'static> {
+    pub module_path: &'static str,
+    /// The version the table was generated from, or `None` if the generator could not determine
+    /// it from the server JAR.
+    pub version: Option<ProtocolVersion>,
+    pub packets: &'static [&'static PacketDescriptor],
+    pub table: &'static PacketTable<P>,
+}
+
+impl<P: ProtocolPacket> Protocol<P> {
+    /// The name of the module the table was generated into, e.g. `v1`.
+    pub fn name(&self) -> &'static str {
+        self.module_path
+            .rsplit("::")
+            .next()
+            .unwrap_or(self.module_path)
+    }
+
+    pub fn descriptor_for(&self, id: u32) -> Option<&'static PacketDescriptor> {
+        packet_table_entry(self.table, id).map(|entry| entry.descriptor)
+    }
+
+    /// Whether `descriptor` describes one of this table's packets.
+    pub fn contains(&self, descriptor: &PacketDescriptor) -> bool {
+        // Descriptors are constants, so the same one may live at several addresses.
+        self.descriptor_for(descriptor.id)
+            .is_some_and(|own| own.name == descriptor.name)
+    }
+
+    pub fn decode_with_limits(
+        &self,
+        id: u32,
+        contents: Bytes,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult<P> {
+        match packet_table_entry(self.table, id) {
+            Some(entry) => (entry.decode)(contents, limits),
+            None => Err(unknown_packet::<P>(id, contents)),
+        }
+    }
+
+    pub fn decode_static_with_limits(
+        &self,
+        id: u32,
+        contents: Bytes,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult<P> {
+        match packet_table_entry(self.table, id) {
+            Some(entry) => (entry.decode_static)(contents, limits),
+            None => Err(unknown_packet::<P>(id, contents)),
+        }
+    }
+
+    /// Encodes `packet`, failing if it is not one of this table's packets.
+    pub fn encode_static(&self, packet: &P, target: &mut BytesMut) -> anyhow::Result<()> {
+        let descriptor = packet.descriptor();
+
+        if !self.contains(descriptor) {
+            anyhow::bail!("packet {descriptor} is not part of protocol {self}");
+        }
+
+        packet.encode_static(target)
+    }
+}
+
+fn unknown_packet<P>(id: u32, contents: Bytes) -> DecodeError {
+    DecodeError::at(DecodeErrorKind::UnknownPacket(id), contents.len()).resolve::<P>(contents.len())
+}
+
+impl<P: ProtocolPacket> fmt::Display for Protocol<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.version {
+            Some(version) => write!(f, "{} ({version})", self.name()),
+            None => write!(f, "{} (unknown version)", self.name()),
+        }
+    }
+}
+
+/// The set of protocol versions an endpoint is willing to speak, all of which decode into `P`.
+#[derive(Debug)]
+pub struct ProtocolRegistry<P: 'static> {
+    protocols: Vec<&'static Protocol<P>>,
+    fallback: Option<&'static Protocol<P>>,
+}
+
+impl<P> Clone for ProtocolRegistry<P> {
+    fn clone(&self) -> Self {
+        Self {
+            protocols: self.protocols.clone(),
+            fallback: self.fallback,
+        }
+    }
+}
+
+impl<P> Default for ProtocolRegistry<P> {
+    fn default() -> Self {
+        Self {
+            protocols: Vec::new(),
+            fallback: None,
+        }
+    }
+}
+
+impl ProtocolRegistry<AnyPacket> {
+    /// Creates a registry containing every table generated into this crate, falling back to the
+    /// newest.
+    pub fn builtin() -> Self {
+        Self {
+            protocols: vec![&v1::PROTOCOL],
+            fallback: Some(&PROTOCOL),
+        }
+    }
+}
+
+impl<P: ProtocolPacket> ProtocolRegistry<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, protocol: &'static Protocol<P>) {
+        self.protocols.push(protocol);
+    }
+
+    pub fn protocols(&self) -> &[&'static Protocol<P>] {
+        &self.protocols
+    }
+
+    /// Finds a registered table by its [name](Protocol::name).
+    pub fn get(&self, name: &str) -> Option<&'static Protocol<P>> {
+        self.protocols
+            .iter()
+            .copied()
+            .find(|protocol| protocol.name() == name)
+    }
+
+    pub fn fallback(&self) -> Option<&'static Protocol<P>> {
+        self.fallback
+    }
+
+    /// Serves `protocol` to clients whose version no table claims, who are refused otherwise.
+    /// This is the only way to speak a table whose version is unknown.
+    pub fn set_fallback(&mut self, protocol: Option<&'static Protocol<P>>) {
+        self.fallback = protocol;
+    }
+
+    /// Whether any client at all could [select](Self::select) a table.
+    pub fn is_selectable(&self) -> bool {
+        self.fallback.is_some()
+            || self
+                .protocols
+                .iter()
+                .any(|protocol| protocol.version.is_some())
+    }
+
+    /// Picks the table for a client speaking `version`.
+    pub fn select(&self, version: ProtocolVersion) -> Option<&'static Protocol<P>> {
+        self.protocols
+            .iter()
+            .copied()
+            .find(|protocol| protocol.version == Some(version))
+            .or(self.fallback)
+    }
+}
@@ -1,14 +1,26 @@
-use anyhow::Context;
+use std::marker::PhantomData;
+
 use bytes::{Buf, BufMut, Bytes, BytesMut};
-use bytes_varint::{VarIntSupport, VarIntSupportMut};
+use bytes_varint::VarIntSupportMut;
 use derive_where::derive_where;
 
-use crate::serde::{Codec, CodecValue, ErasedCodec, Serde};
+use crate::serde::{
+    BoundedSerde, BoundedStaticSerde, ByteString, Codec, CodecString, DecodeError, DecodeErrorKind,
+    DecodeLimits, DecodeResult, ErasedCodec, Schema, Serde, StaticSerde, decode_len,
+    u32_varint_len,
+};
+
+pub const DEFAULT_MAX_LEN: u32 = 4096000;
 
 // === Dictionary === //
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[derive_where(Default)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
+#[cfg_attr(feature = "serde-interop", serde(transparent))]
 pub struct Dictionary<K, V> {
     pub entries: Vec<DictionaryEntry<K, V>>,
 }
@@ -19,7 +31,11 @@ impl<K, V> Dictionary<K, V> {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde-interop",
+    derive(::serde::Serialize, ::serde::Deserialize)
+)]
 pub struct DictionaryEntry<K, V> {
     pub key: K,
     pub value: V,
@@ -33,15 +49,15 @@ impl<K, V> DictionaryEntry<K, V> {
 
 // === Containers === //
 
-#[derive_where(Clone)]
-pub struct VarDictionaryCodec<K: CodecValue, V: CodecValue> {
-    key_codec: ErasedCodec<K>,
-    value_codec: ErasedCodec<V>,
+#[derive(Clone)]
+pub struct VarDictionaryCodec<KC, VC> {
+    key_codec: KC,
+    value_codec: VC,
     max_len: u32,
 }
 
-impl<K: CodecValue, V: CodecValue> VarDictionaryCodec<K, V> {
-    pub fn new(key_codec: ErasedCodec<K>, value_codec: ErasedCodec<V>, max_len: u32) -> Self {
+impl<KC: Codec, VC: Codec> VarDictionaryCodec<KC, VC> {
+    pub fn new(key_codec: KC, value_codec: VC, max_len: u32) -> Self {
         Self {
             key_codec,
             value_codec,
@@ -50,8 +66,8 @@ impl<K: CodecValue, V: CodecValue> VarDictionaryCodec<K, V> {
     }
 }
 
-impl<K: CodecValue, V: CodecValue> Codec for VarDictionaryCodec<K, V> {
-    type Target = Dictionary<K, V>;
+impl<KC: Codec, VC: Codec> Codec for VarDictionaryCodec<KC, VC> {
+    type Target = Dictionary<KC::Target, VC::Target>;
 
     fn fixed_size(&self) -> Option<usize> {
         None
@@ -62,30 +78,29 @@ impl<K: CodecValue, V: CodecValue> Codec for VarDictionaryCodec<K, V> {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        let len = buf
-            .try_get_u32_varint()
-            .context("failed to get dictionary length")?;
-
-        if len > self.max_len {
-            anyhow::bail!(
-                "map of length {len} exceeds maximum length of {}",
-                self.max_len
-            );
-        }
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let len = decode_len(buf, self.max_len)? as usize;
+
+        target.entries.truncate(len);
+
+        for idx in 0..len {
+            // Grow as entries arrive so that a large length prefix cannot allocate more than the
+            // buffer actually holds or the budget allows.
+            if idx == target.entries.len() {
+                limits.reserve_one(&mut target.entries, len, buf)?;
+                target.entries.push(DictionaryEntry::default());
+            }
 
-        target
-            .entries
-            .resize_with(len as usize, DictionaryEntry::default);
+            let entry = &mut target.entries[idx];
 
-        for entry in &mut target.entries {
             self.key_codec
-                .decode(&mut entry.key, buf, false)
-                .context("failed to read map key")?;
+                .decode(&mut entry.key, buf, false, limits)
+                .map_err(|err| err.in_key(idx))?;
 
             self.value_codec
-                .decode(&mut entry.value, buf, false)
-                .context("failed to read map value")?;
+                .decode(&mut entry.value, buf, false, limits)
+                .map_err(|err| err.in_value(idx))?;
         }
 
         Ok(())
@@ -109,28 +124,93 @@ impl<K: CodecValue, V: CodecValue> Codec for VarDictionaryCodec<K, V> {
 
         Ok(())
     }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        let entries = target
+            .entries
+            .iter()
+            .map(|DictionaryEntry { key, value }| {
+                self.key_codec.encoded_len(key) + self.value_codec.encoded_len(value)
+            })
+            .sum::<usize>();
+
+        u32_varint_len(target.entries.len() as u32) + entries
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Dictionary {
+            key: Box::new(self.key_codec.schema()),
+            value: Box::new(self.value_codec.schema()),
+            max_len: self.max_len,
+        }
+    }
 }
 
 impl<K: Serde, V: Serde> Serde for Dictionary<K, V> {
     fn build_codec() -> ErasedCodec<Self> {
-        VarDictionaryCodec::new(K::codec(), V::codec(), 4096000).erase()
+        Self::bounded_codec(DEFAULT_MAX_LEN)
     }
 }
 
-#[derive_where(Clone)]
-pub struct VarArrayCodec<T: CodecValue> {
-    codec: ErasedCodec<T>,
+impl<K: Serde, V: Serde> BoundedSerde for Dictionary<K, V> {
+    fn bounded_codec(max_len: u32) -> ErasedCodec<Self> {
+        VarDictionaryCodec::new(K::codec(), V::codec(), max_len).erase()
+    }
+}
+
+impl<K: StaticSerde, V: StaticSerde> StaticSerde for Dictionary<K, V> {
+    type StaticCodec = VarDictionaryCodec<K::StaticCodec, V::StaticCodec>;
+
+    fn static_codec() -> Self::StaticCodec {
+        Self::bounded_static_codec(DEFAULT_MAX_LEN)
+    }
+}
+
+impl<K: StaticSerde, V: StaticSerde> BoundedStaticSerde for Dictionary<K, V> {
+    type BoundedStaticCodec = VarDictionaryCodec<K::StaticCodec, V::StaticCodec>;
+
+    fn bounded_static_codec(max_len: u32) -> Self::BoundedStaticCodec {
+        VarDictionaryCodec::new(K::static_codec(), V::static_codec(), max_len)
+    }
+}
+
+#[derive(Clone)]
+pub struct VarArrayCodec<C> {
+    codec: C,
     max_len: u32,
 }
 
-impl<T: CodecValue> VarArrayCodec<T> {
-    pub fn new(codec: ErasedCodec<T>, max_len: u32) -> Self {
+impl<C: Codec> VarArrayCodec<C> {
+    pub fn new(codec: C, max_len: u32) -> Self {
         Self { codec, max_len }
     }
+
+    /// Decodes the length prefix of an array at the start of `buf` and returns an iterator which
+    /// decodes its elements one at a time, letting callers process arrays too large to hold in
+    /// memory at once. Since elements are handed off as they are decoded, each one is charged
+    /// against a fresh copy of `limits`.
+    pub fn elements(
+        &self,
+        mut buf: Bytes,
+        limits: DecodeLimits,
+    ) -> DecodeResult<ArrayElements<'_, C>> {
+        let input_len = buf.len();
+        let len = decode_len(&mut buf, self.max_len)
+            .map_err(|err| err.resolve::<Vec<C::Target>>(input_len))?;
+
+        Ok(ArrayElements {
+            codec: &self.codec,
+            buf,
+            limits,
+            input_len,
+            index: 0,
+            len: len as usize,
+        })
+    }
 }
 
-impl<T: CodecValue> Codec for VarArrayCodec<T> {
-    type Target = Vec<T>;
+impl<C: Codec> Codec for VarArrayCodec<C> {
+    type Target = Vec<C::Target>;
 
     fn fixed_size(&self) -> Option<usize> {
         None
@@ -141,22 +221,22 @@ impl<T: CodecValue> Codec for VarArrayCodec<T> {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        let len = buf
-            .try_get_u32_varint()
-            .context("failed to read array length")?;
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let len = decode_len(buf, self.max_len)? as usize;
 
-        if len > self.max_len {
-            anyhow::bail!(
-                "array had length {len} but the maximum allowed array length was {}",
-                self.max_len
-            );
-        }
+        target.truncate(len);
 
-        target.resize_with(len as usize, T::default);
+        for idx in 0..len {
+            // See `VarDictionaryCodec::decode`.
+            if idx == target.len() {
+                limits.reserve_one(target, len, buf)?;
+                target.push(Default::default());
+            }
 
-        for target in target {
-            self.codec.decode(target, buf, false)?;
+            self.codec
+                .decode(&mut target[idx], buf, false, limits)
+                .map_err(|err| err.in_index(idx))?;
         }
 
         Ok(())
@@ -179,11 +259,103 @@ impl<T: CodecValue> Codec for VarArrayCodec<T> {
 
         Ok(())
     }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        let elements = match self.codec.fixed_size() {
+            Some(size) => size * target.len(),
+            None => target
+                .iter()
+                .map(|target| self.codec.encoded_len(target))
+                .sum(),
+        };
+
+        u32_varint_len(target.len() as u32) + elements
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Array {
+            element: Box::new(self.codec.schema()),
+            max_len: self.max_len,
+        }
+    }
+}
+
+/// An iterator over the elements of an array, created by [`VarArrayCodec::elements`].
+pub struct ArrayElements<'a, C> {
+    codec: &'a C,
+    buf: Bytes,
+    limits: DecodeLimits,
+    input_len: usize,
+    index: usize,
+    len: usize,
+}
+
+impl<C: Codec> ArrayElements<'_, C> {
+    /// Returns the bytes following the elements which have been decoded so far.
+    pub fn remainder(&self) -> &Bytes {
+        &self.buf
+    }
+}
+
+impl<C: Codec> Iterator for ArrayElements<'_, C> {
+    type Item = DecodeResult<C::Target>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.len {
+            return None;
+        }
+
+        let mut target = C::Target::default();
+        self.limits.reset();
+
+        let res = self
+            .codec
+            .decode(&mut target, &mut self.buf, false, &mut self.limits)
+            .map_err(|err| {
+                err.in_index(self.index)
+                    .resolve::<Vec<C::Target>>(self.input_len)
+            });
+
+        // Decoding cannot resume after an error since the element's extent is unknown.
+        self.index = if res.is_ok() {
+            self.index + 1
+        } else {
+            self.len
+        };
+
+        Some(res.map(|()| target))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len - self.index))
+    }
 }
 
 impl<T: Serde> Serde for Vec<T> {
     fn build_codec() -> ErasedCodec<Self> {
-        VarArrayCodec::new(T::codec(), 4096000).erase()
+        Self::bounded_codec(DEFAULT_MAX_LEN)
+    }
+}
+
+impl<T: Serde> BoundedSerde for Vec<T> {
+    fn bounded_codec(max_len: u32) -> ErasedCodec<Self> {
+        VarArrayCodec::new(T::codec(), max_len).erase()
+    }
+}
+
+impl<T: StaticSerde> StaticSerde for Vec<T> {
+    type StaticCodec = VarArrayCodec<T::StaticCodec>;
+
+    fn static_codec() -> Self::StaticCodec {
+        Self::bounded_static_codec(DEFAULT_MAX_LEN)
+    }
+}
+
+impl<T: StaticSerde> BoundedStaticSerde for Vec<T> {
+    type BoundedStaticCodec = VarArrayCodec<T::StaticCodec>;
+
+    fn bounded_static_codec(max_len: u32) -> Self::BoundedStaticCodec {
+        VarArrayCodec::new(T::static_codec(), max_len)
     }
 }
 
@@ -212,13 +384,10 @@ impl Codec for ExactByteArrayCodec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
         if buf.remaining() < self.size as usize {
-            anyhow::bail!(
-                "need {} byte(s) for array but got {}",
-                self.size,
-                buf.remaining()
-            );
+            return Err(DecodeError::unexpected_end(self.size as usize, buf));
         }
 
         *target = buf.split_to(self.size as usize);
@@ -239,6 +408,14 @@ impl Codec for ExactByteArrayCodec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        self.size as usize
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::ExactByteArray { size: self.size }
+    }
 }
 
 #[derive(Clone)]
@@ -264,17 +441,12 @@ impl Codec for VarByteArrayCodec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        let len = buf
-            .try_get_u32_varint()
-            .context("failed to read byte array length")?;
-
-        if len > self.max_len {
-            anyhow::bail!("byte array is beyond maximum allowed length");
-        }
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let len = decode_len(buf, self.max_len)?;
 
         if buf.remaining() < len as usize {
-            anyhow::bail!("not enough bytes for byte array");
+            return Err(DecodeError::unexpected_end(len as usize, buf));
         }
 
         *target = buf.split_to(len as usize);
@@ -292,29 +464,80 @@ impl Codec for VarByteArrayCodec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        u32_varint_len(target.len() as u32) + target.len()
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::VarByteArray {
+            max_len: self.max_len,
+        }
+    }
 }
 
 impl Serde for Bytes {
     fn build_codec() -> ErasedCodec<Self> {
-        VarByteArrayCodec::new(4096000).erase()
+        Self::bounded_codec(DEFAULT_MAX_LEN)
+    }
+}
+
+impl BoundedSerde for Bytes {
+    fn bounded_codec(max_len: u32) -> ErasedCodec<Self> {
+        VarByteArrayCodec::new(max_len).erase()
+    }
+}
+
+impl StaticSerde for Bytes {
+    type StaticCodec = VarByteArrayCodec;
+
+    fn static_codec() -> Self::StaticCodec {
+        Self::bounded_static_codec(DEFAULT_MAX_LEN)
+    }
+}
+
+impl BoundedStaticSerde for Bytes {
+    type BoundedStaticCodec = VarByteArrayCodec;
+
+    fn bounded_static_codec(max_len: u32) -> Self::BoundedStaticCodec {
+        VarByteArrayCodec::new(max_len)
     }
 }
 
 // === Strings === //
 
-#[derive(Clone)]
-pub struct FixedSizeStringCodec {
+fn decode_utf8<S: CodecString>(
+    bytes: Bytes,
+    remaining: usize,
+    limits: &mut DecodeLimits,
+) -> DecodeResult<S> {
+    if S::COPIES_INPUT {
+        limits
+            .try_charge(bytes.len())
+            .map_err(|kind| DecodeError::at(kind, remaining))?;
+    }
+
+    S::from_utf8_bytes(bytes)
+        .map_err(|err| DecodeError::at(DecodeErrorKind::InvalidUtf8(err), remaining))
+}
+
+#[derive_where(Clone)]
+pub struct FixedSizeStringCodec<S = String> {
+    _ty: PhantomData<fn(S) -> S>,
     size: u32,
 }
 
-impl FixedSizeStringCodec {
+impl<S: CodecString> FixedSizeStringCodec<S> {
     pub fn new(size: u32) -> Self {
-        Self { size }
+        Self {
+            _ty: PhantomData,
+            size,
+        }
     }
 }
 
-impl Codec for FixedSizeStringCodec {
-    type Target = String;
+impl<S: CodecString> Codec for FixedSizeStringCodec<S> {
+    type Target = S;
 
     fn fixed_size(&self) -> Option<usize> {
         Some(self.size as usize)
@@ -325,24 +548,25 @@ impl Codec for FixedSizeStringCodec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
         if buf.len() < self.size as usize {
-            anyhow::bail!(
-                "need {} byte(s) for string but got {}",
-                self.size,
-                buf.remaining()
-            );
+            return Err(DecodeError::unexpected_end(self.size as usize, buf));
         }
 
-        let buf = buf.split_to(self.size as usize);
+        let remaining = buf.remaining();
+        let mut buf = buf.split_to(self.size as usize);
         let len = buf.iter().position(|&v| v == 0).unwrap_or(buf.len());
+        buf.truncate(len);
 
-        *target = String::from_utf8(buf[..len].to_vec()).context("string was not valid UTF-8")?;
+        *target = decode_utf8(buf, remaining, limits)?;
 
         Ok(())
     }
 
     fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        let target = target.as_str();
+
         if target.as_bytes().contains(&0) {
             anyhow::bail!("interior NUL byte");
         }
@@ -356,21 +580,33 @@ impl Codec for FixedSizeStringCodec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        self.size as usize
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::FixedString { size: self.size }
+    }
 }
 
-#[derive(Clone)]
-pub struct NulTerminatedStringCodec {
+#[derive_where(Clone)]
+pub struct NulTerminatedStringCodec<S = String> {
+    _ty: PhantomData<fn(S) -> S>,
     max_len: u32,
 }
 
-impl NulTerminatedStringCodec {
+impl<S: CodecString> NulTerminatedStringCodec<S> {
     pub fn new(max_len: u32) -> Self {
-        Self { max_len }
+        Self {
+            _ty: PhantomData,
+            max_len,
+        }
     }
 }
 
-impl Codec for NulTerminatedStringCodec {
-    type Target = String;
+impl<S: CodecString> Codec for NulTerminatedStringCodec<S> {
+    type Target = S;
 
     fn fixed_size(&self) -> Option<usize> {
         None
@@ -381,27 +617,29 @@ impl Codec for NulTerminatedStringCodec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        let mut accum = Vec::new();
-
-        for _ in 0..self.max_len {
-            let ch = buf
-                .try_get_u8()
-                .with_context(|| format!("unterminated string, got {:?}", accum))?;
-
-            if ch == 0 {
-                break;
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let remaining = buf.remaining();
+        let window = buf.len().min(self.max_len as usize);
+
+        let accum = match buf[..window].iter().position(|&ch| ch == 0) {
+            Some(len) => {
+                let accum = buf.split_to(len);
+                buf.advance(1);
+                accum
             }
+            None if window == self.max_len as usize => buf.split_to(window),
+            None => return Err(DecodeError::unexpected_end(window + 1, buf)),
+        };
 
-            accum.push(ch);
-        }
-
-        *target = String::from_utf8(accum).context("string was not valid UTF-8")?;
+        *target = decode_utf8(accum, remaining, limits)?;
 
         Ok(())
     }
 
     fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        let target = target.as_str();
+
         if target.len() > self.max_len as usize {
             anyhow::bail!("string too long");
         }
@@ -416,21 +654,35 @@ impl Codec for NulTerminatedStringCodec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        target.as_str().len()
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::NulTerminatedString {
+            max_len: self.max_len,
+        }
+    }
 }
 
-#[derive(Clone)]
-pub struct VarStringCodec {
+#[derive_where(Clone)]
+pub struct VarStringCodec<S = String> {
+    _ty: PhantomData<fn(S) -> S>,
     max_len: u32,
 }
 
-impl VarStringCodec {
+impl<S: CodecString> VarStringCodec<S> {
     pub fn new(max_len: u32) -> Self {
-        Self { max_len }
+        Self {
+            _ty: PhantomData,
+            max_len,
+        }
     }
 }
 
-impl Codec for VarStringCodec {
-    type Target = String;
+impl<S: CodecString> Codec for VarStringCodec<S> {
+    type Target = S;
 
     fn fixed_size(&self) -> Option<usize> {
         None
@@ -441,24 +693,23 @@ impl Codec for VarStringCodec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        let len = buf.try_get_u32_varint().context("missing string length")?;
-
-        if len > self.max_len {
-            anyhow::bail!("string too long");
-        }
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let len = decode_len(buf, self.max_len)?;
 
         if buf.remaining() < len as usize {
-            anyhow::bail!("buffer not long enough for string");
+            return Err(DecodeError::unexpected_end(len as usize, buf));
         }
 
-        *target =
-            String::from_utf8(buf.split_to(len as usize).to_vec()).context("invalid UTF-8")?;
+        let remaining = buf.remaining();
+        *target = decode_utf8(buf.split_to(len as usize), remaining, limits)?;
 
         Ok(())
     }
 
     fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        let target = target.as_str();
+
         if target.len() > self.max_len as usize {
             anyhow::bail!("string too long");
         }
@@ -468,10 +719,72 @@ impl Codec for VarStringCodec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        let len = target.as_str().len();
+
+        u32_varint_len(len as u32) + len
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::VarString {
+            max_len: self.max_len,
+        }
+    }
 }
 
 impl Serde for String {
     fn build_codec() -> ErasedCodec<Self> {
-        VarStringCodec::new(4096000).erase()
+        Self::bounded_codec(DEFAULT_MAX_LEN)
+    }
+}
+
+impl BoundedSerde for String {
+    fn bounded_codec(max_len: u32) -> ErasedCodec<Self> {
+        VarStringCodec::<String>::new(max_len).erase()
+    }
+}
+
+impl StaticSerde for String {
+    type StaticCodec = VarStringCodec<String>;
+
+    fn static_codec() -> Self::StaticCodec {
+        Self::bounded_static_codec(DEFAULT_MAX_LEN)
+    }
+}
+
+impl BoundedStaticSerde for String {
+    type BoundedStaticCodec = VarStringCodec<String>;
+
+    fn bounded_static_codec(max_len: u32) -> Self::BoundedStaticCodec {
+        VarStringCodec::<String>::new(max_len)
+    }
+}
+
+impl Serde for ByteString {
+    fn build_codec() -> ErasedCodec<Self> {
+        Self::bounded_codec(DEFAULT_MAX_LEN)
+    }
+}
+
+impl BoundedSerde for ByteString {
+    fn bounded_codec(max_len: u32) -> ErasedCodec<Self> {
+        VarStringCodec::<ByteString>::new(max_len).erase()
+    }
+}
+
+impl StaticSerde for ByteString {
+    type StaticCodec = VarStringCodec<ByteString>;
+
+    fn static_codec() -> Self::StaticCodec {
+        Self::bounded_static_codec(DEFAULT_MAX_LEN)
+    }
+}
+
+impl BoundedStaticSerde for ByteString {
+    type BoundedStaticCodec = VarStringCodec<ByteString>;
+
+    fn bounded_static_codec(max_len: u32) -> Self::BoundedStaticCodec {
+        VarStringCodec::<ByteString>::new(max_len)
     }
 }
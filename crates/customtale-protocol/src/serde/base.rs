@@ -7,12 +7,18 @@ use std::{
     sync::{Arc, OnceLock, RwLock},
 };
 
-use anyhow::Context;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
+use bytes_varint::VarIntSupportMut;
 use derive_where::derive_where;
 use enum_ordinalize::Ordinalize;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
+use crate::serde::{
+    DecodeError, DecodeErrorKind, DecodeLimits, DecodeResult, EnumSchema, FieldLocation,
+    FieldSchema, Schema, SchemaRef, StructSchema, UnionSchema, VariantSchema, decode_u32_varint,
+    short_type_name, u32_varint_len,
+};
+
 // === Field === //
 
 #[derive_where(Copy, Clone, Hash, Eq, PartialEq)]
@@ -114,36 +120,74 @@ static CODEC_CACHE: RwLock<FxHashMap<TypeId, Box<dyn Any + Send + Sync>>> =
     RwLock::new(FxHashMap::with_hasher(FxBuildHasher));
 
 thread_local! {
-    static REENTRANT_CODEC_INIT: RefCell<FxHashSet<TypeId>>
+    static REENTRANT_CODEC_INIT: RefCell<FxHashSet<usize>>
         = const { RefCell::new(FxHashSet::with_hasher(FxBuildHasher)) };
 }
 
+pub struct CodecCell<T> {
+    inner: OnceLock<T>,
+}
+
+impl<T> Default for CodecCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CodecCell<T> {
+    pub const fn new() -> Self {
+        Self {
+            inner: OnceLock::new(),
+        }
+    }
+
+    pub fn get_or_init(&self, init: impl FnOnce() -> T) -> &T {
+        if let Some(value) = self.inner.get() {
+            return value;
+        }
+
+        let key = self as *const Self as usize;
+
+        if REENTRANT_CODEC_INIT.with_borrow(|v| v.contains(&key)) {
+            panic!("reentrant initialization of codec");
+        }
+
+        self.inner.get_or_init(|| {
+            REENTRANT_CODEC_INIT.with_borrow_mut(|v| v.insert(key));
+
+            let _guard = scopeguard::guard((), |()| {
+                REENTRANT_CODEC_INIT.with_borrow_mut(|v| v.remove(&key));
+            });
+
+            init()
+        })
+    }
+}
+
 pub trait Serde: CodecValue {
     const OPTION_IS_FIXED: bool = false;
 
+    /// Whether schemas containing this type refer to it by name through [`Schema::Named`] rather
+    /// than inlining its definition. Derived structs and unions set this.
+    const SCHEMA_BY_REF: bool = false;
+
     fn build_codec() -> ErasedCodec<Self>;
 
+    /// Returns the definition of this type's schema. Unlike `Self::codec().schema()`, this never
+    /// returns a [`Schema::Named`] reference to `Self`.
+    fn schema() -> Schema {
+        Self::build_codec().schema()
+    }
+
     fn codec() -> ErasedCodec<Self> {
         struct LateCodec<S: Serde> {
-            inner: OnceLock<ErasedCodec<S>>,
+            inner: CodecCell<ErasedCodec<S>>,
             init: fn() -> ErasedCodec<S>,
         }
 
         impl<S: Serde> LateCodec<S> {
             fn get(&self) -> &ErasedCodec<S> {
-                if REENTRANT_CODEC_INIT.with_borrow(|v| v.contains(&TypeId::of::<S>())) {
-                    panic!("reentrant initialization of codec");
-                }
-
-                self.inner.get_or_init(|| {
-                    REENTRANT_CODEC_INIT.with_borrow_mut(|v| v.insert(TypeId::of::<S>()));
-
-                    let _guard = scopeguard::guard((), |()| {
-                        REENTRANT_CODEC_INIT.with_borrow_mut(|v| v.remove(&TypeId::of::<S>()));
-                    });
-
-                    (self.init)()
-                })
+                self.inner.get_or_init(self.init)
             }
         }
 
@@ -167,13 +211,26 @@ pub trait Serde: CodecValue {
                 target: &mut Self::Target,
                 buf: &mut Bytes,
                 non_null_bit_set: bool,
-            ) -> anyhow::Result<()> {
-                self.get().decode(target, buf, non_null_bit_set)
+                limits: &mut DecodeLimits,
+            ) -> DecodeResult {
+                self.get().decode(target, buf, non_null_bit_set, limits)
             }
 
             fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
                 self.get().encode(target, buf)
             }
+
+            fn encoded_len(&self, target: &Self::Target) -> usize {
+                self.get().encoded_len(target)
+            }
+
+            fn schema(&self) -> Schema {
+                if S::SCHEMA_BY_REF {
+                    Schema::Named(SchemaRef::of::<S>())
+                } else {
+                    self.get().schema()
+                }
+            }
         }
 
         if let Some(codec) = CODEC_CACHE.read().unwrap().get(&TypeId::of::<Self>()) {
@@ -188,7 +245,7 @@ pub trait Serde: CodecValue {
                 .clone(),
             hash_map::Entry::Vacant(entry) => {
                 let codec = LateCodec {
-                    inner: OnceLock::new(),
+                    inner: CodecCell::new(),
                     init: Self::build_codec,
                 }
                 .erase();
@@ -203,15 +260,60 @@ pub trait Serde: CodecValue {
         Self::codec().encode(self, buf)
     }
 
-    fn decode(mut data: Bytes) -> anyhow::Result<Self> {
+    fn decode(data: Bytes) -> DecodeResult<Self> {
+        Self::decode_with_limits(data, &mut DecodeLimits::unlimited())
+    }
+
+    /// Like [`decode`](Self::decode) but charges the memory allocated while decoding against
+    /// `limits`.
+    fn decode_with_limits(mut data: Bytes, limits: &mut DecodeLimits) -> DecodeResult<Self> {
+        let input_len = data.len();
         let mut target = Self::default();
         Self::codec()
-            .decode(&mut target, &mut data, false)
-            .with_context(|| format!("failed to parse packet\npacket thus far: {target:#?}"))?;
+            .decode(&mut target, &mut data, false, limits)
+            .map_err(|err| err.resolve::<Self>(input_len))?;
+        Ok(target)
+    }
+}
+
+pub trait BoundedSerde: Serde {
+    fn bounded_codec(max_len: u32) -> ErasedCodec<Self>;
+}
+
+/// A `Serde` type whose codec can be named statically, letting hot paths encode and decode
+/// without going through `ErasedCodec` or the codec cache. The wire format is identical to the
+/// one produced by [`Serde::codec`].
+pub trait StaticSerde: Serde {
+    type StaticCodec: Codec<Target = Self>;
+
+    fn static_codec() -> Self::StaticCodec;
+
+    fn encode_static(&self, buf: &mut BytesMut) -> anyhow::Result<()> {
+        Self::static_codec().encode(self, buf)
+    }
+
+    fn decode_static(data: Bytes) -> DecodeResult<Self> {
+        Self::decode_static_with_limits(data, &mut DecodeLimits::unlimited())
+    }
+
+    /// Like [`decode_static`](Self::decode_static) but charges the memory allocated while decoding
+    /// against `limits`.
+    fn decode_static_with_limits(mut data: Bytes, limits: &mut DecodeLimits) -> DecodeResult<Self> {
+        let input_len = data.len();
+        let mut target = Self::default();
+        Self::static_codec()
+            .decode(&mut target, &mut data, false, limits)
+            .map_err(|err| err.resolve::<Self>(input_len))?;
         Ok(target)
     }
 }
 
+pub trait BoundedStaticSerde: StaticSerde + BoundedSerde {
+    type BoundedStaticCodec: Codec<Target = Self>;
+
+    fn bounded_static_codec(max_len: u32) -> Self::BoundedStaticCodec;
+}
+
 impl<T: Serde> Serde for Option<T> {
     fn build_codec() -> ErasedCodec<Self> {
         if T::OPTION_IS_FIXED {
@@ -222,6 +324,18 @@ impl<T: Serde> Serde for Option<T> {
     }
 }
 
+impl<T: StaticSerde> StaticSerde for Option<T> {
+    type StaticCodec = NullableCodec<T::StaticCodec>;
+
+    fn static_codec() -> Self::StaticCodec {
+        if T::OPTION_IS_FIXED {
+            NullableCodec::Fixed(FixedNullableCodec::new(T::static_codec()))
+        } else {
+            NullableCodec::Variable(VariableNullableCodec::new(T::static_codec()))
+        }
+    }
+}
+
 // === Codec === //
 
 pub trait CodecValue: 'static + Default + fmt::Debug + Clone {}
@@ -247,10 +361,22 @@ pub trait Codec: Any + Send + Sync {
         target: &mut Self::Target,
         buf: &mut Bytes,
         non_null_bit_set: bool,
-    ) -> anyhow::Result<()>;
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult;
 
     fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()>;
 
+    /// Returns the number of bytes [`encode`](Self::encode) writes for `target`, letting callers
+    /// size buffers and enforce limits before encoding. The result is unspecified for values which
+    /// fail to encode.
+    fn encoded_len(&self, target: &Self::Target) -> usize;
+
+    /// Describes the wire format of this codec. Codecs which don't override this are reported as
+    /// [`Schema::Opaque`].
+    fn schema(&self) -> Schema {
+        Schema::Opaque(short_type_name::<Self::Target>())
+    }
+
     fn field<I: CodecValue>(self, field: Field<I, Self::Target>) -> ErasedCodec<I>
     where
         Self: Sized,
@@ -273,21 +399,21 @@ pub trait Codec: Any + Send + Sync {
     where
         Self: Sized,
     {
-        self.map::<Box<Self::Target>>(|v| &**v, |v| &mut **v)
+        BoxedCodec::new(self).erase()
     }
 
     fn nullable_fixed(self) -> ErasedCodec<Option<Self::Target>>
     where
         Self: Sized,
     {
-        FixedNullableCodec::new(self.erase()).erase()
+        FixedNullableCodec::new(self).erase()
     }
 
     fn nullable_variable(self) -> ErasedCodec<Option<Self::Target>>
     where
         Self: Sized,
     {
-        VariableNullableCodec::new(self.erase()).erase()
+        VariableNullableCodec::new(self).erase()
     }
 
     fn erase(self) -> ErasedCodec<Self::Target>
@@ -347,14 +473,23 @@ impl<T: CodecValue> Codec for ErasedCodec<T> {
         target: &mut Self::Target,
         buf: &mut Bytes,
         non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        self.0.decode(target, buf, non_null_bit_set)
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        self.0.decode(target, buf, non_null_bit_set, limits)
     }
 
     fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
         self.0.encode(target, buf)
     }
 
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        self.0.encoded_len(target)
+    }
+
+    fn schema(&self) -> Schema {
+        self.0.schema()
+    }
+
     fn erase(self) -> ErasedCodec<Self::Target>
     where
         Self: Sized,
@@ -397,14 +532,23 @@ impl<I: CodecValue, O: CodecValue> Codec for FieldCodec<I, O> {
         target: &mut Self::Target,
         buf: &mut Bytes,
         non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
         self.inner
-            .decode(self.field.get_mut(target), buf, non_null_bit_set)
+            .decode(self.field.get_mut(target), buf, non_null_bit_set, limits)
     }
 
     fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
         self.inner.encode(self.field.get(target), buf)
     }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        self.inner.encoded_len(self.field.get(target))
+    }
+
+    fn schema(&self) -> Schema {
+        self.inner.schema()
+    }
 }
 
 // === MapCodec === //
@@ -465,25 +609,88 @@ where
         target: &mut Self::Target,
         buf: &mut Bytes,
         non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
         self.inner
-            .decode((self.xf_mut)(target), buf, non_null_bit_set)
+            .decode((self.xf_mut)(target), buf, non_null_bit_set, limits)
     }
 
     fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
         self.inner.encode((self.xf_ref)(target), buf)
     }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        self.inner.encoded_len((self.xf_ref)(target))
+    }
+
+    fn schema(&self) -> Schema {
+        self.inner.schema()
+    }
+}
+
+// === BoxedCodec === //
+
+#[derive(Clone)]
+pub struct BoxedCodec<C> {
+    inner: C,
+}
+
+impl<C: Codec> BoxedCodec<C> {
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+}
+
+impl<C: Codec> Codec for BoxedCodec<C> {
+    type Target = Box<C::Target>;
+
+    fn fixed_size(&self) -> Option<usize> {
+        self.inner.fixed_size()
+    }
+
+    fn wants_non_null_bit(&self) -> bool {
+        self.inner.wants_non_null_bit()
+    }
+
+    fn is_non_null_bit_set(&self, target: &Self::Target) -> bool {
+        self.inner.is_non_null_bit_set(target)
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        // The box itself was allocated when the target was defaulted, but that happens on behalf
+        // of this decode so it is charged here. Recursive types otherwise escape the budget.
+        limits.charge(size_of::<C::Target>(), buf)?;
+        self.inner.decode(target, buf, non_null_bit_set, limits)
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        self.inner.encode(target, buf)
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        self.inner.encoded_len(target)
+    }
+
+    fn schema(&self) -> Schema {
+        self.inner.schema()
+    }
 }
 
 // === FixedNullableCodec === //
 
-#[derive_where(Clone)]
-pub struct FixedNullableCodec<T: CodecValue> {
-    inner: ErasedCodec<T>,
+#[derive(Clone)]
+pub struct FixedNullableCodec<C> {
+    inner: C,
 }
 
-impl<T: CodecValue> FixedNullableCodec<T> {
-    pub fn new(inner: ErasedCodec<T>) -> Self {
+impl<C: Codec> FixedNullableCodec<C> {
+    pub fn new(inner: C) -> Self {
         assert!(inner.fixed_size().is_some());
         assert!(!inner.wants_non_null_bit());
 
@@ -491,8 +698,8 @@ impl<T: CodecValue> FixedNullableCodec<T> {
     }
 }
 
-impl<T: CodecValue> Codec for FixedNullableCodec<T> {
-    type Target = Option<T>;
+impl<C: Codec> Codec for FixedNullableCodec<C> {
+    type Target = Option<C::Target>;
 
     fn fixed_size(&self) -> Option<usize> {
         self.inner.fixed_size()
@@ -511,12 +718,23 @@ impl<T: CodecValue> Codec for FixedNullableCodec<T> {
         target: &mut Self::Target,
         buf: &mut Bytes,
         non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
         if non_null_bit_set {
-            self.inner
-                .decode(target.insert(T::default()), buf, non_null_bit_set)
+            self.inner.decode(
+                target.insert(Default::default()),
+                buf,
+                non_null_bit_set,
+                limits,
+            )
         } else {
-            buf.advance(self.fixed_size().unwrap());
+            let size = self.fixed_size().unwrap();
+
+            if buf.remaining() < size {
+                return Err(DecodeError::unexpected_end(size, buf));
+            }
+
+            buf.advance(size);
             Ok(())
         }
     }
@@ -529,23 +747,34 @@ impl<T: CodecValue> Codec for FixedNullableCodec<T> {
             Ok(())
         }
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        self.fixed_size().unwrap()
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Nullable {
+            inner: Box::new(self.inner.schema()),
+            fixed: true,
+        }
+    }
 }
 
 // === VariableNullableCodec === //
 
-#[derive_where(Clone)]
-pub struct VariableNullableCodec<T: CodecValue> {
-    inner: ErasedCodec<T>,
+#[derive(Clone)]
+pub struct VariableNullableCodec<C> {
+    inner: C,
 }
 
-impl<T: CodecValue> VariableNullableCodec<T> {
-    pub fn new(inner: ErasedCodec<T>) -> Self {
+impl<C: Codec> VariableNullableCodec<C> {
+    pub fn new(inner: C) -> Self {
         Self { inner }
     }
 }
 
-impl<T: CodecValue> Codec for VariableNullableCodec<T> {
-    type Target = Option<T>;
+impl<C: Codec> Codec for VariableNullableCodec<C> {
+    type Target = Option<C::Target>;
 
     fn fixed_size(&self) -> Option<usize> {
         None
@@ -564,10 +793,15 @@ impl<T: CodecValue> Codec for VariableNullableCodec<T> {
         target: &mut Self::Target,
         buf: &mut Bytes,
         non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
         if non_null_bit_set {
-            self.inner
-                .decode(target.insert(T::default()), buf, non_null_bit_set)
+            self.inner.decode(
+                target.insert(Default::default()),
+                buf,
+                non_null_bit_set,
+                limits,
+            )
         } else {
             Ok(())
         }
@@ -580,26 +814,123 @@ impl<T: CodecValue> Codec for VariableNullableCodec<T> {
             Ok(())
         }
     }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        target
+            .as_ref()
+            .map_or(0, |target| self.inner.encoded_len(target))
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Nullable {
+            inner: Box::new(self.inner.schema()),
+            fixed: false,
+        }
+    }
 }
 
-// === StructCodec === //
+// === NullableCodec === //
 
-#[derive_where(Clone)]
-pub struct StructCodec<T: CodecValue> {
+/// Picks between the fixed and variable nullable layouts at runtime. Used by
+/// `Option<T>`'s [`StaticSerde`] impl, where the choice depends on `T::OPTION_IS_FIXED`.
+#[derive(Clone)]
+pub enum NullableCodec<C> {
+    Fixed(FixedNullableCodec<C>),
+    Variable(VariableNullableCodec<C>),
+}
+
+impl<C: Codec> Codec for NullableCodec<C> {
+    type Target = Option<C::Target>;
+
+    fn fixed_size(&self) -> Option<usize> {
+        match self {
+            Self::Fixed(codec) => codec.fixed_size(),
+            Self::Variable(codec) => codec.fixed_size(),
+        }
+    }
+
+    fn wants_non_null_bit(&self) -> bool {
+        true
+    }
+
+    fn is_non_null_bit_set(&self, target: &Self::Target) -> bool {
+        target.is_some()
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        match self {
+            Self::Fixed(codec) => codec.decode(target, buf, non_null_bit_set, limits),
+            Self::Variable(codec) => codec.decode(target, buf, non_null_bit_set, limits),
+        }
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        match self {
+            Self::Fixed(codec) => codec.encode(target, buf),
+            Self::Variable(codec) => codec.encode(target, buf),
+        }
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        match self {
+            Self::Fixed(codec) => codec.encoded_len(target),
+            Self::Variable(codec) => codec.encoded_len(target),
+        }
+    }
+
+    fn schema(&self) -> Schema {
+        match self {
+            Self::Fixed(codec) => codec.schema(),
+            Self::Variable(codec) => codec.schema(),
+        }
+    }
+}
+
+// === StructLayout === //
+
+/// The wire layout of a struct, shared by the dynamic [`StructCodec`] and the statically
+/// dispatched codecs generated by `#[derive(Serde)]`. Fields are identified by their index in
+/// declaration order.
+#[derive(Debug, Clone)]
+pub struct StructLayout {
     fixed_total_size: Option<usize>,
     null_bytes: usize,
-    fixed_fields: Vec<StructField<T>>,
-    variable_fields: Vec<StructField<T>>,
+    fields: Vec<StructLayoutField>,
+    fixed_fields: Vec<usize>,
+    variable_fields: Vec<usize>,
 }
 
-#[derive_where(Clone)]
-struct StructField<T: CodecValue> {
+#[derive(Debug, Copy, Clone)]
+pub struct FieldShape {
+    pub name: &'static str,
+    pub fixed_size: Option<usize>,
+    pub wants_non_null_bit: bool,
+}
+
+impl FieldShape {
+    pub fn of(name: &'static str, codec: &impl Codec) -> Self {
+        Self {
+            name,
+            fixed_size: codec.fixed_size(),
+            wants_non_null_bit: codec.wants_non_null_bit(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StructLayoutField {
     name: &'static str,
+    fixed_size: Option<usize>,
     non_null_bit_idx: Option<usize>,
-    codec: ErasedCodec<T>,
 }
 
-impl<T: CodecValue> StructField<T> {
+impl StructLayoutField {
     fn is_non_null_bit_set(&self, non_null_bits: &[u8]) -> bool {
         self.non_null_bit_idx
             .map(|non_null_bit_idx| {
@@ -609,42 +940,46 @@ impl<T: CodecValue> StructField<T> {
     }
 }
 
-impl<T: CodecValue> StructCodec<T> {
-    pub fn new(fields: impl IntoIterator<Item = NamedCodec<T>>) -> Self {
+impl StructLayout {
+    pub fn new(fields: impl IntoIterator<Item = FieldShape>) -> Self {
         let mut fixed_total_size = Some(0);
         let mut non_null_bits = 0;
+        let mut shapes = Vec::new();
         let mut fixed_fields = Vec::new();
         let mut variable_fields = Vec::new();
 
         // Sort fields into fixed and variable.
-        for NamedCodec { name, codec: field } in fields {
-            if let Some(size) = field.fixed_size() {
-                fixed_fields.push(StructField {
-                    name,
-                    non_null_bit_idx: None,
-                    codec: field,
-                });
+        for (idx, shape) in fields.into_iter().enumerate() {
+            if let Some(size) = shape.fixed_size {
+                fixed_fields.push(idx);
 
                 if let Some(fixed_total_size) = &mut fixed_total_size {
                     *fixed_total_size += size;
                 }
             } else {
-                variable_fields.push(StructField {
-                    name,
-                    non_null_bit_idx: None,
-                    codec: field,
-                });
+                variable_fields.push(idx);
 
                 fixed_total_size = None;
             }
+
+            shapes.push(shape);
         }
 
+        let mut fields = shapes
+            .iter()
+            .map(|shape| StructLayoutField {
+                name: shape.name,
+                fixed_size: shape.fixed_size,
+                non_null_bit_idx: None,
+            })
+            .collect::<Vec<_>>();
+
         // Assign null bit indices. Fixed elements are assigned earlier bits than variable onces.
-        for field in fixed_fields.iter_mut().chain(&mut variable_fields) {
-            field.non_null_bit_idx = if field.codec.wants_non_null_bit() {
-                let idx = non_null_bits;
+        for &idx in fixed_fields.iter().chain(&variable_fields) {
+            fields[idx].non_null_bit_idx = if shapes[idx].wants_non_null_bit {
+                let bit = non_null_bits;
                 non_null_bits += 1;
-                Some(idx)
+                Some(bit)
             } else {
                 None
             };
@@ -657,70 +992,96 @@ impl<T: CodecValue> StructCodec<T> {
         Self {
             fixed_total_size,
             null_bytes: non_null_bits.div_ceil(8),
+            fields,
             fixed_fields,
             variable_fields,
         }
     }
-}
-
-impl<T: CodecValue> Codec for StructCodec<T> {
-    type Target = T;
 
-    fn fixed_size(&self) -> Option<usize> {
+    pub fn fixed_size(&self) -> Option<usize> {
         self.fixed_total_size
     }
 
-    fn decode(
+    /// Describes the layout given the schemas of each field in declaration order.
+    pub fn schema(
+        &self,
+        name: &'static str,
+        field_schemas: impl IntoIterator<Item = Schema>,
+    ) -> StructSchema {
+        let mut locations = vec![FieldLocation::Trailing; self.fields.len()];
+        let mut offset = self.null_bytes;
+
+        for &idx in &self.fixed_fields {
+            let size = self.fields[idx].fixed_size.unwrap();
+            locations[idx] = FieldLocation::Fixed { offset, size };
+            offset += size;
+        }
+
+        if self.variable_fields.len() > 1 {
+            for (slot, &idx) in self.variable_fields.iter().enumerate() {
+                locations[idx] = FieldLocation::Variable { slot };
+            }
+        }
+
+        StructSchema {
+            name,
+            null_bytes: self.null_bytes,
+            fixed_block_size: offset,
+            fixed_size: self.fixed_total_size,
+            fields: self
+                .fields
+                .iter()
+                .zip(locations)
+                .zip(field_schemas)
+                .map(|((field, location), schema)| FieldSchema {
+                    name: field.name,
+                    schema,
+                    location,
+                    non_null_bit: field.non_null_bit_idx,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn decode_fields(
         &self,
-        target: &mut Self::Target,
         buf: &mut Bytes,
-        _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
+        mut decode_field: impl FnMut(usize, &mut Bytes, bool) -> DecodeResult,
+    ) -> DecodeResult {
         if buf.remaining() < self.null_bytes {
-            anyhow::bail!("not enough null bytes for `{}`", type_name::<T>());
+            return Err(DecodeError::unexpected_end(self.null_bytes, buf));
         }
 
         let null_bits = buf.split_to(self.null_bytes);
 
-        for fixed in &self.fixed_fields {
-            fixed
-                .codec
-                .decode(target, buf, fixed.is_non_null_bit_set(&null_bits))
-                .with_context(|| {
-                    format!(
-                        "failed to decode fixed field `{}.{}`",
-                        type_name::<T>(),
-                        fixed.name,
-                    )
-                })?;
-        }
-
-        if let [unique] = &self.variable_fields[..] {
-            unique
-                .codec
-                .decode(target, buf, unique.is_non_null_bit_set(&null_bits))
-                .with_context(|| {
-                    format!(
-                        "failed to decode unique variable field `{}.{}`",
-                        type_name::<T>(),
-                        unique.name,
-                    )
-                })?
+        for &idx in &self.fixed_fields {
+            let fixed = &self.fields[idx];
+
+            decode_field(idx, buf, fixed.is_non_null_bit_set(&null_bits))
+                .map_err(|err| err.in_field(fixed.name))?;
+        }
+
+        if let &[idx] = &self.variable_fields[..] {
+            let unique = &self.fields[idx];
+
+            decode_field(idx, buf, unique.is_non_null_bit_set(&null_bits))
+                .map_err(|err| err.in_field(unique.name))?
         } else {
             let mut max_variable_end = 0;
 
             let mut fixed_buf = buf.clone();
 
             if buf.remaining() < self.variable_fields.len() * 4 {
-                anyhow::bail!(
-                    "variable offset section for `{}` not long enough",
-                    type_name::<T>()
-                );
+                return Err(DecodeError::unexpected_end(
+                    self.variable_fields.len() * 4,
+                    buf,
+                ));
             }
 
             buf.advance(self.variable_fields.len() * 4);
 
-            for field in &self.variable_fields {
+            for &idx in &self.variable_fields {
+                let field = &self.fields[idx];
                 let offset = fixed_buf.get_u32_le();
 
                 if !field.is_non_null_bit_set(&null_bits) {
@@ -731,27 +1092,20 @@ impl<T: CodecValue> Codec for StructCodec<T> {
                 let mut buf = buf.clone();
 
                 if buf.remaining() < offset as usize {
-                    anyhow::bail!(
-                        "variable data section for `{}.{}` not long enough (section has size {} but offset was {})",
-                        type_name::<T>(),
-                        field.name,
-                        buf.remaining(),
-                        offset as i32,
-                    );
+                    return Err(DecodeError::new(
+                        DecodeErrorKind::BadOffset {
+                            offset: offset as i32,
+                            available: buf.remaining(),
+                        },
+                        &buf,
+                    )
+                    .in_field(field.name));
                 }
 
                 buf.advance(offset as usize);
 
-                field
-                    .codec
-                    .decode(target, &mut buf, field.is_non_null_bit_set(&null_bits))
-                    .with_context(|| {
-                        format!(
-                            "failed to decode variable field `{}.{}`",
-                            type_name::<T>(),
-                            field.name,
-                        )
-                    })?;
+                decode_field(idx, &mut buf, field.is_non_null_bit_set(&null_bits))
+                    .map_err(|err| err.in_field(field.name))?;
 
                 max_variable_end = max_variable_end.max(buf_start - buf.remaining());
             }
@@ -762,35 +1116,40 @@ impl<T: CodecValue> Codec for StructCodec<T> {
         Ok(())
     }
 
-    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+    pub fn encode_fields(
+        &self,
+        buf: &mut BytesMut,
+        is_non_null_bit_set: impl Fn(usize) -> bool,
+        mut encode_field: impl FnMut(usize, &mut BytesMut) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
         let null_bytes_len = buf.len();
         buf.put_bytes(0, self.null_bytes);
 
-        for field in self.fixed_fields.iter().chain(&self.variable_fields) {
-            let Some(non_null_bit_idx) = field.non_null_bit_idx else {
+        for &idx in self.fixed_fields.iter().chain(&self.variable_fields) {
+            let Some(non_null_bit_idx) = self.fields[idx].non_null_bit_idx else {
                 continue;
             };
 
-            if field.codec.is_non_null_bit_set(target) {
+            if is_non_null_bit_set(idx) {
                 buf[null_bytes_len..][non_null_bit_idx / 8] |= 1 << (non_null_bit_idx % 8);
             }
         }
 
-        for field in &self.fixed_fields {
-            field.codec.encode(target, buf)?;
+        for &idx in &self.fixed_fields {
+            encode_field(idx, buf)?;
         }
 
-        if let [unique] = &self.variable_fields[..] {
-            unique.codec.encode(target, buf)?;
+        if let &[idx] = &self.variable_fields[..] {
+            encode_field(idx, buf)?;
         } else {
             let offsets_start_len = buf.len();
             buf.put_bytes(0, 4 * self.variable_fields.len());
 
             let variable_start_len = buf.len();
 
-            for (idx, field) in self.variable_fields.iter().enumerate() {
-                if !field.codec.is_non_null_bit_set(target) {
-                    buf[offsets_start_len..][(idx * 4)..][..4]
+            for (slot, &idx) in self.variable_fields.iter().enumerate() {
+                if !is_non_null_bit_set(idx) {
+                    buf[offsets_start_len..][(slot * 4)..][..4]
                         .copy_from_slice(&(-1i32).to_le_bytes());
 
                     continue;
@@ -798,15 +1157,109 @@ impl<T: CodecValue> Codec for StructCodec<T> {
 
                 let own_offset = (buf.len() - variable_start_len) as u32;
 
-                buf[offsets_start_len..][(idx * 4)..][..4]
+                buf[offsets_start_len..][(slot * 4)..][..4]
                     .copy_from_slice(&own_offset.to_le_bytes());
 
-                field.codec.encode(target, buf)?;
+                encode_field(idx, buf)?;
             }
         }
 
         Ok(())
     }
+
+    /// Computes the number of bytes [`encode_fields`](Self::encode_fields) would write given the
+    /// encoded length of each field.
+    pub fn encoded_len(
+        &self,
+        is_non_null_bit_set: impl Fn(usize) -> bool,
+        mut field_len: impl FnMut(usize) -> usize,
+    ) -> usize {
+        let mut len = self.null_bytes;
+
+        for &idx in &self.fixed_fields {
+            len += field_len(idx);
+        }
+
+        if let &[idx] = &self.variable_fields[..] {
+            len += field_len(idx);
+        } else {
+            len += 4 * self.variable_fields.len();
+
+            for &idx in &self.variable_fields {
+                if is_non_null_bit_set(idx) {
+                    len += field_len(idx);
+                }
+            }
+        }
+
+        len
+    }
+}
+
+// === StructCodec === //
+
+#[derive_where(Clone)]
+pub struct StructCodec<T: CodecValue> {
+    layout: StructLayout,
+    fields: Vec<ErasedCodec<T>>,
+}
+
+impl<T: CodecValue> StructCodec<T> {
+    pub fn new(fields: impl IntoIterator<Item = NamedCodec<T>>) -> Self {
+        let fields = fields.into_iter().collect::<Vec<_>>();
+
+        Self {
+            layout: StructLayout::new(
+                fields
+                    .iter()
+                    .map(|NamedCodec { name, codec }| FieldShape::of(name, codec)),
+            ),
+            fields: fields.into_iter().map(|field| field.codec).collect(),
+        }
+    }
+}
+
+impl<T: CodecValue> Codec for StructCodec<T> {
+    type Target = T;
+
+    fn fixed_size(&self) -> Option<usize> {
+        self.layout.fixed_size()
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        self.layout
+            .decode_fields(buf, |idx, buf, non_null_bit_set| {
+                self.fields[idx].decode(target, buf, non_null_bit_set, limits)
+            })
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        self.layout.encode_fields(
+            buf,
+            |idx| self.fields[idx].is_non_null_bit_set(target),
+            |idx, buf| self.fields[idx].encode(target, buf),
+        )
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        self.layout.encoded_len(
+            |idx| self.fields[idx].is_non_null_bit_set(target),
+            |idx| self.fields[idx].encoded_len(target),
+        )
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Struct(self.layout.schema(
+            short_type_name::<T>(),
+            self.fields.iter().map(|field| field.schema()),
+        ))
+    }
 }
 
 // === EnumCodec === //
@@ -846,13 +1299,19 @@ impl<T: SimpleEnum> Codec for EnumCodec<T> {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        let id = buf
-            .try_get_u8()
-            .with_context(|| format!("missing variant data for `{}`", type_name::<T>()))?;
-        *target = *T::VARIANTS
-            .get(id as usize)
-            .with_context(|| format!("no such variant {id} for `{}`", type_name::<T>()))?;
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let remaining = buf.remaining();
+        let id = buf.try_get_u8()?;
+        *target = *T::VARIANTS.get(id as usize).ok_or_else(|| {
+            DecodeError::at(
+                DecodeErrorKind::BadEnumOrdinal {
+                    ty: type_name::<T>(),
+                    ordinal: id,
+                },
+                remaining,
+            )
+        })?;
 
         Ok(())
     }
@@ -862,4 +1321,220 @@ impl<T: SimpleEnum> Codec for EnumCodec<T> {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        1
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Enum(EnumSchema {
+            name: short_type_name::<T>(),
+            variants: T::VARIANTS
+                .iter()
+                .map(|variant| format!("{variant:?}"))
+                .collect(),
+        })
+    }
+}
+
+// === UnionLayout === //
+
+/// The wire layout of a tagged union, shared by the dynamic [`UnionCodec`] and the statically
+/// dispatched codecs generated by `#[derive(Serde)]`.
+#[derive(Debug, Clone)]
+pub struct UnionLayout {
+    names: Vec<&'static str>,
+}
+
+impl UnionLayout {
+    pub fn new(names: impl IntoIterator<Item = &'static str>) -> Self {
+        Self {
+            names: names.into_iter().collect(),
+        }
+    }
+
+    pub fn decode_variant<T>(
+        &self,
+        buf: &mut Bytes,
+        decode_variant: impl FnOnce(usize, &mut Bytes) -> DecodeResult,
+    ) -> DecodeResult {
+        self.decode_variant_of(type_name::<T>(), buf, decode_variant)
+    }
+
+    /// Like [`decode_variant`](Self::decode_variant) but reports unknown tags against the union
+    /// named `ty`.
+    pub fn decode_variant_of(
+        &self,
+        ty: &'static str,
+        buf: &mut Bytes,
+        decode_variant: impl FnOnce(usize, &mut Bytes) -> DecodeResult,
+    ) -> DecodeResult {
+        let remaining = buf.remaining();
+        let type_id = decode_u32_varint(buf)?;
+
+        let Some(name) = self.names.get(type_id as usize) else {
+            return Err(DecodeError::at(
+                DecodeErrorKind::BadUnionTag { ty, tag: type_id },
+                remaining,
+            ));
+        };
+
+        decode_variant(type_id as usize, buf).map_err(|err| err.in_variant(name))
+    }
+
+    pub fn encode_variant(
+        &self,
+        type_id: usize,
+        buf: &mut BytesMut,
+        encode_variant: impl FnOnce(&mut BytesMut) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        buf.put_u32_varint(type_id as u32);
+        encode_variant(buf)
+    }
+
+    /// Computes the number of bytes [`encode_variant`](Self::encode_variant) would write given the
+    /// encoded length of the variant's value.
+    pub fn encoded_len(&self, type_id: usize, variant_len: usize) -> usize {
+        u32_varint_len(type_id as u32) + variant_len
+    }
+
+    /// Describes the layout given the schemas of each variant in tag order.
+    pub fn schema(
+        &self,
+        name: &'static str,
+        variant_schemas: impl IntoIterator<Item = Schema>,
+    ) -> UnionSchema {
+        UnionSchema {
+            name,
+            variants: self
+                .names
+                .iter()
+                .zip(variant_schemas)
+                .map(|(&name, schema)| VariantSchema { name, schema })
+                .collect(),
+        }
+    }
+}
+
+// === UnionCodec === //
+
+#[derive_where(Clone)]
+pub struct UnionCodec<T: CodecValue> {
+    ordinal: fn(&T) -> usize,
+    layout: UnionLayout,
+    variants: Vec<ErasedCodec<T>>,
+}
+
+impl<T: CodecValue> UnionCodec<T> {
+    pub fn new(
+        ordinal: fn(&T) -> usize,
+        variants: impl IntoIterator<Item = NamedCodec<T>>,
+    ) -> Self {
+        let variants = variants.into_iter().collect::<Vec<_>>();
+
+        Self {
+            ordinal,
+            layout: UnionLayout::new(variants.iter().map(|variant| variant.name)),
+            variants: variants.into_iter().map(|variant| variant.codec).collect(),
+        }
+    }
+}
+
+impl<T: CodecValue> Codec for UnionCodec<T> {
+    type Target = T;
+
+    fn fixed_size(&self) -> Option<usize> {
+        None
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        self.layout.decode_variant::<T>(buf, |type_id, buf| {
+            self.variants[type_id].decode(target, buf, true, limits)
+        })
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        let type_id = (self.ordinal)(target);
+
+        self.layout.encode_variant(type_id, buf, |buf| {
+            self.variants[type_id].encode(target, buf)
+        })
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        let type_id = (self.ordinal)(target);
+
+        self.layout
+            .encoded_len(type_id, self.variants[type_id].encoded_len(target))
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Union(self.layout.schema(
+            short_type_name::<T>(),
+            self.variants.iter().map(|variant| variant.schema()),
+        ))
+    }
+}
+
+// === VariantCodec === //
+
+pub struct VariantCodec<T: CodecValue, V: CodecValue> {
+    inner: ErasedCodec<V>,
+    project: fn(&T) -> Option<&V>,
+    wrap: fn(V) -> T,
+}
+
+impl<T: CodecValue, V: CodecValue> VariantCodec<T, V> {
+    pub fn new(inner: ErasedCodec<V>, project: fn(&T) -> Option<&V>, wrap: fn(V) -> T) -> Self {
+        Self {
+            inner,
+            project,
+            wrap,
+        }
+    }
+}
+
+impl<T: CodecValue, V: CodecValue> Codec for VariantCodec<T, V> {
+    type Target = T;
+
+    fn fixed_size(&self) -> Option<usize> {
+        None
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let mut value = V::default();
+        self.inner
+            .decode(&mut value, buf, non_null_bit_set, limits)?;
+        *target = (self.wrap)(value);
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        let Some(value) = (self.project)(target) else {
+            anyhow::bail!("`{}` is not the expected union variant", type_name::<T>());
+        };
+
+        self.inner.encode(value, buf)
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        (self.project)(target).map_or(0, |value| self.inner.encoded_len(value))
+    }
+
+    fn schema(&self) -> Schema {
+        self.inner.schema()
+    }
 }
@@ -0,0 +1,738 @@
+use std::{any::TypeId, sync::RwLock};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use half::f16;
+use rustc_hash::{FxBuildHasher, FxHashMap};
+use uuid::Uuid;
+
+use crate::serde::{
+    ByteBoolCodec, ByteCodec, ByteI8Codec, ByteString, Codec, CodecCell, CodecValue, DecodeError,
+    DecodeErrorKind, DecodeLimits, DecodeResult, Dictionary, EnumSchema, ErasedCodec,
+    ExactByteArrayCodec, FieldLocation, FieldShape, FixedSizeStringCodec, LeF16Codec, LeF32Codec,
+    LeF64Codec, LeI16Codec, LeI32Codec, LeI64Codec, LeU16Codec, LeU32Codec, LeU64Codec,
+    NulTerminatedStringCodec, Schema, SchemaRef, StructLayout, StructSchema, UnionLayout,
+    UnionSchema, UuidCodec, VarArrayCodec, VarByteArrayCodec, VarDictionaryCodec, VarIntCodec,
+    VarLongCodec, VarStringCodec,
+};
+
+// === DynamicValue === //
+
+/// A self-describing value tree, decoded according to a [`Schema`] rather than a concrete Rust
+/// type. Obtain one through [`ErasedCodec::to_dynamic`] or [`Schema::dynamic_codec`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum DynamicValue {
+    /// An absent nullable value.
+    #[default]
+    Null,
+    Bool(bool),
+    U8(u8),
+    I8(i8),
+    U16(u16),
+    I16(i16),
+    /// Both fixed-size `u32`s and VarInts.
+    U32(u32),
+    I32(i32),
+    /// Both fixed-size `u64`s and VarLongs.
+    U64(u64),
+    I64(i64),
+    F16(f16),
+    F32(f32),
+    F64(f64),
+    Uuid(Uuid),
+    /// A simple enum variant, identified by name.
+    Enum(String),
+    Bytes(Bytes),
+    String(ByteString),
+    Array(Vec<DynamicValue>),
+    Dictionary(Dictionary<DynamicValue, DynamicValue>),
+    /// Struct fields in declaration order.
+    Struct(Vec<(&'static str, DynamicValue)>),
+    /// A union variant, identified by name.
+    Variant(&'static str, Box<DynamicValue>),
+}
+
+impl DynamicValue {
+    pub fn is_null(&self) -> bool {
+        matches!(self, DynamicValue::Null)
+    }
+
+    pub fn field(&self, name: &str) -> Option<&DynamicValue> {
+        let DynamicValue::Struct(fields) = self else {
+            return None;
+        };
+
+        fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut DynamicValue> {
+        let DynamicValue::Struct(fields) = self else {
+            return None;
+        };
+
+        fields
+            .iter_mut()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+// === Adapters === //
+
+impl<T: CodecValue> ErasedCodec<T> {
+    /// Converts `value` into a [`DynamicValue`] by encoding it and decoding the result according
+    /// to this codec's schema.
+    pub fn to_dynamic(&self, value: &T) -> anyhow::Result<DynamicValue> {
+        let mut buf = BytesMut::new();
+        self.encode(value, &mut buf)?;
+
+        let mut buf = buf.freeze();
+        let input_len = buf.len();
+        let mut target = DynamicValue::Null;
+
+        self.schema()
+            .dynamic_codec()
+            .decode(
+                &mut target,
+                &mut buf,
+                self.is_non_null_bit_set(value),
+                &mut DecodeLimits::unlimited(),
+            )
+            .map_err(|err| err.resolve::<T>(input_len))?;
+
+        Ok(target)
+    }
+
+    /// Converts a [`DynamicValue`] back into a `T` by encoding it according to this codec's schema
+    /// and decoding the result.
+    pub fn from_dynamic(&self, value: &DynamicValue) -> anyhow::Result<T> {
+        let dynamic = self.schema().dynamic_codec();
+
+        let mut buf = BytesMut::new();
+        dynamic.encode(value, &mut buf)?;
+
+        let mut buf = buf.freeze();
+        let input_len = buf.len();
+        let mut target = T::default();
+
+        self.decode(
+            &mut target,
+            &mut buf,
+            dynamic.is_non_null_bit_set(value),
+            &mut DecodeLimits::unlimited(),
+        )
+        .map_err(|err| err.resolve::<T>(input_len))?;
+
+        Ok(target)
+    }
+}
+
+impl Schema {
+    /// Builds a codec which decodes values of this schema into [`DynamicValue`]s and encodes them
+    /// back into the identical wire format.
+    pub fn dynamic_codec(&self) -> ErasedCodec<DynamicValue> {
+        match self {
+            Schema::Bool => LeafCodec(ByteBoolCodec).erase(),
+            Schema::U8 => LeafCodec(ByteCodec).erase(),
+            Schema::I8 => LeafCodec(ByteI8Codec).erase(),
+            Schema::U16 => LeafCodec(LeU16Codec).erase(),
+            Schema::I16 => LeafCodec(LeI16Codec).erase(),
+            Schema::U32 => LeafCodec(LeU32Codec).erase(),
+            Schema::I32 => LeafCodec(LeI32Codec).erase(),
+            Schema::U64 => LeafCodec(LeU64Codec).erase(),
+            Schema::I64 => LeafCodec(LeI64Codec).erase(),
+            Schema::F16 => LeafCodec(LeF16Codec).erase(),
+            Schema::F32 => LeafCodec(LeF32Codec).erase(),
+            Schema::F64 => LeafCodec(LeF64Codec).erase(),
+            Schema::VarInt => LeafCodec(VarIntCodec).erase(),
+            Schema::VarLong => LeafCodec(VarLongCodec).erase(),
+            Schema::Uuid => LeafCodec(UuidCodec).erase(),
+            Schema::Enum(schema) => DynamicEnumCodec {
+                schema: schema.clone(),
+            }
+            .erase(),
+            Schema::Struct(schema) => DynamicStructCodec::new(schema).erase(),
+            Schema::Union(schema) => DynamicUnionCodec::new(schema).erase(),
+            Schema::Named(schema_ref) => DynamicRefCodec {
+                schema_ref: *schema_ref,
+                inner: CodecCell::new(),
+            }
+            .erase(),
+            Schema::Nullable { inner, fixed } => DynamicNullableCodec {
+                inner: inner.dynamic_codec(),
+                fixed: *fixed,
+            }
+            .erase(),
+            Schema::Array { element, max_len } => {
+                LeafCodec(VarArrayCodec::new(element.dynamic_codec(), *max_len)).erase()
+            }
+            Schema::Dictionary {
+                key,
+                value,
+                max_len,
+            } => LeafCodec(VarDictionaryCodec::new(
+                key.dynamic_codec(),
+                value.dynamic_codec(),
+                *max_len,
+            ))
+            .erase(),
+            Schema::ExactByteArray { size } => LeafCodec(ExactByteArrayCodec::new(*size)).erase(),
+            Schema::VarByteArray { max_len } => LeafCodec(VarByteArrayCodec::new(*max_len)).erase(),
+            Schema::FixedString { size } => {
+                LeafCodec(FixedSizeStringCodec::<ByteString>::new(*size)).erase()
+            }
+            Schema::NulTerminatedString { max_len } => {
+                LeafCodec(NulTerminatedStringCodec::<ByteString>::new(*max_len)).erase()
+            }
+            Schema::VarString { max_len } => {
+                LeafCodec(VarStringCodec::<ByteString>::new(*max_len)).erase()
+            }
+            Schema::Opaque(name) => OpaqueCodec { name }.erase(),
+        }
+    }
+}
+
+// === LeafCodec === //
+
+trait DynamicLeaf: CodecValue {
+    fn wrap(self) -> DynamicValue;
+
+    fn unwrap(value: &DynamicValue) -> Option<&Self>;
+}
+
+macro_rules! impl_dynamic_leaf {
+    ($($ty:ty => $variant:ident),*$(,)?) => {$(
+        impl DynamicLeaf for $ty {
+            fn wrap(self) -> DynamicValue {
+                DynamicValue::$variant(self)
+            }
+
+            fn unwrap(value: &DynamicValue) -> Option<&Self> {
+                match value {
+                    DynamicValue::$variant(value) => Some(value),
+                    _ => None,
+                }
+            }
+        }
+    )*};
+}
+
+impl_dynamic_leaf! {
+    bool => Bool,
+    u8 => U8,
+    i8 => I8,
+    u16 => U16,
+    i16 => I16,
+    u32 => U32,
+    i32 => I32,
+    u64 => U64,
+    i64 => I64,
+    f16 => F16,
+    f32 => F32,
+    f64 => F64,
+    Uuid => Uuid,
+    Bytes => Bytes,
+    ByteString => String,
+    Vec<DynamicValue> => Array,
+    Dictionary<DynamicValue, DynamicValue> => Dictionary,
+}
+
+/// Adapts one of the typed codecs to [`DynamicValue`]s.
+struct LeafCodec<C>(C);
+
+impl<C: Codec> Codec for LeafCodec<C>
+where
+    C::Target: DynamicLeaf,
+{
+    type Target = DynamicValue;
+
+    fn fixed_size(&self) -> Option<usize> {
+        self.0.fixed_size()
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let mut value = C::Target::default();
+        self.0.decode(&mut value, buf, non_null_bit_set, limits)?;
+        *target = value.wrap();
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        let Some(value) = C::Target::unwrap(target) else {
+            anyhow::bail!("expected a `{}` value but got {target:?}", self.0.schema());
+        };
+
+        self.0.encode(value, buf)
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        C::Target::unwrap(target).map_or(0, |value| self.0.encoded_len(value))
+    }
+
+    fn schema(&self) -> Schema {
+        self.0.schema()
+    }
+}
+
+// === DynamicEnumCodec === //
+
+struct DynamicEnumCodec {
+    schema: EnumSchema,
+}
+
+impl Codec for DynamicEnumCodec {
+    type Target = DynamicValue;
+
+    fn fixed_size(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let remaining = buf.remaining();
+        let ordinal = buf.try_get_u8()?;
+
+        let Some(name) = self.schema.variants.get(ordinal as usize) else {
+            return Err(DecodeError::at(
+                DecodeErrorKind::BadEnumOrdinal {
+                    ty: self.schema.name,
+                    ordinal,
+                },
+                remaining,
+            ));
+        };
+
+        *target = DynamicValue::Enum(name.clone());
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        let DynamicValue::Enum(name) = target else {
+            anyhow::bail!(
+                "expected a `{}` variant but got {target:?}",
+                self.schema.name
+            );
+        };
+
+        let Some(ordinal) = self.schema.variants.iter().position(|v| v == name) else {
+            anyhow::bail!("`{}` has no variant named `{name}`", self.schema.name);
+        };
+
+        buf.put_u8(ordinal as u8);
+
+        Ok(())
+    }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        1
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Enum(self.schema.clone())
+    }
+}
+
+// === DynamicStructCodec === //
+
+struct DynamicStructCodec {
+    schema: StructSchema,
+    layout: StructLayout,
+    fields: Vec<ErasedCodec<DynamicValue>>,
+}
+
+impl DynamicStructCodec {
+    fn new(schema: &StructSchema) -> Self {
+        // The layout is rebuilt from the schema rather than from the field codecs so that building
+        // a codec never has to resolve the definitions of named types.
+        let layout = StructLayout::new(schema.fields.iter().map(|field| FieldShape {
+            name: field.name,
+            fixed_size: match field.location {
+                FieldLocation::Fixed { size, .. } => Some(size),
+                _ => None,
+            },
+            wants_non_null_bit: field.non_null_bit.is_some(),
+        }));
+
+        Self {
+            schema: schema.clone(),
+            layout,
+            fields: schema
+                .fields
+                .iter()
+                .map(|field| field.schema.dynamic_codec())
+                .collect(),
+        }
+    }
+}
+
+impl Codec for DynamicStructCodec {
+    type Target = DynamicValue;
+
+    fn fixed_size(&self) -> Option<usize> {
+        self.layout.fixed_size()
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let mut values = self
+            .schema
+            .fields
+            .iter()
+            .map(|field| (field.name, DynamicValue::Null))
+            .collect::<Vec<_>>();
+
+        self.layout
+            .decode_fields(buf, |idx, buf, non_null_bit_set| {
+                self.fields[idx].decode(&mut values[idx].1, buf, non_null_bit_set, limits)
+            })?;
+
+        *target = DynamicValue::Struct(values);
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        if !matches!(target, DynamicValue::Struct(_)) {
+            anyhow::bail!(
+                "expected a `{}` struct but got {target:?}",
+                self.schema.name
+            );
+        }
+
+        let values = self
+            .schema
+            .fields
+            .iter()
+            .map(|field| {
+                target.field(field.name).ok_or_else(|| {
+                    anyhow::anyhow!("`{}` is missing field `{}`", self.schema.name, field.name)
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        self.layout.encode_fields(
+            buf,
+            |idx| self.fields[idx].is_non_null_bit_set(values[idx]),
+            |idx, buf| self.fields[idx].encode(values[idx], buf),
+        )
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        let values = self
+            .schema
+            .fields
+            .iter()
+            .map(|field| target.field(field.name).unwrap_or(&DynamicValue::Null))
+            .collect::<Vec<_>>();
+
+        self.layout.encoded_len(
+            |idx| self.fields[idx].is_non_null_bit_set(values[idx]),
+            |idx| self.fields[idx].encoded_len(values[idx]),
+        )
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Struct(self.schema.clone())
+    }
+}
+
+// === DynamicUnionCodec === //
+
+struct DynamicUnionCodec {
+    schema: UnionSchema,
+    layout: UnionLayout,
+    variants: Vec<ErasedCodec<DynamicValue>>,
+}
+
+impl DynamicUnionCodec {
+    fn new(schema: &UnionSchema) -> Self {
+        Self {
+            schema: schema.clone(),
+            layout: UnionLayout::new(schema.variants.iter().map(|variant| variant.name)),
+            variants: schema
+                .variants
+                .iter()
+                .map(|variant| variant.schema.dynamic_codec())
+                .collect(),
+        }
+    }
+}
+
+impl Codec for DynamicUnionCodec {
+    type Target = DynamicValue;
+
+    fn fixed_size(&self) -> Option<usize> {
+        None
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        self.layout
+            .decode_variant_of(self.schema.name, buf, |type_id, buf| {
+                let mut value = DynamicValue::Null;
+                self.variants[type_id].decode(&mut value, buf, true, limits)?;
+
+                *target =
+                    DynamicValue::Variant(self.schema.variants[type_id].name, Box::new(value));
+
+                Ok(())
+            })
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        let DynamicValue::Variant(name, value) = target else {
+            anyhow::bail!(
+                "expected a `{}` variant but got {target:?}",
+                self.schema.name
+            );
+        };
+
+        let Some(type_id) = self
+            .schema
+            .variants
+            .iter()
+            .position(|variant| variant.name == *name)
+        else {
+            anyhow::bail!("`{}` has no variant named `{name}`", self.schema.name);
+        };
+
+        self.layout.encode_variant(type_id, buf, |buf| {
+            self.variants[type_id].encode(value, buf)
+        })
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        let DynamicValue::Variant(name, value) = target else {
+            return 0;
+        };
+
+        let Some(type_id) = self
+            .schema
+            .variants
+            .iter()
+            .position(|variant| variant.name == *name)
+        else {
+            return 0;
+        };
+
+        self.layout
+            .encoded_len(type_id, self.variants[type_id].encoded_len(value))
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Union(self.schema.clone())
+    }
+}
+
+// === DynamicRefCodec === //
+
+static DYNAMIC_CODEC_CACHE: RwLock<FxHashMap<TypeId, ErasedCodec<DynamicValue>>> =
+    RwLock::new(FxHashMap::with_hasher(FxBuildHasher));
+
+/// Resolves a [`Schema::Named`] reference on first use, letting recursive types have finite
+/// dynamic codecs.
+struct DynamicRefCodec {
+    schema_ref: SchemaRef,
+    inner: CodecCell<ErasedCodec<DynamicValue>>,
+}
+
+impl DynamicRefCodec {
+    fn get(&self) -> &ErasedCodec<DynamicValue> {
+        self.inner.get_or_init(|| {
+            let type_id = self.schema_ref.type_id();
+
+            if let Some(codec) = DYNAMIC_CODEC_CACHE.read().unwrap().get(&type_id) {
+                return codec.clone();
+            }
+
+            let codec = self.schema_ref.definition().dynamic_codec();
+
+            DYNAMIC_CODEC_CACHE
+                .write()
+                .unwrap()
+                .entry(type_id)
+                .or_insert(codec)
+                .clone()
+        })
+    }
+}
+
+impl Codec for DynamicRefCodec {
+    type Target = DynamicValue;
+
+    fn fixed_size(&self) -> Option<usize> {
+        self.get().fixed_size()
+    }
+
+    fn wants_non_null_bit(&self) -> bool {
+        self.get().wants_non_null_bit()
+    }
+
+    fn is_non_null_bit_set(&self, target: &Self::Target) -> bool {
+        self.get().is_non_null_bit_set(target)
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        self.get().decode(target, buf, non_null_bit_set, limits)
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        self.get().encode(target, buf)
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        self.get().encoded_len(target)
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Named(self.schema_ref)
+    }
+}
+
+// === DynamicNullableCodec === //
+
+struct DynamicNullableCodec {
+    inner: ErasedCodec<DynamicValue>,
+    fixed: bool,
+}
+
+impl Codec for DynamicNullableCodec {
+    type Target = DynamicValue;
+
+    fn fixed_size(&self) -> Option<usize> {
+        if self.fixed {
+            self.inner.fixed_size()
+        } else {
+            None
+        }
+    }
+
+    fn wants_non_null_bit(&self) -> bool {
+        true
+    }
+
+    fn is_non_null_bit_set(&self, target: &Self::Target) -> bool {
+        !target.is_null()
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        non_null_bit_set: bool,
+        limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        if non_null_bit_set {
+            return self.inner.decode(target, buf, non_null_bit_set, limits);
+        }
+
+        *target = DynamicValue::Null;
+
+        if let Some(size) = self.fixed_size() {
+            if buf.remaining() < size {
+                return Err(DecodeError::unexpected_end(size, buf));
+            }
+
+            buf.advance(size);
+        }
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        if !target.is_null() {
+            self.inner.encode(target, buf)
+        } else {
+            if let Some(size) = self.fixed_size() {
+                buf.put_bytes(0, size);
+            }
+
+            Ok(())
+        }
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        if !target.is_null() {
+            self.inner.encoded_len(target)
+        } else {
+            self.fixed_size().unwrap_or(0)
+        }
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Nullable {
+            inner: Box::new(self.inner.schema()),
+            fixed: self.fixed,
+        }
+    }
+}
+
+// === OpaqueCodec === //
+
+struct OpaqueCodec {
+    name: &'static str,
+}
+
+impl Codec for OpaqueCodec {
+    type Target = DynamicValue;
+
+    fn fixed_size(&self) -> Option<usize> {
+        None
+    }
+
+    fn decode(
+        &self,
+        _target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        Err(DecodeError::custom(
+            format_args!("cannot dynamically decode opaque type `{}`", self.name),
+            buf,
+        ))
+    }
+
+    fn encode(&self, _target: &Self::Target, _buf: &mut BytesMut) -> anyhow::Result<()> {
+        anyhow::bail!("cannot dynamically encode opaque type `{}`", self.name);
+    }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        0
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Opaque(self.name)
+    }
+}
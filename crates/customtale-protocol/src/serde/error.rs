@@ -0,0 +1,270 @@
+use std::{any::type_name, error::Error, fmt, str};
+
+use bytes::{Buf, TryGetError};
+use bytes_varint::{VarIntError, VarIntSupport};
+use thiserror::Error;
+
+// === DecodeError === //
+
+pub type DecodeResult<T = ()> = Result<T, DecodeError>;
+
+/// A decode failure, tagged with the path to the value which failed to decode and the position in
+/// the input at which it starts.
+///
+/// Codecs construct errors relative to the end of the input buffer since that is all they can see.
+/// The absolute byte offset becomes available once the top-level decode call has resolved the
+/// error against the input length.
+#[derive(Debug, Clone)]
+pub struct DecodeError {
+    kind: DecodeErrorKind,
+    path: Vec<PathSegment>,
+    from_end: usize,
+    root: Option<&'static str>,
+    offset: Option<usize>,
+}
+
+#[derive(Debug, Clone, Error)]
+#[non_exhaustive]
+pub enum DecodeErrorKind {
+    #[error("expected {expected} byte(s) but only {actual} remained")]
+    UnexpectedEnd { expected: usize, actual: usize },
+    #[error("malformed VarInt")]
+    BadVarInt,
+    #[error("unknown boolean variant {0}")]
+    BadBool(u8),
+    #[error("no such variant {ordinal} for `{ty}`")]
+    BadEnumOrdinal { ty: &'static str, ordinal: u8 },
+    #[error("unknown union variant {tag} for `{ty}`")]
+    BadUnionTag { ty: &'static str, tag: u32 },
+    #[error("string was not valid UTF-8")]
+    InvalidUtf8(#[source] str::Utf8Error),
+    #[error("length {len} exceeds maximum length of {max_len}")]
+    TooLong { len: u64, max_len: u32 },
+    #[error(
+        "variable field offset {offset} is out of bounds for a data section of {available} byte(s)"
+    )]
+    BadOffset { offset: i32, available: usize },
+    #[error("unknown packet ID {0}")]
+    UnknownPacket(u32),
+    #[error(
+        "allocating {requested} byte(s) would exceed the remaining decode budget of {remaining} byte(s)"
+    )]
+    OverBudget { requested: usize, remaining: usize },
+    #[error("{0}")]
+    Custom(String),
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum PathSegment {
+    Field(&'static str),
+    Variant(&'static str),
+    Index(usize),
+    Key(usize),
+    Value(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Field(name) => write!(f, ".{name}"),
+            PathSegment::Variant(name) => write!(f, "::{name}"),
+            PathSegment::Index(idx) => write!(f, "[{idx}]"),
+            PathSegment::Key(idx) => write!(f, "[{idx}].key"),
+            PathSegment::Value(idx) => write!(f, "[{idx}].value"),
+        }
+    }
+}
+
+impl DecodeError {
+    /// Creates an error for a value starting `remaining` bytes before the end of the input.
+    pub fn at(kind: DecodeErrorKind, remaining: usize) -> Self {
+        Self {
+            kind,
+            path: Vec::new(),
+            from_end: remaining,
+            root: None,
+            offset: None,
+        }
+    }
+
+    /// Creates an error for a value starting at the current position of `buf`.
+    pub fn new(kind: DecodeErrorKind, buf: &impl Buf) -> Self {
+        Self::at(kind, buf.remaining())
+    }
+
+    pub fn unexpected_end(expected: usize, buf: &impl Buf) -> Self {
+        Self::new(
+            DecodeErrorKind::UnexpectedEnd {
+                expected,
+                actual: buf.remaining(),
+            },
+            buf,
+        )
+    }
+
+    pub fn custom(message: impl fmt::Display, buf: &impl Buf) -> Self {
+        Self::new(DecodeErrorKind::Custom(message.to_string()), buf)
+    }
+
+    pub fn kind(&self) -> &DecodeErrorKind {
+        &self.kind
+    }
+
+    pub fn into_kind(self) -> DecodeErrorKind {
+        self.kind
+    }
+
+    /// The path from the root value to the value which failed to decode.
+    pub fn path(&self) -> impl DoubleEndedIterator<Item = PathSegment> + '_ {
+        self.path.iter().rev().copied()
+    }
+
+    /// The name of the type whose decoding failed, once resolved.
+    pub fn root(&self) -> Option<&'static str> {
+        self.root
+    }
+
+    /// The byte offset into the input at which the failing value starts, once resolved.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    pub fn in_field(self, name: &'static str) -> Self {
+        self.in_segment(PathSegment::Field(name))
+    }
+
+    pub fn in_variant(self, name: &'static str) -> Self {
+        self.in_segment(PathSegment::Variant(name))
+    }
+
+    pub fn in_index(self, idx: usize) -> Self {
+        self.in_segment(PathSegment::Index(idx))
+    }
+
+    pub fn in_key(self, idx: usize) -> Self {
+        self.in_segment(PathSegment::Key(idx))
+    }
+
+    pub fn in_value(self, idx: usize) -> Self {
+        self.in_segment(PathSegment::Value(idx))
+    }
+
+    pub fn in_segment(mut self, segment: PathSegment) -> Self {
+        self.path.push(segment);
+        self
+    }
+
+    /// Resolves the error's offset against the length of the input it was decoded from and records
+    /// `T` as the root of its path. Errors which have already been resolved are left untouched.
+    pub fn resolve<T: ?Sized>(mut self, input_len: usize) -> Self {
+        if self.offset.is_none() {
+            self.root = Some(short_type_name::<T>());
+            self.offset = Some(input_len.saturating_sub(self.from_end));
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)?;
+
+        if self.root.is_some() || !self.path.is_empty() {
+            f.write_str(" at `")?;
+
+            if let Some(root) = self.root {
+                f.write_str(root)?;
+            }
+
+            for segment in self.path() {
+                fmt::Display::fmt(&segment, f)?;
+            }
+
+            f.write_str("`")?;
+        }
+
+        if let Some(offset) = self.offset {
+            write!(f, " (byte offset {offset})")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for DecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.kind.source()
+    }
+}
+
+impl From<TryGetError> for DecodeError {
+    fn from(err: TryGetError) -> Self {
+        Self::at(
+            DecodeErrorKind::UnexpectedEnd {
+                expected: err.requested,
+                actual: err.available,
+            },
+            err.available,
+        )
+    }
+}
+
+pub(crate) fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = type_name::<T>();
+
+    if name.contains('<') {
+        name
+    } else {
+        name.rsplit("::").next().unwrap_or(name)
+    }
+}
+
+// === Helpers === //
+
+pub fn decode_u32_varint(buf: &mut impl Buf) -> DecodeResult<u32> {
+    let remaining = buf.remaining();
+
+    buf.try_get_u32_varint().map_err(|err| match err {
+        VarIntError::NumericOverflow => DecodeError::at(DecodeErrorKind::BadVarInt, remaining),
+        VarIntError::BufferUnderflow => DecodeError::at(
+            DecodeErrorKind::UnexpectedEnd {
+                expected: remaining + 1,
+                actual: remaining,
+            },
+            remaining,
+        ),
+    })
+}
+
+pub fn decode_u64_varint(buf: &mut impl Buf) -> DecodeResult<u64> {
+    let remaining = buf.remaining();
+
+    buf.try_get_u64_varint().map_err(|err| match err {
+        VarIntError::NumericOverflow => DecodeError::at(DecodeErrorKind::BadVarInt, remaining),
+        VarIntError::BufferUnderflow => DecodeError::at(
+            DecodeErrorKind::UnexpectedEnd {
+                expected: remaining + 1,
+                actual: remaining,
+            },
+            remaining,
+        ),
+    })
+}
+
+pub fn decode_len(buf: &mut impl Buf, max_len: u32) -> DecodeResult<u32> {
+    let remaining = buf.remaining();
+    let len = decode_u32_varint(buf)?;
+
+    if len > max_len {
+        return Err(DecodeError::at(
+            DecodeErrorKind::TooLong {
+                len: len as u64,
+                max_len,
+            },
+            remaining,
+        ));
+    }
+
+    Ok(len)
+}
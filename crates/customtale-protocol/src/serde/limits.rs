@@ -0,0 +1,199 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use bytes::Buf;
+
+use crate::serde::{DecodeError, DecodeErrorKind, DecodeResult};
+
+// === DecodeLimits === //
+
+/// The budget used by [`DecodeLimits::default`].
+pub const DEFAULT_MAX_ALLOC: usize = 64 * 1024 * 1024;
+
+/// A budget on the memory which decoding may allocate, so that a small input declaring huge
+/// collections cannot make the decoder reserve far more memory than it took to send.
+///
+/// Codecs charge the budget before growing collections, boxing values, or copying data out of the
+/// input buffer. Data sliced out of the input buffer is free since it shares its allocation.
+#[derive(Debug)]
+pub struct DecodeLimits {
+    max_alloc: usize,
+    allocated: usize,
+    shared: Option<SharedBudget>,
+    /// The part of `allocated` drawn from `shared`, which is given back on reset.
+    drawn: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ALLOC)
+    }
+}
+
+impl Clone for DecodeLimits {
+    fn clone(&self) -> Self {
+        Self {
+            max_alloc: self.max_alloc,
+            allocated: self.allocated,
+            shared: self.shared.clone(),
+            // Only the original gives back what it drew.
+            drawn: 0,
+        }
+    }
+}
+
+impl Drop for DecodeLimits {
+    fn drop(&mut self) {
+        self.reset();
+    }
+}
+
+impl DecodeLimits {
+    pub const fn new(max_alloc: usize) -> Self {
+        Self {
+            max_alloc,
+            allocated: 0,
+            shared: None,
+            drawn: 0,
+        }
+    }
+
+    pub const fn unlimited() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    /// Additionally charges everything to `budget`, which other limits may share.
+    pub fn sharing(mut self, budget: SharedBudget) -> Self {
+        self.reset();
+        self.shared = Some(budget);
+        self
+    }
+
+    pub fn max_alloc(&self) -> usize {
+        self.max_alloc
+    }
+
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+
+    pub fn remaining(&self) -> usize {
+        let remaining = self.max_alloc - self.allocated;
+
+        match &self.shared {
+            Some(shared) => remaining.min(shared.remaining()),
+            None => remaining,
+        }
+    }
+
+    /// Restores the full budget, typically once the previously decoded value has been handed off.
+    pub fn reset(&mut self) {
+        if let Some(shared) = &self.shared {
+            shared.give_back(self.drawn);
+        }
+
+        self.allocated = 0;
+        self.drawn = 0;
+    }
+
+    /// Charges `bytes` against the budget, leaving it untouched if that would exceed it.
+    pub fn try_charge(&mut self, bytes: usize) -> Result<(), DecodeErrorKind> {
+        let over_budget = |remaining| DecodeErrorKind::OverBudget {
+            requested: bytes,
+            remaining,
+        };
+
+        if bytes > self.max_alloc - self.allocated {
+            return Err(over_budget(self.remaining()));
+        }
+
+        if let Some(shared) = &self.shared {
+            shared.try_draw(bytes).map_err(over_budget)?;
+            self.drawn += bytes;
+        }
+
+        self.allocated += bytes;
+        Ok(())
+    }
+
+    /// Like [`try_charge`](Self::try_charge) but reports failures against the value starting at
+    /// the current position of `buf`.
+    pub fn charge(&mut self, bytes: usize, buf: &impl Buf) -> DecodeResult {
+        self.try_charge(bytes)
+            .map_err(|kind| DecodeError::new(kind, buf))
+    }
+
+    /// Ensures `vec` has room for one more element, growing it geometrically up to a total of
+    /// `len` elements and charging the budget for the capacity it adds.
+    pub fn reserve_one<T>(&mut self, vec: &mut Vec<T>, len: usize, buf: &impl Buf) -> DecodeResult {
+        if vec.len() < vec.capacity() {
+            return Ok(());
+        }
+
+        let additional = vec
+            .capacity()
+            .max(4)
+            .min(len.saturating_sub(vec.len()))
+            .max(1);
+
+        self.charge(additional.saturating_mul(size_of::<T>()), buf)?;
+        vec.reserve_exact(additional);
+
+        Ok(())
+    }
+}
+
+// === SharedBudget === //
+
+/// A budget drawn on by several [`DecodeLimits`], such as those of every stream of a connection,
+/// so that spreading packets across them cannot multiply the memory a peer may tie up.
+#[derive(Debug, Clone)]
+pub struct SharedBudget {
+    max_alloc: usize,
+    allocated: Arc<AtomicUsize>,
+}
+
+impl Default for SharedBudget {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ALLOC)
+    }
+}
+
+impl SharedBudget {
+    pub fn new(max_alloc: usize) -> Self {
+        Self {
+            max_alloc,
+            allocated: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn max_alloc(&self) -> usize {
+        self.max_alloc
+    }
+
+    pub fn allocated(&self) -> usize {
+        self.allocated.load(Ordering::Relaxed)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.max_alloc - self.allocated()
+    }
+
+    /// Takes `bytes` from the budget, or reports how much remains if there is not enough.
+    fn try_draw(&self, bytes: usize) -> Result<(), usize> {
+        self.allocated
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |allocated| {
+                allocated
+                    .checked_add(bytes)
+                    .filter(|&allocated| allocated <= self.max_alloc)
+            })
+            .map(|_| ())
+            .map_err(|allocated| self.max_alloc - allocated)
+    }
+
+    fn give_back(&self, bytes: usize) {
+        self.allocated.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
@@ -4,8 +4,43 @@ pub use self::array::*;
 mod base;
 pub use self::base::*;
 
-mod macros;
-pub use macros::*;
+mod dynamic;
+pub use self::dynamic::*;
+
+mod error;
+pub use self::error::*;
+
+mod limits;
+pub use self::limits::*;
 
 mod primitives;
 pub use self::primitives::*;
+
+mod schema;
+pub use self::schema::*;
+
+mod string;
+pub use self::string::*;
+
+pub use customtale_protocol_derive::Serde;
+
+#[doc(hidden)]
+pub mod codec_internals {
+    pub use {
+        anyhow::Result,
+        bytes::{Bytes, BytesMut},
+        crate::serde::{
+            BoundedSerde, BoundedStaticSerde, Codec, CodecCell, DecodeLimits, DecodeResult,
+            EnumCodec, ErasedCodec, FieldShape, FixedNullableCodec, FixedSizeStringCodec, Schema,
+            SchemaRef, Serde, StaticSerde, StructCodec, StructLayout, UnionCodec, UnionLayout,
+            VariableNullableCodec, VariantCodec,
+        },
+        std::{
+            default::Default,
+            option::Option,
+            primitive::{bool, usize},
+            result::Result::Ok,
+            unreachable,
+        },
+    };
+}
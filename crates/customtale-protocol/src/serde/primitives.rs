@@ -1,9 +1,12 @@
-use anyhow::Context;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
-use bytes_varint::{VarIntSupport, VarIntSupportMut};
+use bytes_varint::VarIntSupportMut;
+use half::f16;
 use uuid::Uuid;
 
-use crate::serde::{Codec, ErasedCodec, Serde};
+use crate::serde::{
+    BoxedCodec, Codec, DecodeError, DecodeErrorKind, DecodeLimits, DecodeResult, ErasedCodec,
+    Schema, Serde, StaticSerde, decode_u32_varint, decode_u64_varint,
+};
 
 #[derive(Clone)]
 pub struct ByteBoolCodec;
@@ -20,14 +23,14 @@ impl Codec for ByteBoolCodec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        match buf
-            .try_get_u8()
-            .context("failed to read byte for boolean")?
-        {
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        let remaining = buf.remaining();
+
+        match buf.try_get_u8()? {
             0 => *target = false,
             1 => *target = true,
-            v => anyhow::bail!("unknown boolean variant {v}"),
+            v => return Err(DecodeError::at(DecodeErrorKind::BadBool(v), remaining)),
         }
 
         Ok(())
@@ -38,6 +41,14 @@ impl Codec for ByteBoolCodec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        1
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Bool
+    }
 }
 
 impl Serde for bool {
@@ -48,6 +59,14 @@ impl Serde for bool {
     }
 }
 
+impl StaticSerde for bool {
+    type StaticCodec = ByteBoolCodec;
+
+    fn static_codec() -> Self::StaticCodec {
+        ByteBoolCodec
+    }
+}
+
 #[derive(Clone)]
 pub struct ByteCodec;
 
@@ -63,8 +82,9 @@ impl Codec for ByteCodec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        *target = buf.try_get_u8().context("failed to read byte")?;
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_u8()?;
 
         Ok(())
     }
@@ -74,6 +94,14 @@ impl Codec for ByteCodec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        1
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::U8
+    }
 }
 
 impl Serde for u8 {
@@ -84,6 +112,67 @@ impl Serde for u8 {
     }
 }
 
+impl StaticSerde for u8 {
+    type StaticCodec = ByteCodec;
+
+    fn static_codec() -> Self::StaticCodec {
+        ByteCodec
+    }
+}
+
+#[derive(Clone)]
+pub struct ByteI8Codec;
+
+impl Codec for ByteI8Codec {
+    type Target = i8;
+
+    fn fixed_size(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_i8()?;
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        buf.put_i8(*target);
+
+        Ok(())
+    }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        1
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::I8
+    }
+}
+
+impl Serde for i8 {
+    const OPTION_IS_FIXED: bool = true;
+
+    fn build_codec() -> ErasedCodec<Self> {
+        ByteI8Codec.erase()
+    }
+}
+
+impl StaticSerde for i8 {
+    type StaticCodec = ByteI8Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        ByteI8Codec
+    }
+}
+
 #[derive(Clone)]
 pub struct LeU64Codec;
 
@@ -99,8 +188,9 @@ impl Codec for LeU64Codec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        *target = buf.try_get_u64_le().context("failed to read LeU64")?;
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_u64_le()?;
 
         Ok(())
     }
@@ -110,6 +200,14 @@ impl Codec for LeU64Codec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        8
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::U64
+    }
 }
 
 impl Serde for u64 {
@@ -120,6 +218,67 @@ impl Serde for u64 {
     }
 }
 
+impl StaticSerde for u64 {
+    type StaticCodec = LeU64Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        LeU64Codec
+    }
+}
+
+#[derive(Clone)]
+pub struct LeI64Codec;
+
+impl Codec for LeI64Codec {
+    type Target = i64;
+
+    fn fixed_size(&self) -> Option<usize> {
+        Some(8)
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_i64_le()?;
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        buf.put_i64_le(*target);
+
+        Ok(())
+    }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        8
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::I64
+    }
+}
+
+impl Serde for i64 {
+    const OPTION_IS_FIXED: bool = true;
+
+    fn build_codec() -> ErasedCodec<Self> {
+        LeI64Codec.erase()
+    }
+}
+
+impl StaticSerde for i64 {
+    type StaticCodec = LeI64Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        LeI64Codec
+    }
+}
+
 #[derive(Clone)]
 pub struct LeU32Codec;
 
@@ -135,8 +294,9 @@ impl Codec for LeU32Codec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        *target = buf.try_get_u32_le().context("failed to read LeU32")?;
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_u32_le()?;
 
         Ok(())
     }
@@ -146,6 +306,14 @@ impl Codec for LeU32Codec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        4
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::U32
+    }
 }
 
 impl Serde for u32 {
@@ -156,6 +324,14 @@ impl Serde for u32 {
     }
 }
 
+impl StaticSerde for u32 {
+    type StaticCodec = LeU32Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        LeU32Codec
+    }
+}
+
 #[derive(Clone)]
 pub struct LeI32Codec;
 
@@ -171,8 +347,9 @@ impl Codec for LeI32Codec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        *target = buf.try_get_i32_le().context("failed to read LeU32")?;
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_i32_le()?;
 
         Ok(())
     }
@@ -182,6 +359,14 @@ impl Codec for LeI32Codec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        4
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::I32
+    }
 }
 
 impl Serde for i32 {
@@ -192,6 +377,14 @@ impl Serde for i32 {
     }
 }
 
+impl StaticSerde for i32 {
+    type StaticCodec = LeI32Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        LeI32Codec
+    }
+}
+
 #[derive(Clone)]
 pub struct LeU16Codec;
 
@@ -207,8 +400,9 @@ impl Codec for LeU16Codec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        *target = buf.try_get_u16_le().context("failed to read LeU16")?;
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_u16_le()?;
 
         Ok(())
     }
@@ -218,6 +412,14 @@ impl Codec for LeU16Codec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        2
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::U16
+    }
 }
 
 impl Serde for u16 {
@@ -228,6 +430,67 @@ impl Serde for u16 {
     }
 }
 
+impl StaticSerde for u16 {
+    type StaticCodec = LeU16Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        LeU16Codec
+    }
+}
+
+#[derive(Clone)]
+pub struct LeI16Codec;
+
+impl Codec for LeI16Codec {
+    type Target = i16;
+
+    fn fixed_size(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_i16_le()?;
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        buf.put_i16_le(*target);
+
+        Ok(())
+    }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        2
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::I16
+    }
+}
+
+impl Serde for i16 {
+    const OPTION_IS_FIXED: bool = true;
+
+    fn build_codec() -> ErasedCodec<Self> {
+        LeI16Codec.erase()
+    }
+}
+
+impl StaticSerde for i16 {
+    type StaticCodec = LeI16Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        LeI16Codec
+    }
+}
+
 #[derive(Clone)]
 pub struct LeF64Codec;
 
@@ -243,8 +506,9 @@ impl Codec for LeF64Codec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        *target = buf.try_get_f64_le().context("failed to read LeF32")?;
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_f64_le()?;
 
         Ok(())
     }
@@ -253,6 +517,14 @@ impl Codec for LeF64Codec {
         buf.put_f64_le(*target);
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        8
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::F64
+    }
 }
 
 impl Serde for f64 {
@@ -263,6 +535,14 @@ impl Serde for f64 {
     }
 }
 
+impl StaticSerde for f64 {
+    type StaticCodec = LeF64Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        LeF64Codec
+    }
+}
+
 #[derive(Clone)]
 pub struct LeF32Codec;
 
@@ -278,8 +558,9 @@ impl Codec for LeF32Codec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        *target = buf.try_get_f32_le().context("failed to read LeF32")?;
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = buf.try_get_f32_le()?;
 
         Ok(())
     }
@@ -288,6 +569,14 @@ impl Codec for LeF32Codec {
         buf.put_f32_le(*target);
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        4
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::F32
+    }
 }
 
 impl Serde for f32 {
@@ -298,6 +587,67 @@ impl Serde for f32 {
     }
 }
 
+impl StaticSerde for f32 {
+    type StaticCodec = LeF32Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        LeF32Codec
+    }
+}
+
+#[derive(Clone)]
+pub struct LeF16Codec;
+
+impl Codec for LeF16Codec {
+    type Target = f16;
+
+    fn fixed_size(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = f16::from_bits(buf.try_get_u16_le()?);
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        buf.put_u16_le(target.to_bits());
+
+        Ok(())
+    }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        2
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::F16
+    }
+}
+
+impl Serde for f16 {
+    const OPTION_IS_FIXED: bool = true;
+
+    fn build_codec() -> ErasedCodec<Self> {
+        LeF16Codec.erase()
+    }
+}
+
+impl StaticSerde for f16 {
+    type StaticCodec = LeF16Codec;
+
+    fn static_codec() -> Self::StaticCodec {
+        LeF16Codec
+    }
+}
+
 #[derive(Clone)]
 pub struct VarIntCodec;
 
@@ -313,8 +663,9 @@ impl Codec for VarIntCodec {
         target: &mut Self::Target,
         buf: &mut Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
-        *target = buf.try_get_u32_varint().context("failed to read VarInt")?;
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = decode_u32_varint(buf)?;
 
         Ok(())
     }
@@ -324,6 +675,61 @@ impl Codec for VarIntCodec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        u32_varint_len(*target)
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::VarInt
+    }
+}
+
+#[derive(Clone)]
+pub struct VarLongCodec;
+
+impl Codec for VarLongCodec {
+    type Target = u64;
+
+    fn fixed_size(&self) -> Option<usize> {
+        None
+    }
+
+    fn decode(
+        &self,
+        target: &mut Self::Target,
+        buf: &mut Bytes,
+        _non_null_bit_set: bool,
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
+        *target = decode_u64_varint(buf)?;
+
+        Ok(())
+    }
+
+    fn encode(&self, target: &Self::Target, buf: &mut BytesMut) -> anyhow::Result<()> {
+        buf.put_u64_varint(*target);
+
+        Ok(())
+    }
+
+    fn encoded_len(&self, target: &Self::Target) -> usize {
+        u64_varint_len(*target)
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::VarLong
+    }
+}
+
+/// Returns the number of bytes [`VarIntCodec`] uses to encode `value`.
+pub fn u32_varint_len(value: u32) -> usize {
+    (u32::BITS - (value | 1).leading_zeros()).div_ceil(7) as usize
+}
+
+/// Returns the number of bytes [`VarLongCodec`] uses to encode `value`.
+pub fn u64_varint_len(value: u64) -> usize {
+    (u64::BITS - (value | 1).leading_zeros()).div_ceil(7) as usize
 }
 
 // === Box === //
@@ -336,9 +742,18 @@ impl<T: Serde> Serde for Box<T> {
     }
 }
 
+impl<T: StaticSerde> StaticSerde for Box<T> {
+    type StaticCodec = BoxedCodec<T::StaticCodec>;
+
+    fn static_codec() -> Self::StaticCodec {
+        BoxedCodec::new(T::static_codec())
+    }
+}
+
 // === UUID === //
 
-struct UuidCodec;
+#[derive(Clone)]
+pub struct UuidCodec;
 
 impl Codec for UuidCodec {
     type Target = Uuid;
@@ -352,7 +767,8 @@ impl Codec for UuidCodec {
         target: &mut Self::Target,
         buf: &mut bytes::Bytes,
         _non_null_bit_set: bool,
-    ) -> anyhow::Result<()> {
+        _limits: &mut DecodeLimits,
+    ) -> DecodeResult {
         let high_bits = buf.try_get_u64_le()?;
         let low_bits = buf.try_get_u64_le()?;
 
@@ -369,6 +785,14 @@ impl Codec for UuidCodec {
 
         Ok(())
     }
+
+    fn encoded_len(&self, _target: &Self::Target) -> usize {
+        16
+    }
+
+    fn schema(&self) -> Schema {
+        Schema::Uuid
+    }
 }
 
 impl Serde for Uuid {
@@ -378,3 +802,11 @@ impl Serde for Uuid {
         UuidCodec.erase()
     }
 }
+
+impl StaticSerde for Uuid {
+    type StaticCodec = UuidCodec;
+
+    fn static_codec() -> Self::StaticCodec {
+        UuidCodec
+    }
+}
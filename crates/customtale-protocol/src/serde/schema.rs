@@ -0,0 +1,242 @@
+use std::{any::TypeId, fmt, hash};
+
+use crate::serde::{Serde, short_type_name};
+
+// === Schema === //
+
+/// A description of the wire format produced by a [`Codec`](crate::serde::Codec), obtained through
+/// [`Codec::schema`](crate::serde::Codec::schema).
+///
+/// Types which opt into [`Serde::SCHEMA_BY_REF`]—structs and unions deriving `Serde`—appear as
+/// [`Schema::Named`] references when nested inside another schema so that recursive types have
+/// finite schemas. Use [`SchemaRef::definition`] or [`Serde::schema`] to expand them.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Schema {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F16,
+    F32,
+    F64,
+    VarInt,
+    VarLong,
+    Uuid,
+    Enum(EnumSchema),
+    Struct(StructSchema),
+    Union(UnionSchema),
+    Named(SchemaRef),
+    Nullable {
+        inner: Box<Schema>,
+        fixed: bool,
+    },
+    Array {
+        element: Box<Schema>,
+        max_len: u32,
+    },
+    Dictionary {
+        key: Box<Schema>,
+        value: Box<Schema>,
+        max_len: u32,
+    },
+    ExactByteArray {
+        size: u32,
+    },
+    VarByteArray {
+        max_len: u32,
+    },
+    FixedString {
+        size: u32,
+    },
+    NulTerminatedString {
+        max_len: u32,
+    },
+    VarString {
+        max_len: u32,
+    },
+    /// A codec which does not describe itself, identified by the name of the type it encodes.
+    Opaque(&'static str),
+}
+
+impl Schema {
+    /// Expands a [`Schema::Named`] reference into its definition, leaving other schemas as-is.
+    pub fn resolve(self) -> Schema {
+        match self {
+            Schema::Named(schema_ref) => schema_ref.definition(),
+            schema => schema,
+        }
+    }
+
+    /// Calls `visit` on every schema directly nested in this one. References are not expanded.
+    pub fn for_each_child(&self, mut visit: impl FnMut(&Schema)) {
+        match self {
+            Schema::Struct(schema) => schema.fields.iter().for_each(|field| visit(&field.schema)),
+            Schema::Union(schema) => schema
+                .variants
+                .iter()
+                .for_each(|variant| visit(&variant.schema)),
+            Schema::Nullable { inner, .. } => visit(inner),
+            Schema::Array { element, .. } => visit(element),
+            Schema::Dictionary { key, value, .. } => {
+                visit(key);
+                visit(value);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Schema::Bool => f.write_str("bool"),
+            Schema::U8 => f.write_str("u8"),
+            Schema::I8 => f.write_str("i8"),
+            Schema::U16 => f.write_str("u16"),
+            Schema::I16 => f.write_str("i16"),
+            Schema::U32 => f.write_str("u32"),
+            Schema::I32 => f.write_str("i32"),
+            Schema::U64 => f.write_str("u64"),
+            Schema::I64 => f.write_str("i64"),
+            Schema::F16 => f.write_str("f16"),
+            Schema::F32 => f.write_str("f32"),
+            Schema::F64 => f.write_str("f64"),
+            Schema::VarInt => f.write_str("varint"),
+            Schema::VarLong => f.write_str("varlong"),
+            Schema::Uuid => f.write_str("uuid"),
+            Schema::Enum(schema) => f.write_str(schema.name),
+            Schema::Struct(schema) => f.write_str(schema.name),
+            Schema::Union(schema) => f.write_str(schema.name),
+            Schema::Named(schema_ref) => f.write_str(schema_ref.name()),
+            Schema::Nullable { inner, .. } => write!(f, "{inner}?"),
+            Schema::Array { element, max_len } => write!(f, "[{element}; ..={max_len}]"),
+            Schema::Dictionary {
+                key,
+                value,
+                max_len,
+            } => write!(f, "{{{key}: {value}; ..={max_len}}}"),
+            Schema::ExactByteArray { size } => write!(f, "bytes[{size}]"),
+            Schema::VarByteArray { max_len } => write!(f, "bytes[..={max_len}]"),
+            Schema::FixedString { size } => write!(f, "string[{size}]"),
+            Schema::NulTerminatedString { max_len } => write!(f, "cstring[..={max_len}]"),
+            Schema::VarString { max_len } => write!(f, "string[..={max_len}]"),
+            Schema::Opaque(name) => f.write_str(name),
+        }
+    }
+}
+
+// === SchemaRef === //
+
+/// A by-name reference to the schema of a [`Serde`] type. Two references are equal if they refer
+/// to types with the same name.
+#[derive(Copy, Clone)]
+pub struct SchemaRef {
+    name: &'static str,
+    type_id: TypeId,
+    definition: fn() -> Schema,
+}
+
+impl SchemaRef {
+    pub fn of<T: Serde>() -> Self {
+        Self {
+            name: short_type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+            definition: T::schema,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    pub fn definition(&self) -> Schema {
+        (self.definition)()
+    }
+}
+
+impl fmt::Debug for SchemaRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SchemaRef").field(&self.name).finish()
+    }
+}
+
+impl hash::Hash for SchemaRef {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl Eq for SchemaRef {}
+
+impl PartialEq for SchemaRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+// === Compound Schemas === //
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct EnumSchema {
+    pub name: &'static str,
+    /// Variant names, indexed by their ordinal.
+    pub variants: Vec<String>,
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct StructSchema {
+    pub name: &'static str,
+    /// The number of bytes holding the non-null bits at the start of the struct.
+    pub null_bytes: usize,
+    /// The size of the null bytes and fixed-size fields, after which the variable offset table (if
+    /// any) begins.
+    pub fixed_block_size: usize,
+    /// The size of the entire struct, if every field has a fixed size.
+    pub fixed_size: Option<usize>,
+    /// Fields in declaration order.
+    pub fields: Vec<FieldSchema>,
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct FieldSchema {
+    pub name: &'static str,
+    pub schema: Schema,
+    pub location: FieldLocation,
+    /// The index of the non-null bit guarding this field, if any.
+    pub non_null_bit: Option<usize>,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum FieldLocation {
+    /// The field lives at a fixed byte offset from the start of the struct.
+    Fixed { offset: usize, size: usize },
+    /// The field's offset, relative to the end of the offset table, is stored in the given slot of
+    /// the offset table.
+    Variable { slot: usize },
+    /// The struct's only variable-size field, placed directly after the fixed block without an
+    /// offset table.
+    Trailing,
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct UnionSchema {
+    pub name: &'static str,
+    /// Variants, indexed by their VarInt tag.
+    pub variants: Vec<VariantSchema>,
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct VariantSchema {
+    pub name: &'static str,
+    pub schema: Schema,
+}
@@ -0,0 +1,183 @@
+use std::{borrow::Borrow, fmt, hash, ops::Deref, str};
+
+use bytes::Bytes;
+
+use crate::serde::CodecValue;
+
+// === ByteString === //
+
+/// An immutable UTF-8 string backed by a reference-counted [`Bytes`] buffer. Decoding one slices
+/// the packet buffer rather than copying out of it.
+#[derive(Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ByteString(Bytes);
+
+impl ByteString {
+    pub const fn new() -> Self {
+        Self(Bytes::new())
+    }
+
+    pub const fn from_static(value: &'static str) -> Self {
+        Self(Bytes::from_static(value.as_bytes()))
+    }
+
+    pub fn from_utf8(bytes: Bytes) -> Result<Self, str::Utf8Error> {
+        str::from_utf8(&bytes)?;
+        Ok(Self(bytes))
+    }
+
+    /// # Safety
+    ///
+    /// `bytes` must contain valid UTF-8.
+    pub const unsafe fn from_utf8_unchecked(bytes: Bytes) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.0) }
+    }
+
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.0
+    }
+}
+
+impl fmt::Debug for ByteString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for ByteString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl hash::Hash for ByteString {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl Deref for ByteString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for ByteString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for ByteString {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ByteString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<String> for ByteString {
+    fn from(value: String) -> Self {
+        Self(Bytes::from(value.into_bytes()))
+    }
+}
+
+impl From<&str> for ByteString {
+    fn from(value: &str) -> Self {
+        Self(Bytes::copy_from_slice(value.as_bytes()))
+    }
+}
+
+impl From<ByteString> for String {
+    fn from(value: ByteString) -> Self {
+        value.as_str().to_owned()
+    }
+}
+
+impl From<ByteString> for Bytes {
+    fn from(value: ByteString) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq<str> for ByteString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ByteString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for ByteString {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+#[cfg(feature = "serde-interop")]
+impl ::serde::Serialize for ByteString {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde-interop")]
+impl<'de> ::serde::Deserialize<'de> for ByteString {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+// === CodecString === //
+
+/// A string type which the string codecs can decode into and encode from.
+pub trait CodecString: CodecValue {
+    /// Whether decoding copies the string out of the input buffer rather than slicing it, in which
+    /// case the copy is charged against the decode budget.
+    const COPIES_INPUT: bool;
+
+    fn from_utf8_bytes(bytes: Bytes) -> Result<Self, str::Utf8Error>;
+
+    fn as_str(&self) -> &str;
+}
+
+impl CodecString for String {
+    const COPIES_INPUT: bool = true;
+
+    fn from_utf8_bytes(bytes: Bytes) -> Result<Self, str::Utf8Error> {
+        String::from_utf8(bytes.to_vec()).map_err(|err| err.utf8_error())
+    }
+
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+impl CodecString for ByteString {
+    const COPIES_INPUT: bool = false;
+
+    fn from_utf8_bytes(bytes: Bytes) -> Result<Self, str::Utf8Error> {
+        ByteString::from_utf8(bytes)
+    }
+
+    fn as_str(&self) -> &str {
+        self
+    }
+}
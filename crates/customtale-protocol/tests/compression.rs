@@ -0,0 +1,154 @@
+//! Checks that every compression policy produces frames the decoder accepts.
+
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use customtale_protocol::{
+    compression::{
+        CompressionDictionary, CompressionPolicy, CompressionSettings, CompressionStats,
+    },
+    framed::{HytaleDecodeError, HytaleDecoder, HytaleEncoder},
+    packets::{AnyPacket, Asset, ClientboundPacket, Packet, PacketCategory, WorldSettings},
+};
+use tokio_util::codec::{Decoder, Encoder};
+
+fn world_settings(assets: usize) -> WorldSettings {
+    WorldSettings {
+        worldHeight: 320,
+        requiredAssets: Some(
+            (0..assets)
+                .map(|i| Asset {
+                    hash: format!("{i:064x}").into(),
+                    name: format!("Common/Blocks/Stone_{i}.png").into(),
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn encode(encoder: &mut HytaleEncoder<ClientboundPacket>, packet: WorldSettings) -> BytesMut {
+    let mut dst = BytesMut::new();
+    encoder.encode(packet.into(), &mut dst).unwrap();
+    dst
+}
+
+fn decode(
+    decoder: &mut HytaleDecoder<ClientboundPacket>,
+    mut src: BytesMut,
+) -> Result<WorldSettings, HytaleDecodeError> {
+    let packet = decoder.decode(&mut src)?.unwrap().into_inner();
+
+    let AnyPacket::WorldSettings(packet) = packet else {
+        panic!("expected WorldSettings, got {packet:?}");
+    };
+
+    Ok(*packet)
+}
+
+#[test]
+fn small_payloads_are_stored_raw() {
+    let mut encoder = HytaleEncoder::<ClientboundPacket>::new();
+    encoder.compression = CompressionPolicy::new(CompressionSettings {
+        min_size: usize::MAX,
+        ..Default::default()
+    });
+
+    let mut decoder = HytaleDecoder::<ClientboundPacket>::new(PacketCategory::all());
+
+    let frame = encode(&mut encoder, world_settings(50));
+    assert_eq!(
+        decode(&mut decoder, frame)
+            .unwrap()
+            .requiredAssets
+            .unwrap()
+            .len(),
+        50
+    );
+
+    let stats = encoder.stats().get(WorldSettings::DESCRIPTOR).unwrap();
+    assert_eq!(stats.packets, 1);
+    assert!(stats.bytes_saved() < 0);
+}
+
+#[test]
+fn contexts_are_reused_across_settings() {
+    let mut encoder = HytaleEncoder::<ClientboundPacket>::new();
+    let mut decoder = HytaleDecoder::<ClientboundPacket>::new(PacketCategory::all());
+
+    for level in [1, 19, -5, 3] {
+        encoder.compression.set(
+            WorldSettings::DESCRIPTOR,
+            CompressionSettings {
+                level,
+                ..Default::default()
+            },
+        );
+
+        let frame = encode(&mut encoder, world_settings(50));
+        assert_eq!(
+            decode(&mut decoder, frame)
+                .unwrap()
+                .requiredAssets
+                .unwrap()
+                .len(),
+            50
+        );
+    }
+
+    let stats = encoder.stats().get(WorldSettings::DESCRIPTOR).unwrap();
+    assert_eq!(stats.packets, 4);
+    assert!(stats.bytes_saved() > 0);
+}
+
+#[test]
+fn dictionaries_must_be_shared() {
+    // Raw content makes for a valid, if untuned, dictionary.
+    let mut content = BytesMut::new();
+    AnyPacket::from(world_settings(20))
+        .encode_static(&mut content)
+        .unwrap();
+    let dictionary = Arc::new(CompressionDictionary::new(content.to_vec()));
+
+    let mut policy = CompressionPolicy::default();
+    policy.set(
+        WorldSettings::DESCRIPTOR,
+        CompressionSettings {
+            dictionary: Some(dictionary),
+            ..Default::default()
+        },
+    );
+
+    let mut encoder = HytaleEncoder::<ClientboundPacket>::new();
+    encoder.compression = policy.clone();
+
+    let frame = encode(&mut encoder, world_settings(10));
+
+    let mut decoder = HytaleDecoder::<ClientboundPacket>::new(PacketCategory::all());
+    decoder.compression = policy;
+    assert_eq!(
+        decode(&mut decoder, frame.clone())
+            .unwrap()
+            .requiredAssets
+            .unwrap()
+            .len(),
+        10,
+    );
+
+    let mut decoder = HytaleDecoder::<ClientboundPacket>::new(PacketCategory::all());
+    assert!(decode(&mut decoder, frame).is_err());
+}
+
+#[test]
+fn stats_are_labelled_with_the_sent_descriptors() {
+    let mut encoder = HytaleEncoder::<ClientboundPacket>::new();
+    encode(&mut encoder, world_settings(50));
+
+    let mut stats = CompressionStats::default();
+    stats.merge(encoder.stats());
+    stats.merge(encoder.stats());
+
+    let entries = stats.iter().collect::<Vec<_>>();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].0.name, WorldSettings::DESCRIPTOR.name);
+    assert_eq!(entries[0].1.packets, 2);
+}
@@ -0,0 +1,66 @@
+//! Checks that each end of a connection refuses packets which only travel the other way.
+
+use bytes::BytesMut;
+use customtale_protocol::{
+    framed::{HytaleDecodeError, HytaleDecoder, HytaleEncodeError, HytaleEncoder},
+    packets::{
+        ClientMovement, ClientboundPacket, Packet, PacketCategory, ServerboundPacket, SetChunk,
+    },
+};
+use tokio_util::codec::{Decoder, Encoder};
+
+#[test]
+fn servers_refuse_clientbound_packets() {
+    let mut frame = BytesMut::new();
+    HytaleEncoder::<ClientboundPacket>::new()
+        .encode(SetChunk::default().into(), &mut frame)
+        .unwrap();
+
+    let mut decoder = HytaleDecoder::<ServerboundPacket>::new(PacketCategory::all());
+
+    let error = decoder.decode(&mut frame).unwrap_err();
+    assert!(
+        matches!(error, HytaleDecodeError::WrongDirection { descriptor } if descriptor.id == SetChunk::DESCRIPTOR.id),
+        "{error:?}",
+    );
+}
+
+#[test]
+fn clients_refuse_serverbound_packets() {
+    let mut frame = BytesMut::new();
+    HytaleEncoder::<ServerboundPacket>::new()
+        .encode(ClientMovement::default().into(), &mut frame)
+        .unwrap();
+
+    let mut decoder = HytaleDecoder::<ClientboundPacket>::new(PacketCategory::all());
+
+    let error = decoder.decode(&mut frame).unwrap_err();
+    assert!(
+        matches!(error, HytaleDecodeError::WrongDirection { descriptor } if descriptor.id == ClientMovement::DESCRIPTOR.id),
+        "{error:?}",
+    );
+}
+
+#[test]
+fn encoders_refuse_packets_for_the_other_direction() {
+    let mut dst = BytesMut::new();
+
+    let error = HytaleEncoder::<ServerboundPacket>::new()
+        .encode(SetChunk::default().into(), &mut dst)
+        .unwrap_err();
+    assert!(
+        matches!(error, HytaleEncodeError::WrongDirection { descriptor } if descriptor.id == SetChunk::DESCRIPTOR.id),
+        "{error:?}",
+    );
+
+    let error = HytaleEncoder::<ClientboundPacket>::new()
+        .encode(ClientMovement::default().into(), &mut dst)
+        .unwrap_err();
+    assert!(
+        matches!(error, HytaleEncodeError::WrongDirection { descriptor } if descriptor.id == ClientMovement::DESCRIPTOR.id),
+        "{error:?}",
+    );
+
+    // Nothing is written for refused packets.
+    assert!(dst.is_empty());
+}
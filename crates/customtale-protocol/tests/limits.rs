@@ -0,0 +1,130 @@
+//! Checks that small inputs cannot make the decoder allocate more than its budget or buffer more
+//! than a packet's maximum size.
+
+use bytes::{BufMut, Bytes, BytesMut};
+use bytes_varint::VarIntSupportMut;
+use customtale_protocol::{
+    framed::{HytaleDecodeError, HytaleDecoder, HytaleEncoder},
+    packets::{
+        AnyPacket, Asset, AssetPart, ClientboundPacket, Packet, PacketCategory, ServerboundPacket,
+        ViewRadius, WorldSettings,
+    },
+    serde::{DecodeErrorKind, DecodeLimits, DecodeResult, SharedBudget},
+};
+use tokio_util::codec::{Decoder, Encoder};
+
+/// A frame of packet `P` whose payload is `uncompressed_len` zeroes, compressed.
+fn zeroes_frame<P: Packet>(uncompressed_len: usize) -> BytesMut {
+    let zeroes = vec![0; uncompressed_len];
+    let mut payload = Vec::with_capacity(zstd_safe::compress_bound(uncompressed_len));
+    zstd_safe::compress(&mut payload, &zeroes, 3).unwrap();
+
+    let mut frame = BytesMut::new();
+    frame.put_u32_le(payload.len() as u32);
+    frame.put_u32_le(P::DESCRIPTOR.id);
+    frame.put_slice(&payload);
+    frame
+}
+
+#[test]
+fn decompression_is_charged_to_the_budget() {
+    let mut frame = zeroes_frame::<AssetPart>(1024 * 1024);
+    assert!(frame.len() < 1024);
+
+    let mut decoder = HytaleDecoder::<ClientboundPacket>::new(PacketCategory::all());
+    decoder.limits = DecodeLimits::new(256 * 1024);
+
+    let error = decoder.decode(&mut frame).unwrap_err();
+    assert!(
+        matches!(error, HytaleDecodeError::OverBudget { descriptor, .. } if descriptor.id == AssetPart::DESCRIPTOR.id),
+        "{error:?}",
+    );
+}
+
+#[test]
+fn decompression_stops_past_the_maximum_size() {
+    let max_size = AssetPart::DESCRIPTOR.max_size as usize;
+    let mut frame = zeroes_frame::<AssetPart>(max_size + 64 * 1024);
+
+    let mut decoder = HytaleDecoder::<ClientboundPacket>::new(PacketCategory::all());
+
+    let error = decoder.decode(&mut frame).unwrap_err();
+    let HytaleDecodeError::TooLong { received, .. } = error else {
+        panic!("expected TooLong, got {error:?}");
+    };
+
+    // Nothing past the first byte over the limit is buffered.
+    assert_eq!(received as usize, max_size + 1);
+}
+
+#[test]
+fn huge_array_lengths_do_not_reserve_memory() {
+    // The most `requiredAssets` may hold.
+    const LEN: u32 = 4_096_000;
+
+    // `worldHeight` and `requiredAssets` are both present, but the array's elements are missing.
+    let mut contents = BytesMut::new();
+    contents.put_u8(0b1);
+    contents.put_i32_le(320);
+    contents.put_u32_varint(LEN);
+    let contents = contents.freeze();
+
+    type Decode = fn(u32, Bytes, &mut DecodeLimits) -> DecodeResult<AnyPacket>;
+    let decoders: [(&str, Decode); 2] = [
+        ("erased", AnyPacket::decode_with_limits),
+        ("static", AnyPacket::decode_static_with_limits),
+    ];
+
+    for (name, decode) in decoders {
+        let mut limits = DecodeLimits::new(128);
+        let error =
+            decode(WorldSettings::DESCRIPTOR.id, contents.clone(), &mut limits).unwrap_err();
+
+        let DecodeErrorKind::OverBudget { requested, .. } = *error.kind() else {
+            panic!("{name}: expected OverBudget, got {error:?}");
+        };
+
+        // Space is reserved a few elements at a time rather than for the whole declared length.
+        assert!(
+            requested <= 4 * size_of::<Asset>(),
+            "{name}: requested {requested} bytes"
+        );
+    }
+}
+
+#[test]
+fn decoders_sharing_a_budget_cannot_exceed_it() {
+    let mut frame = BytesMut::new();
+    HytaleEncoder::<ServerboundPacket>::new()
+        .encode(ViewRadius { value: 12 }.into(), &mut frame)
+        .unwrap();
+
+    // Enough for a single payload at a time.
+    let payload_len = frame.len() - 8;
+    let budget = SharedBudget::new(payload_len);
+
+    let decoder = || {
+        let mut decoder = HytaleDecoder::<ServerboundPacket>::new(PacketCategory::all());
+        decoder.limits = DecodeLimits::default().sharing(budget.clone());
+        decoder
+    };
+    let (mut first, mut second) = (decoder(), decoder());
+
+    // The first decoder holds on to its share while the payload is still arriving.
+    let mut partial = BytesMut::from(&frame[..8]);
+    assert!(first.decode(&mut partial).unwrap().is_none());
+    assert_eq!(budget.remaining(), 0);
+
+    let error = second.decode(&mut frame.clone()).unwrap_err();
+    assert!(
+        matches!(error, HytaleDecodeError::OverBudget { .. }),
+        "{error:?}"
+    );
+
+    // It gives it back once the packet is decoded.
+    partial.extend_from_slice(&frame[8..]);
+    assert!(first.decode(&mut partial).unwrap().is_some());
+    assert_eq!(budget.remaining(), payload_len);
+
+    assert!(second.decode(&mut frame).unwrap().is_some());
+}
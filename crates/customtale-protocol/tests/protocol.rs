@@ -0,0 +1,240 @@
+//! Checks that clients are matched to the packet table of their protocol version and that each
+//! table only speaks its own packets.
+
+use bytes::{BufMut, Bytes, BytesMut};
+use customtale_protocol::{
+    packets::{
+        self, AnyPacket, Packet, PacketCategory, PacketDescriptor, PacketDirection, PacketEntry,
+        PacketTable, Protocol, ProtocolPacket, ProtocolRegistry, ProtocolVersion, WorldSettings,
+        build_packet_table,
+    },
+    serde::{DecodeErrorKind, DecodeLimits},
+};
+
+const KNOWN: ProtocolVersion = ProtocolVersion {
+    crc: 0x1234_5678,
+    build_number: 7,
+};
+
+const UNKNOWN: ProtocolVersion = ProtocolVersion {
+    crc: 0x0bad_f00d,
+    build_number: 8,
+};
+
+/// The builtin table under a version of its own.
+static VERSIONED: Protocol<AnyPacket> = Protocol {
+    module_path: "tests::versioned",
+    version: Some(KNOWN),
+    packets: packets::PROTOCOL.packets,
+    table: packets::PROTOCOL.table,
+};
+
+static EMPTY_TABLE: [Option<&PacketEntry<AnyPacket>>; 0] = [];
+
+/// A table without a single packet.
+static EMPTY: Protocol<AnyPacket> = Protocol {
+    module_path: "tests::empty",
+    version: None,
+    packets: &[],
+    table: &EMPTY_TABLE,
+};
+
+fn registry() -> ProtocolRegistry<AnyPacket> {
+    let mut registry = ProtocolRegistry::new();
+    registry.register(&VERSIONED);
+    registry
+}
+
+#[test]
+fn known_version_is_selected() {
+    let selected = registry().select(KNOWN).unwrap();
+    assert!(std::ptr::eq(selected, &VERSIONED));
+}
+
+#[test]
+fn unknown_version_is_rejected() {
+    assert!(registry().select(UNKNOWN).is_none());
+}
+
+#[test]
+fn unversioned_tables_are_not_selected_implicitly() {
+    let mut registry = registry();
+    registry.register(&EMPTY);
+
+    assert!(registry.select(UNKNOWN).is_none());
+}
+
+#[test]
+fn fallback_serves_unknown_versions() {
+    let mut registry = registry();
+    registry.register(&EMPTY);
+    registry.set_fallback(registry.get("empty"));
+
+    assert!(std::ptr::eq(registry.select(UNKNOWN).unwrap(), &EMPTY));
+    assert!(std::ptr::eq(registry.select(KNOWN).unwrap(), &VERSIONED));
+}
+
+#[test]
+fn tables_only_speak_their_own_packets() {
+    let packet = AnyPacket::from(WorldSettings {
+        worldHeight: 320,
+        requiredAssets: None,
+    });
+
+    let mut contents = BytesMut::new();
+    VERSIONED.encode_static(&packet, &mut contents).unwrap();
+
+    let decoded = VERSIONED
+        .decode_static_with_limits(
+            WorldSettings::DESCRIPTOR.id,
+            contents.clone().freeze(),
+            &mut DecodeLimits::unlimited(),
+        )
+        .unwrap();
+    assert!(decoded.downcast::<WorldSettings>().is_ok());
+
+    assert!(EMPTY.descriptor_for(WorldSettings::DESCRIPTOR.id).is_none());
+    assert!(EMPTY.encode_static(&packet, &mut BytesMut::new()).is_err());
+
+    let error = EMPTY
+        .decode_static_with_limits(
+            WorldSettings::DESCRIPTOR.id,
+            contents.freeze(),
+            &mut DecodeLimits::unlimited(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind(), DecodeErrorKind::UnknownPacket(_)));
+}
+
+#[test]
+fn builtin_registry_falls_back_to_the_newest_table() {
+    let registry = ProtocolRegistry::builtin();
+
+    assert!(registry.is_selectable());
+    assert!(std::ptr::eq(
+        registry.select(UNKNOWN).unwrap(),
+        &packets::PROTOCOL
+    ));
+}
+
+#[test]
+fn registries_without_a_known_version_or_fallback_select_nothing() {
+    let mut registry = ProtocolRegistry::new();
+    registry.register(&EMPTY);
+    assert!(!registry.is_selectable());
+
+    registry.register(&VERSIONED);
+    assert!(registry.is_selectable());
+}
+
+// === Other packet types === //
+
+/// Stands in for the packets of a table generated into another module, which keeps each packet's
+/// contents as they are.
+#[derive(Debug)]
+struct Opaque {
+    descriptor: &'static PacketDescriptor,
+    contents: Bytes,
+}
+
+impl ProtocolPacket for Opaque {
+    fn descriptor(&self) -> &'static PacketDescriptor {
+        self.descriptor
+    }
+
+    fn encode_static(&self, target: &mut BytesMut) -> anyhow::Result<()> {
+        target.put_slice(&self.contents);
+        Ok(())
+    }
+}
+
+const fn descriptor(name: &'static str, id: u32) -> PacketDescriptor {
+    PacketDescriptor {
+        name,
+        id,
+        is_compressed: false,
+        max_size: 1024,
+        category: PacketCategory::CONNECTION,
+        direction: PacketDirection::Both,
+    }
+}
+
+static HELLO: PacketDescriptor = descriptor("Hello", 0);
+static GREETING: PacketDescriptor = descriptor("Greeting", 1);
+
+/// The `PacketEntry` of a packet with `descriptor`.
+macro_rules! opaque_entry {
+    ($descriptor:ident) => {
+        PacketEntry {
+            descriptor: &$descriptor,
+            decode: |contents, _| {
+                Ok(Opaque {
+                    descriptor: &$descriptor,
+                    contents,
+                })
+            },
+            decode_static: |contents, _| {
+                Ok(Opaque {
+                    descriptor: &$descriptor,
+                    contents,
+                })
+            },
+            from_dynamic: |_| anyhow::bail!("opaque packets have no dynamic form"),
+        }
+    };
+}
+
+static OLD_ENTRIES: [PacketEntry<Opaque>; 1] = [opaque_entry!(HELLO)];
+static NEW_ENTRIES: [PacketEntry<Opaque>; 2] = [opaque_entry!(HELLO), opaque_entry!(GREETING)];
+
+static OLD_TABLE: [Option<&PacketEntry<Opaque>>; 1] = build_packet_table(&OLD_ENTRIES);
+static NEW_TABLE: [Option<&PacketEntry<Opaque>>; 2] = build_packet_table(&NEW_ENTRIES);
+
+const fn opaque(
+    module_path: &'static str,
+    version: ProtocolVersion,
+    packets: &'static [&'static PacketDescriptor],
+    table: &'static PacketTable<Opaque>,
+) -> Protocol<Opaque> {
+    Protocol {
+        module_path,
+        version: Some(version),
+        packets,
+        table,
+    }
+}
+
+static OPAQUE_OLD: Protocol<Opaque> = opaque("tests::old", KNOWN, &[&HELLO], &OLD_TABLE);
+static OPAQUE_NEW: Protocol<Opaque> =
+    opaque("tests::new", UNKNOWN, &[&HELLO, &GREETING], &NEW_TABLE);
+
+#[test]
+fn versions_of_another_packet_type_are_selected_side_by_side() {
+    let mut registry = ProtocolRegistry::new();
+    registry.register(&OPAQUE_OLD);
+    registry.register(&OPAQUE_NEW);
+
+    let old = registry.select(KNOWN).unwrap();
+    let new = registry.select(UNKNOWN).unwrap();
+    assert!(std::ptr::eq(old, &OPAQUE_OLD));
+    assert!(std::ptr::eq(new, &OPAQUE_NEW));
+
+    let contents = Bytes::from_static(b"hi");
+    let mut limits = DecodeLimits::unlimited();
+
+    let greeting = new
+        .decode_with_limits(GREETING.id, contents.clone(), &mut limits)
+        .unwrap();
+    assert_eq!(greeting.descriptor.name, "Greeting");
+
+    let mut encoded = BytesMut::new();
+    new.encode_static(&greeting, &mut encoded).unwrap();
+    assert_eq!(encoded, contents);
+
+    // The older version predates `Greeting`.
+    assert!(old.encode_static(&greeting, &mut BytesMut::new()).is_err());
+    let error = old
+        .decode_with_limits(GREETING.id, contents, &mut limits)
+        .unwrap_err();
+    assert!(matches!(error.kind(), DecodeErrorKind::UnknownPacket(1)));
+}
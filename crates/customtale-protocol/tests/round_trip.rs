@@ -0,0 +1,195 @@
+//! Generates random packets from their schemas and checks that both the erased and the static
+//! codecs encode every one to the same bytes, which survive an encode → decode → encode round
+//! trip through either.
+
+use bytes::{Bytes, BytesMut};
+use customtale_protocol::{
+    packets::{AnyPacket, PacketDescriptor},
+    serde::{ByteString, Dictionary, DictionaryEntry, DynamicValue, Schema},
+};
+use half::f16;
+use proptest::{
+    collection::vec,
+    prelude::*,
+    sample::select,
+    string::string_regex,
+    test_runner::{Config, TestCaseError, TestRunner},
+};
+use uuid::Uuid;
+
+/// The number of named types to expand before nullable values and collections are forced to be
+/// empty, keeping recursive types finite.
+const MAX_DEPTH: usize = 4;
+
+/// Collections and strings are kept far below their `max_len` so that cases stay small.
+const MAX_ELEMENTS: u32 = 3;
+const MAX_BYTES: u32 = 32;
+
+fn value(schema: &Schema, depth: usize) -> BoxedStrategy<DynamicValue> {
+    let limit = |max_len: u32, cap: u32| {
+        if depth >= MAX_DEPTH {
+            0
+        } else {
+            max_len.min(cap) as usize
+        }
+    };
+
+    match schema {
+        Schema::Bool => any::<bool>().prop_map(DynamicValue::Bool).boxed(),
+        Schema::U8 => any::<u8>().prop_map(DynamicValue::U8).boxed(),
+        Schema::I8 => any::<i8>().prop_map(DynamicValue::I8).boxed(),
+        Schema::U16 => any::<u16>().prop_map(DynamicValue::U16).boxed(),
+        Schema::I16 => any::<i16>().prop_map(DynamicValue::I16).boxed(),
+        Schema::U32 | Schema::VarInt => any::<u32>().prop_map(DynamicValue::U32).boxed(),
+        Schema::I32 => any::<i32>().prop_map(DynamicValue::I32).boxed(),
+        Schema::U64 | Schema::VarLong => any::<u64>().prop_map(DynamicValue::U64).boxed(),
+        Schema::I64 => any::<i64>().prop_map(DynamicValue::I64).boxed(),
+        // Floats are generated from raw bits so that NaN payloads and signed zeros are covered.
+        Schema::F16 => any::<u16>()
+            .prop_map(|bits| DynamicValue::F16(f16::from_bits(bits)))
+            .boxed(),
+        Schema::F32 => any::<u32>()
+            .prop_map(|bits| DynamicValue::F32(f32::from_bits(bits)))
+            .boxed(),
+        Schema::F64 => any::<u64>()
+            .prop_map(|bits| DynamicValue::F64(f64::from_bits(bits)))
+            .boxed(),
+        Schema::Uuid => any::<u128>()
+            .prop_map(|bits| DynamicValue::Uuid(Uuid::from_u128(bits)))
+            .boxed(),
+        Schema::Enum(schema) => select(schema.variants.clone())
+            .prop_map(DynamicValue::Enum)
+            .boxed(),
+        Schema::Struct(schema) => schema
+            .fields
+            .iter()
+            .map(|field| {
+                let name = field.name;
+                value(&field.schema, depth).prop_map(move |value| (name, value))
+            })
+            .collect::<Vec<_>>()
+            .prop_map(DynamicValue::Struct)
+            .boxed(),
+        Schema::Union(schema) => {
+            prop::strategy::Union::new(schema.variants.iter().map(|variant| {
+                let name = variant.name;
+                value(&variant.schema, depth)
+                    .prop_map(move |value| DynamicValue::Variant(name, Box::new(value)))
+            }))
+            .boxed()
+        }
+        Schema::Named(schema_ref) => {
+            // Expand definitions lazily so that only the parts of the type tree which are actually
+            // generated get built.
+            let schema_ref = *schema_ref;
+            Just(())
+                .prop_flat_map(move |()| value(&schema_ref.definition(), depth + 1))
+                .boxed()
+        }
+        Schema::Nullable { .. } if depth >= MAX_DEPTH => Just(DynamicValue::Null).boxed(),
+        Schema::Nullable { inner, .. } => {
+            prop_oneof![Just(DynamicValue::Null), value(inner, depth)].boxed()
+        }
+        Schema::Array { element, max_len } => {
+            vec(value(element, depth), 0..=limit(*max_len, MAX_ELEMENTS))
+                .prop_map(DynamicValue::Array)
+                .boxed()
+        }
+        Schema::Dictionary {
+            key,
+            value: value_schema,
+            max_len,
+        } => vec(
+            (value(key, depth), value(value_schema, depth)),
+            0..=limit(*max_len, MAX_ELEMENTS),
+        )
+        .prop_map(|entries| {
+            DynamicValue::Dictionary(Dictionary::new(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| DictionaryEntry::new(key, value))
+                    .collect(),
+            ))
+        })
+        .boxed(),
+        Schema::ExactByteArray { size } => vec(any::<u8>(), *size as usize)
+            .prop_map(|bytes| DynamicValue::Bytes(Bytes::from(bytes)))
+            .boxed(),
+        Schema::VarByteArray { max_len } => vec(any::<u8>(), 0..=limit(*max_len, MAX_BYTES))
+            .prop_map(|bytes| DynamicValue::Bytes(Bytes::from(bytes)))
+            .boxed(),
+        Schema::FixedString { size: max_len }
+        | Schema::NulTerminatedString { max_len }
+        | Schema::VarString { max_len } => string_regex(&format!(
+            "[a-zA-Z0-9 _.:/-]{{0,{}}}",
+            limit(*max_len, MAX_BYTES)
+        ))
+        .unwrap()
+        .prop_map(|value| DynamicValue::String(ByteString::from(value)))
+        .boxed(),
+        schema => panic!("cannot generate values for schema `{schema}`"),
+    }
+}
+
+fn check_round_trip(
+    descriptor: &PacketDescriptor,
+    value: &DynamicValue,
+) -> Result<(), TestCaseError> {
+    let packet = AnyPacket::from_dynamic(descriptor.id, value)
+        .map_err(|err| TestCaseError::fail(format!("failed to build packet: {err:?}")))?;
+
+    let mut encoded = BytesMut::new();
+    packet
+        .encode(&mut encoded)
+        .map_err(|err| TestCaseError::fail(format!("failed to encode: {err:?}")))?;
+
+    prop_assert_eq!(packet.encoded_len(), encoded.len());
+
+    let mut encoded_static = BytesMut::new();
+    packet
+        .encode_static(&mut encoded_static)
+        .map_err(|err| TestCaseError::fail(format!("failed to statically encode: {err:?}")))?;
+
+    prop_assert_eq!(&encoded[..], &encoded_static[..]);
+
+    let encoded = encoded.freeze();
+
+    let decoded = AnyPacket::decode(descriptor.id, encoded.clone())
+        .map_err(|err| TestCaseError::fail(format!("failed to decode: {err}")))?;
+
+    let mut reencoded = BytesMut::new();
+    decoded
+        .encode(&mut reencoded)
+        .map_err(|err| TestCaseError::fail(format!("failed to re-encode: {err:?}")))?;
+
+    prop_assert_eq!(&encoded[..], &reencoded[..]);
+
+    let decoded_static = AnyPacket::decode_static(descriptor.id, encoded.clone())
+        .map_err(|err| TestCaseError::fail(format!("failed to statically decode: {err}")))?;
+
+    let mut reencoded_static = BytesMut::new();
+    decoded_static
+        .encode_static(&mut reencoded_static)
+        .map_err(|err| TestCaseError::fail(format!("failed to statically re-encode: {err:?}")))?;
+
+    prop_assert_eq!(&encoded[..], &reencoded_static[..]);
+
+    Ok(())
+}
+
+#[test]
+fn every_packet_round_trips() {
+    for (descriptor, schema) in AnyPacket::schemas() {
+        let mut runner = TestRunner::new(Config {
+            cases: 32,
+            failure_persistence: None,
+            ..Config::default()
+        });
+
+        if let Err(err) = runner.run(&value(&schema, 0), |value| {
+            check_round_trip(descriptor, &value)
+        }) {
+            panic!("{descriptor} failed to round-trip: {err}");
+        }
+    }
+}
@@ -0,0 +1,468 @@
+//! The server's configuration file.
+//!
+//! The configuration is a TOML document whose keys may each be overridden on the command line
+//! with `KEY=VALUE` pairs, where `KEY` is a dotted path such as `network.bind` and `VALUE` is a
+//! TOML value. Values which are not valid TOML, such as `--set server.name=Lobby`, are taken as
+//! strings.
+//!
+//! Overrides are merged into the document before it is deserialized, so a problem with either is
+//! reported as a [`ConfigError::Invalid`] pointing at the offending text. Overrides are shown
+//! below the file they were applied to.
+
+use std::{
+    fs, io,
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    path::{Path, PathBuf},
+};
+
+use customtale_protocol::packets::{self, ProtocolRegistry};
+use miette::{Diagnostic, NamedSource, SourceSpan};
+use serde::{Deserialize, Deserializer, de::Error as _};
+use thiserror::Error;
+use toml::de::{DeTable, DeValue};
+
+use crate::transport::{MULTI_STREAM_ALPN, StreamLayout};
+
+// === ConfigError === //
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum ConfigError {
+    #[error("failed to read {}", path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("invalid override `{arg}`")]
+    #[diagnostic(help("overrides take the form KEY=VALUE, e.g. `server.max_players=20`"))]
+    MalformedOverride { arg: String },
+    #[error("{message}")]
+    Invalid {
+        message: String,
+        #[source_code]
+        source_code: NamedSource<String>,
+        #[label]
+        span: SourceSpan,
+    },
+}
+
+// === Config === //
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub network: NetworkConfig,
+    pub world: WorldConfig,
+    pub auth: AuthConfig,
+    pub paths: PathsConfig,
+    pub log: LogConfig,
+}
+
+impl Config {
+    /// Reads the configuration file at `path` and applies `overrides` to it.
+    pub fn load(path: &Path, overrides: &[String]) -> Result<Self, ConfigError> {
+        let text = fs::read_to_string(path).map_err(|source| ConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::parse(path.display().to_string(), text, overrides)
+    }
+
+    /// Parses a configuration document named `name` and applies `overrides` to it.
+    pub fn parse(name: String, text: String, overrides: &[String]) -> Result<Self, ConfigError> {
+        let file_len = text.len();
+        let mut source = text;
+
+        // Each override is parsed from a copy of its line which is padded to the line's offset in
+        // `source`, so that the spans of every value point into `source`.
+        let mut documents = Vec::with_capacity(overrides.len());
+
+        if !overrides.is_empty() {
+            if !source.is_empty() && !source.ends_with('\n') {
+                source.push('\n');
+            }
+
+            source.push_str("\n# Command line overrides\n");
+
+            for arg in overrides {
+                let Some((key, value)) = arg.split_once('=') else {
+                    return Err(ConfigError::MalformedOverride { arg: arg.clone() });
+                };
+
+                let (key, value) = (key.trim(), value.trim());
+
+                let line = match DeTable::parse(&format!("{key} = {value}")) {
+                    Ok(_) => format!("{key} = {value}"),
+                    Err(_) => format!("{key} = {}", toml::Value::String(value.to_string())),
+                };
+
+                documents.push(format!("{}{line}", " ".repeat(source.len())));
+
+                source.push_str(&line);
+                source.push('\n');
+            }
+        }
+
+        let invalid = |error: toml::de::Error| ConfigError::Invalid {
+            message: error.message().to_string(),
+            source_code: NamedSource::new(&name, source.clone()),
+            span: error.span().unwrap_or(0..0).into(),
+        };
+
+        let mut table = DeTable::parse(&source[..file_len]).map_err(invalid)?;
+
+        for document in &documents {
+            let overrides = DeTable::parse(document).map_err(invalid)?;
+            merge(table.get_mut(), overrides.into_inner());
+        }
+
+        Self::deserialize(toml::de::Deserializer::from(table)).map_err(invalid)
+    }
+}
+
+/// Merges `from` into `into`, replacing everything but tables, which are merged recursively.
+fn merge<'i>(into: &mut DeTable<'i>, from: DeTable<'i>) {
+    for (key, mut value) in from {
+        if let Some(existing) = into.get_mut(&**key.get_ref())
+            && let DeValue::Table(existing) = existing.get_mut()
+            && let DeValue::Table(table) = value.get_mut()
+        {
+            merge(existing, std::mem::take(table));
+            continue;
+        }
+
+        into.insert(key, value);
+    }
+}
+
+// === Sections === //
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    /// The name shown to players once they have joined.
+    pub name: String,
+    pub motd: String,
+    /// The most players which may be connected at once.
+    pub max_players: u32,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            name: "CustomTale Server".to_string(),
+            motd: String::new(),
+            max_players: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkConfig {
+    /// The addresses to listen on.
+    #[serde(deserialize_with = "non_empty")]
+    pub bind: Vec<SocketAddr>,
+    /// Whether IPv6 addresses also accept IPv4 clients. Unspecified IPv6 addresses such as `[::]`
+    /// then listen on every interface of both families.
+    pub dual_stack: bool,
+    /// The ALPN protocols to offer, in order of preference.
+    #[serde(deserialize_with = "alpn_protocols")]
+    pub alpn: Vec<String>,
+    /// The layout used with clients which negotiate [`MULTI_STREAM_ALPN`]. Every other client only
+    /// uses the primary stream.
+    pub stream_layout: StreamLayoutKind,
+    /// A cap on each connection's outbound bandwidth, in bytes per second.
+    pub bandwidth_limit: Option<u64>,
+    /// The packet table, e.g. `v1`, served to clients whose protocol version no table claims.
+    /// Defaults to the newest table. Such clients are refused if this is set to `false`.
+    #[serde(deserialize_with = "protocol_name")]
+    pub protocol_fallback: Option<String>,
+}
+
+impl NetworkConfig {
+    /// The ALPN protocols to offer, preceded by [`MULTI_STREAM_ALPN`] if the stream layout needs
+    /// it and `alpn` does not list it.
+    pub fn offered_alpn(&self) -> Vec<Vec<u8>> {
+        let mut protocols = self
+            .alpn
+            .iter()
+            .map(|protocol| protocol.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+
+        if self.stream_layout != StreamLayoutKind::Single
+            && !protocols
+                .iter()
+                .any(|protocol| protocol == MULTI_STREAM_ALPN)
+        {
+            protocols.insert(0, MULTI_STREAM_ALPN.to_vec());
+        }
+
+        protocols
+    }
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            bind: vec![SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 5520))],
+            dual_stack: false,
+            alpn: vec!["hytale/2".to_string(), "hytale/1".to_string()],
+            stream_layout: StreamLayoutKind::Single,
+            bandwidth_limit: None,
+            protocol_fallback: Some(packets::PROTOCOL.name().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StreamLayoutKind {
+    /// See [`StreamLayout::single`].
+    #[default]
+    Single,
+    /// See [`StreamLayout::split`].
+    Split,
+}
+
+impl StreamLayoutKind {
+    pub fn layout(self) -> StreamLayout {
+        match self {
+            Self::Single => StreamLayout::single(),
+            Self::Split => StreamLayout::split(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WorldConfig {
+    #[serde(deserialize_with = "positive")]
+    pub height: i32,
+    pub view_radius: ViewRadiusLimits,
+}
+
+impl Default for WorldConfig {
+    fn default() -> Self {
+        Self {
+            height: 320,
+            view_radius: ViewRadiusLimits::default(),
+        }
+    }
+}
+
+/// The range of view radii, in chunks, clients may ask for. Requests outside it are clamped.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Deserialize)]
+#[serde(try_from = "RawViewRadiusLimits")]
+pub struct ViewRadiusLimits {
+    pub min: i32,
+    pub max: i32,
+}
+
+impl Default for ViewRadiusLimits {
+    fn default() -> Self {
+        Self { min: 2, max: 32 }
+    }
+}
+
+impl ViewRadiusLimits {
+    pub fn clamp(&self, radius: i32) -> i32 {
+        radius.clamp(self.min, self.max)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawViewRadiusLimits {
+    min: i32,
+    max: i32,
+}
+
+impl Default for RawViewRadiusLimits {
+    fn default() -> Self {
+        let ViewRadiusLimits { min, max } = ViewRadiusLimits::default();
+        Self { min, max }
+    }
+}
+
+impl TryFrom<RawViewRadiusLimits> for ViewRadiusLimits {
+    type Error = String;
+
+    fn try_from(RawViewRadiusLimits { min, max }: RawViewRadiusLimits) -> Result<Self, String> {
+        if min < 1 {
+            return Err(format!(
+                "the minimum view radius must be at least 1, not {min}"
+            ));
+        }
+
+        if min > max {
+            return Err(format!(
+                "the minimum view radius ({min}) is larger than the maximum ({max})"
+            ));
+        }
+
+        Ok(Self { min, max })
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    pub mode: AuthMode,
+    /// How the server's operator signs in when the server authenticates players.
+    pub oauth: OAuthFlowKind,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthMode {
+    /// Players must prove their identity through the session service.
+    #[default]
+    Authenticated,
+    /// Anyone may join under any name.
+    Offline,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OAuthFlowKind {
+    /// Logs a URL to open in a browser on the same machine.
+    #[default]
+    Browser,
+    /// Logs a code to enter on any device, for servers without a browser.
+    Device,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PathsConfig {
+    /// The directory relative paths are resolved against.
+    pub data_dir: PathBuf,
+    pub tls_cert: PathBuf,
+    pub tls_key: PathBuf,
+    /// The directory every connection is captured into, if any.
+    pub capture_dir: Option<PathBuf>,
+}
+
+impl Default for PathsConfig {
+    fn default() -> Self {
+        Self {
+            data_dir: PathBuf::from("."),
+            tls_cert: PathBuf::from("tls/cert.pem"),
+            tls_key: PathBuf::from("tls/key.pem"),
+            capture_dir: None,
+        }
+    }
+}
+
+impl PathsConfig {
+    pub fn resolve(&self, path: &Path) -> PathBuf {
+        self.data_dir.join(path)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    /// A `tracing` filter directive such as `info,customtale_server=debug`. `RUST_LOG` takes
+    /// precedence when it is set.
+    #[serde(deserialize_with = "log_filter")]
+    pub filter: String,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            filter: "info".to_string(),
+        }
+    }
+}
+
+// === Validation === //
+
+fn non_empty<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let values = Vec::<T>::deserialize(deserializer)?;
+
+    if values.is_empty() {
+        return Err(D::Error::invalid_length(0, &"at least one entry"));
+    }
+
+    Ok(values)
+}
+
+fn alpn_protocols<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let protocols = non_empty::<D, String>(deserializer)?;
+
+    if let Some(protocol) = protocols
+        .iter()
+        .find(|protocol| protocol.is_empty() || protocol.len() > 255)
+    {
+        return Err(D::Error::custom(format!(
+            "ALPN protocol {protocol:?} must be between 1 and 255 bytes long"
+        )));
+    }
+
+    Ok(protocols)
+}
+
+fn protocol_name<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NameOrDisabled {
+        Name(String),
+        Disabled(bool),
+    }
+
+    let name = match NameOrDisabled::deserialize(deserializer)? {
+        NameOrDisabled::Name(name) => name,
+        NameOrDisabled::Disabled(false) => return Ok(None),
+        NameOrDisabled::Disabled(true) => {
+            return Err(D::Error::custom("expected a protocol name or `false`"));
+        }
+    };
+
+    let registry = ProtocolRegistry::builtin();
+
+    if registry.get(&name).is_none() {
+        let known = registry
+            .protocols()
+            .iter()
+            .map(|protocol| protocol.name())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        return Err(D::Error::custom(format!(
+            "unknown protocol {name:?}; expected one of {known}"
+        )));
+    }
+
+    Ok(Some(name))
+}
+
+fn positive<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+    let value = i32::deserialize(deserializer)?;
+
+    if value <= 0 {
+        return Err(D::Error::custom(format!(
+            "expected a positive number, not {value}"
+        )));
+    }
+
+    Ok(value)
+}
+
+fn log_filter<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let filter = String::deserialize(deserializer)?;
+
+    tracing_subscriber::EnvFilter::builder()
+        .parse(&filter)
+        .map_err(|error| D::Error::custom(format!("invalid log filter: {error}")))?;
+
+    Ok(filter)
+}
@@ -0,0 +1,508 @@
+use std::{io, net::SocketAddr, sync::Arc, time::Duration};
+
+use customtale_auth::session::SessionServiceError;
+use customtale_protocol::{
+    capture::CaptureTap,
+    framed::{HytaleDecodeError, HytaleEncodeError},
+    packets::{
+        AnyPacket, Disconnect, DisconnectType, Packet, PacketCategory, PacketDescriptor, Protocol,
+        ProtocolVersion,
+    },
+};
+use futures::SinkExt;
+use quinn::{ApplicationClose, Incoming, ServerConfig, TransportConfig, VarInt};
+use thiserror::Error;
+use tokio::time::{Instant, timeout_at};
+
+use crate::{
+    outbound::OutboundScheduler,
+    router::RouteError,
+    transport::{StreamLayout, TransportRx, TransportTx, negotiate_layout},
+};
+
+// === ConnectionPhase === //
+
+/// The stages a connection goes through, in order. Each phase determines which packet categories
+/// the client may send and how long it may stay in that phase.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum ConnectionPhase {
+    /// Waiting for the client's `Connect`.
+    Connecting,
+    /// Exchanging authorization grants and tokens with the client.
+    Authenticating,
+    /// Waiting for the client to request the world's assets.
+    Setup,
+    /// Sending assets while the client loads the world.
+    Loading,
+    /// The client is in the world.
+    Playing,
+    /// A `Disconnect` has been or is being sent and nothing more will be read.
+    Disconnecting,
+}
+
+impl ConnectionPhase {
+    pub const fn allowed_categories(self) -> PacketCategory {
+        match self {
+            Self::Connecting => PacketCategory::CONNECTION,
+            Self::Authenticating => PacketCategory::CONNECTION.union(PacketCategory::AUTH),
+            Self::Setup => PacketCategory::CONNECTION.union(PacketCategory::SETUP),
+            Self::Loading => PacketCategory::CONNECTION
+                .union(PacketCategory::SETUP)
+                .union(PacketCategory::PLAYER),
+            Self::Playing => PacketCategory::all().difference(PacketCategory::AUTH),
+            Self::Disconnecting => PacketCategory::empty(),
+        }
+    }
+
+    /// How long a connection may remain in this phase before it is dropped.
+    pub const fn timeout(self) -> Option<Duration> {
+        match self {
+            Self::Connecting => Some(Duration::from_secs(10)),
+            // Both sides make round trips to the session service during this phase.
+            Self::Authenticating => Some(Duration::from_secs(30)),
+            Self::Setup => Some(Duration::from_secs(30)),
+            Self::Loading => Some(Duration::from_secs(120)),
+            Self::Playing => None,
+            Self::Disconnecting => Some(Duration::from_secs(5)),
+        }
+    }
+
+    pub const fn can_transition_to(self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (Self::Connecting, Self::Authenticating)
+                // Servers which do not authenticate players skip straight to setup.
+                | (Self::Connecting, Self::Setup)
+                | (Self::Authenticating, Self::Setup)
+                | (Self::Setup, Self::Loading)
+                | (Self::Loading, Self::Playing)
+        ) || (!matches!(self, Self::Disconnecting) && matches!(next, Self::Disconnecting))
+    }
+}
+
+// === Transport === //
+
+/// How long a connection may go without receiving anything before QUIC drops it.
+pub const IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often we ping otherwise quiet clients so that they do not hit [`IDLE_TIMEOUT`].
+pub const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How often playing clients are sent a `Ping`, whose `Pong` reports the client's backlog.
+pub const PING_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The transport settings every client connection is accepted with.
+pub fn transport_config() -> Arc<TransportConfig> {
+    let mut config = TransportConfig::default();
+    config
+        .max_idle_timeout(Some(IDLE_TIMEOUT.try_into().unwrap()))
+        .keep_alive_interval(Some(KEEP_ALIVE_INTERVAL))
+        // Clients may only open streams of their own once they have authenticated. See
+        // `TransportRx::activate_layout`.
+        .max_concurrent_uni_streams(VarInt::from_u32(0));
+
+    Arc::new(config)
+}
+
+/// The QUIC application close code the connection is closed with after sending a `Disconnect` of
+/// type `ty`.
+pub const fn close_code(ty: DisconnectType) -> VarInt {
+    match ty {
+        DisconnectType::Disconnect => VarInt::from_u32(0),
+        DisconnectType::Crash => VarInt::from_u32(1),
+    }
+}
+
+/// The inverse of [`close_code`]. Unknown codes are treated as crashes.
+pub fn disconnect_type(code: VarInt) -> DisconnectType {
+    if code == close_code(DisconnectType::Disconnect) {
+        DisconnectType::Disconnect
+    } else {
+        DisconnectType::Crash
+    }
+}
+
+// === ConnectionError === //
+
+#[derive(Debug, Error)]
+pub enum ConnectionError {
+    #[error("the client closed the connection")]
+    Closed,
+    #[error(
+        "the client disconnected ({ty:?}): {}",
+        reason.as_deref().unwrap_or("no reason given"),
+    )]
+    ClientDisconnected {
+        reason: Option<String>,
+        ty: DisconnectType,
+    },
+    #[error("the connection was lost")]
+    Lost(#[source] quinn::ConnectionError),
+    #[error("the client spent too long in the {phase:?} phase")]
+    Timeout { phase: ConnectionPhase },
+    #[error("the connection cannot move from the {from:?} phase to the {to:?} phase")]
+    InvalidTransition {
+        from: ConnectionPhase,
+        to: ConnectionPhase,
+    },
+    #[error("expected {expected} during the {phase:?} phase but the client sent {descriptor}")]
+    UnexpectedPacket {
+        descriptor: &'static PacketDescriptor,
+        expected: &'static PacketDescriptor,
+        phase: ConnectionPhase,
+    },
+    #[error("the client sent {descriptor} without a {field}")]
+    MissingField {
+        descriptor: &'static PacketDescriptor,
+        field: &'static str,
+    },
+    #[error("unsupported protocol version {0}")]
+    UnsupportedProtocol(ProtocolVersion),
+    #[error("the server is full")]
+    ServerFull,
+    #[error("the server has no game session to authenticate players with")]
+    NotAuthenticated,
+    #[error("failed to authenticate with the session service")]
+    Session(#[from] SessionServiceError),
+    #[error("failed to receive a packet")]
+    Decode(#[source] HytaleDecodeError),
+    #[error("failed to send a packet")]
+    Encode(#[source] HytaleEncodeError),
+    #[error("failed to handle a packet")]
+    Route(#[from] RouteError),
+}
+
+impl ConnectionError {
+    /// The `Disconnect` to send the client for this error, or `None` if the client is already gone.
+    pub fn disconnect(&self) -> Option<Disconnect> {
+        let (reason, ty) = match self {
+            Self::Closed | Self::ClientDisconnected { .. } | Self::Lost(_) => return None,
+            // Our own failures are reported as crashes and without details.
+            Self::InvalidTransition { .. }
+            | Self::NotAuthenticated
+            | Self::Session(_)
+            | Self::Encode(_) => ("Internal server error".to_string(), DisconnectType::Crash),
+            _ => (self.to_string(), DisconnectType::Disconnect),
+        };
+
+        Some(Disconnect {
+            reason: Some(reason.into()),
+            r#type: ty,
+        })
+    }
+
+    /// Whether the connection ended because of the client rather than because of us.
+    pub fn is_client_fault(&self) -> bool {
+        !matches!(
+            self,
+            Self::InvalidTransition { .. }
+                | Self::NotAuthenticated
+                | Self::Session(_)
+                | Self::Encode(_)
+        )
+    }
+}
+
+impl From<quinn::ConnectionError> for ConnectionError {
+    fn from(error: quinn::ConnectionError) -> Self {
+        match error {
+            quinn::ConnectionError::ApplicationClosed(ApplicationClose { error_code, reason }) => {
+                Self::ClientDisconnected {
+                    reason: (!reason.is_empty())
+                        .then(|| String::from_utf8_lossy(&reason).into_owned()),
+                    ty: disconnect_type(error_code),
+                }
+            }
+            error => Self::Lost(error),
+        }
+    }
+}
+
+/// Recovers the QUIC error behind an IO error raised by a stream whose connection went away.
+fn connection_lost(error: &io::Error) -> Option<quinn::ConnectionError> {
+    let inner = error.get_ref()?;
+
+    if let Some(quinn::ReadError::ConnectionLost(error)) = inner.downcast_ref() {
+        return Some(error.clone());
+    }
+
+    if let Some(quinn::WriteError::ConnectionLost(error)) = inner.downcast_ref() {
+        return Some(error.clone());
+    }
+
+    None
+}
+
+impl From<HytaleDecodeError> for ConnectionError {
+    fn from(error: HytaleDecodeError) -> Self {
+        match &error {
+            HytaleDecodeError::Io(io) => {
+                connection_lost(io).map_or(Self::Decode(error), Self::from)
+            }
+            _ => Self::Decode(error),
+        }
+    }
+}
+
+impl From<HytaleEncodeError> for ConnectionError {
+    fn from(error: HytaleEncodeError) -> Self {
+        match &error {
+            HytaleEncodeError::Io(io) => {
+                connection_lost(io).map_or(Self::Encode(error), Self::from)
+            }
+            _ => Self::Encode(error),
+        }
+    }
+}
+
+// === Connection === //
+
+/// The server's end of a client connection, which keeps the packet filter and deadline in step
+/// with the connection's [`ConnectionPhase`].
+#[derive(Debug)]
+pub struct Connection {
+    phase: ConnectionPhase,
+    deadline: Option<Instant>,
+    quic: quinn::Connection,
+    tx: TransportTx,
+    rx: TransportRx,
+    outbound: OutboundScheduler,
+}
+
+impl Connection {
+    /// Completes the QUIC handshake for `incoming` with `server_config` and accepts the primary
+    /// stream the client sends packets over, giving up once the [`ConnectionPhase::Connecting`]
+    /// deadline passes. `layout` is used if the client negotiated a protocol which allows it.
+    pub async fn accept(
+        incoming: Incoming,
+        server_config: Arc<ServerConfig>,
+        layout: &StreamLayout,
+    ) -> Result<Self, ConnectionError> {
+        let phase = ConnectionPhase::Connecting;
+        let deadline = deadline_for(phase).unwrap();
+
+        let accept = async {
+            let quic = incoming.accept_with(server_config)?.await?;
+            let (tx, rx) = quic.accept_bi().await?;
+            Ok::<_, ConnectionError>((quic, tx, rx))
+        };
+
+        let (quic, tx, rx) = timeout_at(deadline, accept)
+            .await
+            .map_err(|_| ConnectionError::Timeout { phase })??;
+
+        let layout = Arc::new(negotiate_layout(&quic, layout));
+
+        Ok(Self {
+            phase,
+            deadline: Some(deadline),
+            tx: TransportTx::new(quic.clone(), layout.clone(), tx),
+            rx: TransportRx::new(quic.clone(), &layout, rx, phase.allowed_categories()),
+            quic,
+            outbound: OutboundScheduler::new(),
+        })
+    }
+
+    pub fn phase(&self) -> ConnectionPhase {
+        self.phase
+    }
+
+    pub fn remote_address(&self) -> SocketAddr {
+        self.quic.remote_address()
+    }
+
+    pub fn outbound(&mut self) -> &mut OutboundScheduler {
+        &mut self.outbound
+    }
+
+    /// Records every frame sent or received from now on through `tap`.
+    pub fn capture(&mut self, tap: CaptureTap) {
+        self.tx.set_tap(tap.clone());
+        self.rx.set_tap(tap);
+    }
+
+    /// Encodes and decodes every packet from now on with `protocol`'s packet table.
+    pub fn set_protocol(&mut self, protocol: &'static Protocol<AnyPacket>) {
+        self.tx.set_protocol(protocol);
+        self.rx.set_protocol(protocol);
+    }
+
+    pub fn transition(&mut self, next: ConnectionPhase) -> Result<(), ConnectionError> {
+        if !self.phase.can_transition_to(next) {
+            return Err(ConnectionError::InvalidTransition {
+                from: self.phase,
+                to: next,
+            });
+        }
+
+        tracing::debug!(from = ?self.phase, to = ?next, "connection changed phase");
+
+        self.phase = next;
+        self.deadline = deadline_for(next);
+        self.rx.set_allowed_categories(next.allowed_categories());
+
+        // Setup is the first phase after the client has authenticated.
+        if next == ConnectionPhase::Setup {
+            self.tx.activate_layout();
+            self.rx.activate_layout();
+        }
+
+        // Setup and loading packets must reach the client in the order they were produced.
+        if next == ConnectionPhase::Playing {
+            self.outbound.queue.set_prioritized(true);
+        }
+
+        Ok(())
+    }
+
+    /// Receives the next packet, failing if the current phase's deadline passes first. Queued
+    /// packets are sent while waiting.
+    pub async fn recv(&mut self) -> Result<AnyPacket, ConnectionError> {
+        let Self {
+            tx, rx, outbound, ..
+        } = self;
+
+        let next = async {
+            loop {
+                tokio::select! {
+                    packet = rx.next() => break Ok::<_, ConnectionError>(packet),
+                    result = outbound.drive(tx), if outbound.has_work() => result?,
+                }
+            }
+        };
+
+        let packet = match self.deadline {
+            Some(deadline) => timeout_at(deadline, next)
+                .await
+                .map_err(|_| ConnectionError::Timeout { phase: self.phase })??,
+            None => next.await?,
+        };
+
+        match packet.ok_or(ConnectionError::Closed)??.into_inner() {
+            AnyPacket::Disconnect(packet) => Err(ConnectionError::ClientDisconnected {
+                reason: packet.reason.map(String::from),
+                ty: packet.r#type,
+            }),
+            packet => Ok(packet),
+        }
+    }
+
+    /// Receives the next packet, failing unless it is a `P`.
+    pub async fn expect<P: Packet + 'static>(&mut self) -> Result<Box<P>, ConnectionError> {
+        self.recv()
+            .await?
+            .downcast::<P>()
+            .map_err(|packet| ConnectionError::UnexpectedPacket {
+                descriptor: packet.descriptor(),
+                expected: P::DESCRIPTOR,
+                phase: self.phase,
+            })
+    }
+
+    /// Queues `packet` to be sent by [`recv`](Self::recv) or [`flush`](Self::flush).
+    pub fn queue(&mut self, packet: impl Into<AnyPacket>) {
+        self.outbound.queue.push(packet);
+    }
+
+    /// Sends `packet` along with everything queued before it.
+    pub async fn send(&mut self, packet: impl Into<AnyPacket>) -> Result<(), ConnectionError> {
+        self.queue(packet);
+        self.flush().await
+    }
+
+    /// Sends everything that is queued.
+    pub async fn flush(&mut self) -> Result<(), ConnectionError> {
+        self.outbound.drain(&mut self.tx).await?;
+        Ok(())
+    }
+
+    /// Ends the connection, logging why it ended and telling the client if it is still there.
+    pub async fn close(mut self, result: Result<(), ConnectionError>) {
+        let remote = self.remote_address();
+        let phase = self.phase;
+
+        let packet = match result {
+            Ok(()) => {
+                tracing::info!(%remote, ?phase, "connection ended");
+
+                Some(Disconnect {
+                    reason: None,
+                    r#type: DisconnectType::Disconnect,
+                })
+            }
+            Err(error) => {
+                if error.is_client_fault() {
+                    tracing::info!(%remote, ?phase, "connection ended: {error}");
+                } else {
+                    tracing::error!(%remote, ?phase, "connection ended: {error}");
+                }
+
+                error.disconnect()
+            }
+        };
+
+        for (descriptor, stats) in self.tx.stats().iter() {
+            tracing::debug!(
+                %remote,
+                packets = stats.packets,
+                uncompressed = stats.uncompressed_bytes,
+                saved = stats.bytes_saved(),
+                "compression of {descriptor}",
+            );
+        }
+
+        match packet {
+            Some(packet) => self.disconnect(packet).await,
+            None => self.quic.close(close_code(DisconnectType::Disconnect), b""),
+        }
+    }
+
+    /// Moves to the [`ConnectionPhase::Disconnecting`] phase, sends `packet`, and waits for the
+    /// client to acknowledge everything we sent before closing the QUIC connection with the
+    /// [`close_code`] for the packet's type. Packets which are still queued are sent first. Waiting
+    /// is abandoned once the phase's deadline passes.
+    pub async fn disconnect(&mut self, packet: Disconnect) {
+        let code = close_code(packet.r#type);
+        let reason = packet.reason.as_deref().unwrap_or("").as_bytes().to_vec();
+
+        // Already disconnecting, so the packet was sent before and only the close remains.
+        if self.transition(ConnectionPhase::Disconnecting).is_err() {
+            self.quic.close(code, &reason);
+            return;
+        }
+
+        let deadline = self.deadline.unwrap();
+        let (tx, outbound) = (&mut self.tx, &mut self.outbound);
+
+        let send = async {
+            outbound.drain(tx).await?;
+
+            // The other streams carry nothing the client needs to see the disconnect, so they
+            // are finished without waiting for acknowledgement.
+            tx.finish_lanes();
+
+            let primary = tx.primary();
+            primary.send(packet.into()).await?;
+
+            let stream = primary.get_mut();
+            if stream.finish().is_ok() {
+                // The client acknowledging our data is all we are waiting for.
+                _ = stream.stopped().await;
+            }
+
+            Ok::<_, HytaleEncodeError>(())
+        };
+
+        match timeout_at(deadline, send).await {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => tracing::debug!("failed to send disconnect: {error}"),
+            Err(_) => tracing::debug!("client did not acknowledge disconnect in time"),
+        }
+
+        self.quic.close(code, &reason);
+    }
+}
+
+fn deadline_for(phase: ConnectionPhase) -> Option<Instant> {
+    phase.timeout().map(|timeout| Instant::now() + timeout)
+}
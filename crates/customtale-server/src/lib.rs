@@ -0,0 +1,6 @@
+pub mod config;
+pub mod connection;
+pub mod outbound;
+pub mod router;
+pub mod tls;
+pub mod transport;
@@ -1,57 +1,118 @@
 use std::{
-    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
-    sync::Arc,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::{SystemTime, UNIX_EPOCH},
 };
 
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
 use customtale_auth::{
-    fingerprint::compute_certificate_fingerprint,
     manager::{ServerAuthCredentials, ServerAuthManager},
-    oauth::OAuthBrowserFlow,
+    oauth::{OAuthBrowserFlow, OAuthDeviceFlow},
     session::SessionService,
 };
 use customtale_protocol::{
+    capture::CaptureTap,
     packets::{
-        AnyPacket, AuthGrant, ItemCategory, ItemGridInfoDisplayMode, PacketCategory,
-        ServerAuthToken, UpdateAmbienceFX, UpdateAudioCategories, UpdateBlockBreakingDecals,
-        UpdateBlockGroups, UpdateBlockHitboxes, UpdateBlockParticleSets, UpdateBlockSets,
-        UpdateBlockSoundSets, UpdateBlockTypes, UpdateCameraShake, UpdateEntityEffects,
-        UpdateEntityStatTypes, UpdateEntityUIComponents, UpdateEnvironments,
-        UpdateEqualizerEffects, UpdateFieldcraftCategories, UpdateFluidFX, UpdateFluids,
-        UpdateHitboxCollisionConfig, UpdateInteractions, UpdateItemCategories,
+        AnyPacket, AuthGrant, AuthToken, ClientReady, Connect, ConnectAccept, InstantData,
+        ItemCategory, ItemGridInfoDisplayMode, Packet, Ping, PlayerOptions, Pong, ProtocolRegistry,
+        ProtocolVersion, RequestAssets, ServerAuthToken, ServerInfo, UpdateAmbienceFX,
+        UpdateAudioCategories, UpdateBlockBreakingDecals, UpdateBlockGroups, UpdateBlockHitboxes,
+        UpdateBlockParticleSets, UpdateBlockSets, UpdateBlockSoundSets, UpdateBlockTypes,
+        UpdateCameraShake, UpdateEntityEffects, UpdateEntityStatTypes, UpdateEntityUIComponents,
+        UpdateEnvironments, UpdateEqualizerEffects, UpdateFieldcraftCategories, UpdateFluidFX,
+        UpdateFluids, UpdateHitboxCollisionConfig, UpdateInteractions, UpdateItemCategories,
         UpdateItemPlayerAnimations, UpdateItemQualities, UpdateItemReticles, UpdateItemSoundSets,
         UpdateModelvfxs, UpdateParticleSpawners, UpdateParticleSystems, UpdateRecipes,
         UpdateRepulsionConfig, UpdateResourceTypes, UpdateReverbEffects, UpdateRootInteractions,
         UpdateSoundEvents, UpdateSoundSets, UpdateTagPatterns, UpdateTrails, UpdateTranslations,
-        UpdateType, UpdateUnarmedInteractions, UpdateWeathers, WorldLoadFinished,
+        UpdateType, UpdateUnarmedInteractions, UpdateWeathers, ViewRadius, WorldLoadFinished,
         WorldLoadProgress, WorldSettings,
     },
-    serde::Dictionary,
+    serde::{ByteString, Dictionary},
 };
-use futures::{SinkExt, StreamExt};
-use miette::IntoDiagnostic;
-use quinn::{
-    crypto::rustls::QuicServerConfig,
-    rustls::{
-        self,
-        pki_types::{CertificateDer, PrivatePkcs8KeyDer},
-    },
+use customtale_server::{
+    config::{AuthMode, Config, ConfigError, OAuthFlowKind},
+    connection::{self, Connection, ConnectionError, ConnectionPhase},
+    outbound::BandwidthLimit,
+    router::{PacketRouter, UnhandledPolicy},
+    tls::{CertificateStore, CertificateStoreError, ServerCertificate},
+    transport::StreamLayout,
 };
+use futures::future::join_all;
+use miette::{IntoDiagnostic, WrapErr};
+use quinn::{crypto::rustls::QuicServerConfig, rustls};
 use rustls::crypto::CryptoProvider;
-use tokio_util::codec::Framed;
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::time::{Instant, MissedTickBehavior};
 use tracing_subscriber::util::SubscriberInitExt;
 
-use crate::framed::{HytaleDecoder, HytaleEncoder};
+fn command() -> Command {
+    Command::new("customtale-server")
+        .about("Runs a CustomTale server")
+        .arg(
+            Arg::new("config")
+                .short('c')
+                .long("config")
+                .value_name("PATH")
+                .value_parser(value_parser!(PathBuf))
+                .help(format!(
+                    "The configuration file to load. Defaults to `{DEFAULT_CONFIG_PATH}` if it \
+                     exists"
+                )),
+        )
+        .arg(
+            Arg::new("set")
+                .short('s')
+                .long("set")
+                .value_name("KEY=VALUE")
+                .action(ArgAction::Append)
+                .help("Overrides a configuration key, e.g. `network.bind=[\"[::]:5520\"]`"),
+        )
+        .arg(
+            Arg::new("check")
+                .long("check")
+                .action(ArgAction::SetTrue)
+                .help("Validates the configuration and exits"),
+        )
+}
 
-pub mod framed;
+const DEFAULT_CONFIG_PATH: &str = "customtale.toml";
+
+fn load_config(matches: &ArgMatches) -> Result<Config, ConfigError> {
+    let overrides = matches
+        .get_many::<String>("set")
+        .unwrap_or_default()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    match matches.get_one::<PathBuf>("config") {
+        Some(path) => Config::load(path, &overrides),
+        None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+            Config::load(Path::new(DEFAULT_CONFIG_PATH), &overrides)
+        }
+        None => Config::parse("<defaults>".to_string(), String::new(), &overrides),
+    }
+}
 
 // TODO: Implement actual authentication and socket handling.
 #[tokio::main]
 async fn main() -> miette::Result<()> {
+    let matches = command().get_matches();
+    let config = Arc::new(load_config(&matches)?);
+
+    if matches.get_flag("check") {
+        println!("The configuration is valid");
+        return Ok(());
+    }
+
     tracing_subscriber::fmt::Subscriber::builder()
         .with_env_filter(
-            tracing_subscriber::EnvFilter::builder()
-                .with_default_directive("INFO".parse().unwrap())
-                .from_env_lossy(),
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(&config.log.filter)),
         )
         .finish()
         .try_init()
@@ -64,636 +125,852 @@ async fn main() -> miette::Result<()> {
     let session_service = SessionService::new()?;
     let auth_manager = ServerAuthManager::new(session_service.clone());
 
-    let flow = OAuthBrowserFlow::start(session_service.clone()).await?;
+    match config.auth.mode {
+        AuthMode::Authenticated => {
+            let oauth = match config.auth.oauth {
+                OAuthFlowKind::Browser => {
+                    let flow = OAuthBrowserFlow::start(session_service.clone()).await?;
+                    tracing::info!("OAuth path: {}", flow.auth_url());
+                    flow.finished().await?
+                }
+                OAuthFlowKind::Device => {
+                    let flow = OAuthDeviceFlow::start(session_service.clone()).await?;
+                    tracing::info!(
+                        "Visit {} and enter the code {}",
+                        flow.verification_uri(),
+                        flow.verification_code(),
+                    );
+                    flow.finished().await?
+                }
+            };
 
-    tracing::info!("OAuth path: {}", flow.auth_url());
+            auth_manager
+                .provide_credentials(ServerAuthCredentials {
+                    oauth: Some(oauth),
+                    session: None,
+                })
+                .await;
+        }
+        AuthMode::Offline => tracing::warn!("Players are not authenticated in offline mode"),
+    }
 
-    let oauth = flow.finished().await?;
+    // TODO: com/hypixel/hytale/server/core/io/transport/QUICTransport.java
+    let certificates = Arc::new(
+        CertificateStore::open(
+            config.paths.resolve(&config.paths.tls_cert),
+            config.paths.resolve(&config.paths.tls_key),
+            &["localhost".to_string()],
+            CryptoProvider::get_default().unwrap().clone(),
+        )
+        .into_diagnostic()?,
+    );
+
+    tracing::info!("Certificate fingerprint {}", certificates.fingerprint());
+
+    #[cfg(unix)]
+    reload_certificates_on_hangup(certificates.clone()).into_diagnostic()?;
+
+    let server_configs = ServerConfigs {
+        certificates: certificates.clone(),
+        alpn: config.network.offered_alpn(),
+        cached: Mutex::new(None),
+    };
+
+    // Connections are accepted with the configuration for the certificate current at the time,
+    // so the endpoints' own configuration is never used for handshakes.
+    let (_, server_config) = server_configs.current().into_diagnostic()?;
+
+    let mut protocols = ProtocolRegistry::builtin();
+
+    // The name was checked against the builtin tables when the configuration was loaded.
+    protocols.set_fallback(
+        config
+            .network
+            .protocol_fallback
+            .as_deref()
+            .and_then(|name| protocols.get(name)),
+    );
+
+    if !protocols.is_selectable() {
+        miette::bail!(
+            help = "set `network.protocol_fallback` to the table unknown clients should speak",
+            "no client could select a protocol: no table knows its version and there is no \
+             fallback",
+        );
+    }
 
-    auth_manager
-        .provide_credentials(ServerAuthCredentials {
-            oauth: Some(oauth),
-            session: None,
+    let endpoints = config
+        .network
+        .bind
+        .iter()
+        .map(|&addr| {
+            bind_endpoint(addr, config.network.dual_stack, (*server_config).clone())
+                .into_diagnostic()
+                .wrap_err_with(|| format!("failed to listen on {addr}"))
         })
-        .await;
+        .collect::<miette::Result<Vec<_>>>()?;
+
+    let state = Arc::new(ServerState {
+        protocols,
+        router: setup_router(),
+        session_service,
+        auth_manager,
+        server_configs,
+        capture_dir: config
+            .paths
+            .capture_dir
+            .as_deref()
+            .map(|dir| config.paths.resolve(dir)),
+        bandwidth_limit: config
+            .network
+            .bandwidth_limit
+            .map(|bytes_per_second| BandwidthLimit {
+                bytes_per_second,
+                burst: bytes_per_second,
+            }),
+        stream_layout: config.network.stream_layout.layout(),
+        players: PlayerSlots::new(config.server.max_players),
+        config,
+    });
+
+    for protocol in state.protocols.protocols() {
+        tracing::info!("Supporting protocol {protocol}");
+    }
 
-    // TODO: com/hypixel/hytale/server/core/io/transport/QUICTransport.java
-    let ssc =
-        rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).into_diagnostic()?;
+    if let Some(protocol) = state.protocols.fallback() {
+        tracing::info!("Serving protocol {protocol} to clients of unknown versions");
+    }
 
-    let cert_der = CertificateDer::from(ssc.cert);
-    let key = PrivatePkcs8KeyDer::from(ssc.signing_key.serialize_der());
+    if let Some(dir) = &state.capture_dir {
+        tracing::info!("Capturing connections into {}", dir.display());
+    }
 
-    let cert_fingerprint = Arc::new(compute_certificate_fingerprint(&cert_der));
+    for endpoint in &endpoints {
+        tracing::info!("Listening on {}", endpoint.local_addr().into_diagnostic()?);
+    }
 
-    let mut tls_server_config =
-        rustls::ServerConfig::builder_with_provider(CryptoProvider::get_default().unwrap().clone())
-            .with_protocol_versions(&[&rustls::version::TLS13])
-            .unwrap()
-            .with_no_client_auth()
-            .with_single_cert(vec![cert_der], key.into())
-            .unwrap();
+    join_all(
+        endpoints
+            .into_iter()
+            .map(|endpoint| accept_connections(endpoint, state.clone())),
+    )
+    .await;
 
-    tls_server_config.alpn_protocols = vec![b"hytale/2".to_vec(), b"hytale/1".to_vec()];
+    Ok(())
+}
 
-    let suite = tls_server_config
-        .crypto_provider()
-        .cipher_suites
-        .iter()
-        .find_map(|cs| match (cs.suite(), cs.tls13()) {
-            (rustls::CipherSuite::TLS13_AES_128_GCM_SHA256, Some(suite)) => {
-                Some(suite.quic_suite())
-            }
-            _ => None,
-        })
-        .flatten();
+/// Binds a QUIC endpoint to `addr`. IPv6 addresses accept IPv4 clients only if `dual_stack` is
+/// set.
+fn bind_endpoint(
+    addr: SocketAddr,
+    dual_stack: bool,
+    server_config: quinn::ServerConfig,
+) -> std::io::Result<quinn::Endpoint> {
+    let socket = Socket::new(Domain::for_address(addr), Type::DGRAM, Some(Protocol::UDP))?;
+
+    if addr.is_ipv6() {
+        socket.set_only_v6(!dual_stack)?;
+    }
 
-    let crypto =
-        QuicServerConfig::with_initial(Arc::new(tls_server_config), suite.unwrap()).unwrap();
-    let crypto = Arc::new(crypto);
+    socket.bind(&addr.into())?;
 
-    let endpoint = quinn::Endpoint::server(
-        quinn::ServerConfig::with_crypto(crypto),
-        SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 5520)),
+    quinn::Endpoint::new(
+        quinn::EndpointConfig::default(),
+        Some(server_config),
+        socket.into(),
+        Arc::new(quinn::TokioRuntime),
     )
-    .into_diagnostic()?;
+}
 
+async fn accept_connections(endpoint: quinn::Endpoint, state: Arc<ServerState>) {
     while let Some(incoming) = endpoint.accept().await {
-        let session_service = session_service.clone();
-        let auth_manager = auth_manager.clone();
-        let cert_fingerprint = cert_fingerprint.clone();
+        let state = state.clone();
 
         tokio::spawn(async move {
-            let conn = incoming.await.unwrap();
-
-            let (tx, rx) = conn.accept_bi().await.unwrap();
-
             // com/hypixel/hytale/server/core/io/netty/HytaleChannelInitializer.java
             // com/hypixel/hytale/protocol/io/netty/PacketDecoder.java
             // com/hypixel/hytale/protocol/io/netty/PacketEncoder.java
 
-            let mut tx = Framed::new(tx, HytaleEncoder);
-            let mut rx = Framed::new(
-                rx,
-                HytaleDecoder {
-                    allowed_categories: PacketCategory::CONNECTION,
-                },
-            );
+            let remote = incoming.remote_address();
 
-            let Some(packet1) = rx.next().await else {
-                return;
+            let (certificate, server_config) = match state.server_configs.current() {
+                Ok(current) => current,
+                Err(error) => {
+                    tracing::error!(%remote, "failed to configure TLS: {error}");
+                    incoming.refuse();
+                    return;
+                }
             };
 
-            let AnyPacket::Connect(packet1) = packet1.unwrap() else {
-                panic!("what?");
-            };
+            let mut connection =
+                match Connection::accept(incoming, server_config, &state.stream_layout).await {
+                    Ok(connection) => connection,
+                    Err(error) => {
+                        tracing::info!(%remote, "connection failed to open: {error}");
+                        return;
+                    }
+                };
+
+            if let Some(dir) = &state.capture_dir {
+                start_capture(dir, &mut connection);
+            }
+
+            connection
+                .outbound()
+                .set_bandwidth_limit(state.bandwidth_limit);
+
+            let result = handle_connection(&state, &mut connection, &certificate).await;
+            connection.close(result).await;
+        });
+    }
+}
+
+/// Reloads the certificate store whenever the process receives `SIGHUP`. Connections which are
+/// already open are unaffected.
+#[cfg(unix)]
+fn reload_certificates_on_hangup(certificates: Arc<CertificateStore>) -> std::io::Result<()> {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut hangup = signal(SignalKind::hangup())?;
+
+    tokio::spawn(async move {
+        while hangup.recv().await.is_some() {
+            match certificates.reload() {
+                Ok(certificate) => tracing::info!(
+                    "Reloaded certificate, fingerprint {}",
+                    certificate.fingerprint,
+                ),
+                Err(error) => tracing::error!("failed to reload certificate: {error}"),
+            }
+        }
+    });
 
-            let server_credentials = auth_manager.credentials();
-            let server_credentials = server_credentials.session.as_ref().unwrap();
-
-            let grant = session_service
-                .request_authorization_grant(
-                    packet1.identityToken.as_ref().unwrap(),
-                    auth_manager.audience(),
-                    &server_credentials.session_token,
-                )
-                .await
-                .unwrap();
-
-            tx.send(
-                AuthGrant {
-                    authorizationGrant: Some(grant.clone()),
-                    serverIdentityToken: Some(server_credentials.identity_token.clone()),
+    Ok(())
+}
+
+/// Builds the QUIC configuration each connection is accepted with, which serves the certificate
+/// current when the connection arrived. A connection's certificate therefore cannot change under
+/// it if the store is reloaded during its handshake.
+struct ServerConfigs {
+    certificates: Arc<CertificateStore>,
+    alpn: Vec<Vec<u8>>,
+    /// The configuration built for the most recently served certificate.
+    cached: Mutex<Option<(Arc<ServerCertificate>, Arc<quinn::ServerConfig>)>>,
+}
+
+impl ServerConfigs {
+    fn current(
+        &self,
+    ) -> Result<(Arc<ServerCertificate>, Arc<quinn::ServerConfig>), CertificateStoreError> {
+        let certificate = self.certificates.current();
+        let mut cached = self.cached.lock().unwrap();
+
+        if let Some((served, config)) = &*cached
+            && Arc::ptr_eq(served, &certificate)
+        {
+            return Ok((certificate, config.clone()));
+        }
+
+        let mut tls_server_config = self.certificates.server_config(certificate.clone())?;
+        tls_server_config.alpn_protocols = self.alpn.clone();
+
+        let suite = tls_server_config
+            .crypto_provider()
+            .cipher_suites
+            .iter()
+            .find_map(|cs| match (cs.suite(), cs.tls13()) {
+                (rustls::CipherSuite::TLS13_AES_128_GCM_SHA256, Some(suite)) => {
+                    Some(suite.quic_suite())
                 }
-                .into(),
-            )
-            .await
-            .unwrap();
+                _ => None,
+            })
+            .flatten();
 
-            rx.codec_mut().allowed_categories |= PacketCategory::AUTH;
+        let crypto =
+            QuicServerConfig::with_initial(Arc::new(tls_server_config), suite.unwrap()).unwrap();
 
-            let Some(packet2) = rx.next().await else {
-                return;
-            };
+        let mut server_config = quinn::ServerConfig::with_crypto(Arc::new(crypto));
+        server_config.transport_config(connection::transport_config());
 
-            let AnyPacket::AuthToken(packet2) = packet2.unwrap() else {
-                panic!("what?");
-            };
+        let server_config = Arc::new(server_config);
+        *cached = Some((certificate.clone(), server_config.clone()));
 
-            dbg!(&packet2);
+        Ok((certificate, server_config))
+    }
+}
+
+struct ServerState {
+    protocols: ProtocolRegistry<AnyPacket>,
+    router: PacketRouter<Session>,
+    session_service: SessionService,
+    auth_manager: ServerAuthManager,
+    server_configs: ServerConfigs,
+    /// The directory every connection is captured into, if any.
+    capture_dir: Option<PathBuf>,
+    bandwidth_limit: Option<BandwidthLimit>,
+    /// The layout used for clients which support more than one stream.
+    stream_layout: StreamLayout,
+    players: PlayerSlots,
+    config: Arc<Config>,
+}
+
+/// Counts the players connected to the server against its limit.
+struct PlayerSlots {
+    taken: AtomicU32,
+    max: u32,
+}
+
+impl PlayerSlots {
+    fn new(max: u32) -> Self {
+        Self {
+            taken: AtomicU32::new(0),
+            max,
+        }
+    }
 
-            let server_access_token = session_service
-                .exchange_auth_grant_for_token(
-                    packet2.serverAuthorizationGrant.as_ref().unwrap(),
-                    &cert_fingerprint,
-                    &server_credentials.session_token,
-                )
-                .await
-                .unwrap();
+    /// Takes a slot until the returned guard is dropped, or returns `None` if the server is full.
+    fn claim(&self) -> Option<PlayerSlot<'_>> {
+        self.taken
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |taken| {
+                (taken < self.max).then_some(taken + 1)
+            })
+            .ok()?;
 
-            tx.send(
-                ServerAuthToken {
-                    serverAccessToken: Some(server_access_token),
+        Some(PlayerSlot(&self.taken))
+    }
+}
+
+struct PlayerSlot<'a>(&'a AtomicU32);
+
+impl Drop for PlayerSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+fn start_capture(dir: &Path, connection: &mut Connection) {
+    let started = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+
+    let remote = connection.remote_address();
+    // IPv6 addresses contain colons, which not every file system allows.
+    let host = remote.ip().to_string().replace(':', "_");
+    let path = dir.join(format!("{started}-{host}-{}.ctcap", remote.port()));
+
+    match CaptureTap::create(&path) {
+        Ok(tap) => {
+            tracing::info!(%remote, "capturing connection into {}", path.display());
+            connection.capture(tap);
+        }
+        Err(error) => {
+            tracing::warn!(%remote, "failed to create capture {}: {error}", path.display())
+        }
+    }
+}
+
+/// Runs a connection which was served `certificate` during its handshake.
+async fn handle_connection(
+    state: &ServerState,
+    connection: &mut Connection,
+    certificate: &ServerCertificate,
+) -> Result<(), ConnectionError> {
+    let connect = connection.expect::<Connect>().await?;
+
+    let version = ProtocolVersion {
+        crc: connect.protocolCrc,
+        build_number: connect.protocolBuildNumber,
+    };
+
+    let Some(protocol) = state.protocols.select(version) else {
+        return Err(ConnectionError::UnsupportedProtocol(version));
+    };
+
+    tracing::info!(%version, "client selected protocol {protocol}");
+
+    connection.set_protocol(protocol);
+
+    let _slot = state.players.claim().ok_or(ConnectionError::ServerFull)?;
+
+    match state.config.auth.mode {
+        AuthMode::Authenticated => {
+            authenticate(state, connection, &connect, &certificate.fingerprint).await?;
+        }
+        AuthMode::Offline => {
+            connection
+                .send(ConnectAccept {
                     passwordChallenge: None,
-                }
-                .into(),
-            )
-            .await
-            .unwrap();
-
-            // We've authenticated!
-            // com/hypixel/hytale/server/core/io/handlers/SetupPacketHandler.java
-            tracing::info!("Authenticated!");
-            rx.codec_mut().allowed_categories |= PacketCategory::SETUP;
-
-            tx.send(
-                WorldSettings {
-                    worldHeight: 320,
-                    requiredAssets: Some(Vec::new()),
-                }
-                .into(),
-            )
-            .await
-            .unwrap();
+                })
+                .await?;
+        }
+    }
 
-            loop {
-                let Some(packet3) = rx.next().await else {
-                    return;
-                };
+    // com/hypixel/hytale/server/core/io/handlers/SetupPacketHandler.java
+    connection.transition(ConnectionPhase::Setup)?;
 
-                match packet3.unwrap() {
-                    AnyPacket::RequestAssets(_) => {
-                        tx.send(
-                            UpdateAmbienceFX {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                ambienceFX: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateAudioCategories {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                categories: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateBlockBreakingDecals {
-                                r#type: UpdateType::Init,
-                                blockBreakingDecals: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateBlockGroups {
-                                r#type: UpdateType::Init,
-                                groups: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateBlockHitboxes {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                blockBaseHitboxes: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateBlockParticleSets {
-                                r#type: UpdateType::Init,
-                                blockParticleSets: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateBlockTypes {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                blockTypes: Some(Dictionary::default()),
-                                updateBlockTextures: true,
-                                updateModelTextures: true,
-                                updateModels: true,
-                                updateMapGeometry: true,
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateCameraShake {
-                                r#type: UpdateType::Init,
-                                profiles: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateEntityEffects {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                entityEffects: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateEntityStatTypes {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                types: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateEnvironments {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                environments: Some(Dictionary::default()),
-                                rebuildMapGeometry: true,
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateEqualizerEffects {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                effects: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateFieldcraftCategories {
-                                r#type: UpdateType::Init,
-                                itemCategories: Some(Vec::new()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateFluidFX {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                fluidFX: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateFluids {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                fluids: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateHitboxCollisionConfig {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                hitboxCollisionConfigs: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateItemCategories {
-                                r#type: UpdateType::Init,
-                                itemCategories: Some(vec![ItemCategory {
-                                    id: Some("Blocks".to_string()),
-                                    name: Some("Blocks".to_string()),
-                                    icon: Some("Icons/ItemCategories/Natural.png".to_string()),
-                                    order: 0,
-                                    infoDisplayMode: ItemGridInfoDisplayMode::None,
-                                    children: Some(vec![ItemCategory {
-                                        id: Some("Rocks".to_string()),
-                                        name: Some("server.ui.itemcategory.rocks".to_string()),
-                                        icon: Some("Icons/ItemCategories/Blocks.png".to_string()),
-                                        order: 0,
-                                        infoDisplayMode: ItemGridInfoDisplayMode::None,
-                                        children: None,
-                                    }]),
-                                }]),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateItemPlayerAnimations {
-                                r#type: UpdateType::Init,
-                                itemPlayerAnimations: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateItemQualities {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                itemQualities: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateItemReticles {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                itemReticleConfigs: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateParticleSpawners {
-                                r#type: UpdateType::Init,
-                                particleSpawners: Some(Dictionary::default()),
-                                removedParticleSpawners: Some(Vec::new()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateParticleSystems {
-                                r#type: UpdateType::Init,
-                                particleSystems: Some(Dictionary::default()),
-                                removedParticleSystems: Some(Vec::new()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateResourceTypes {
-                                r#type: UpdateType::Init,
-                                resourceTypes: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateWeathers {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                weathers: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateTranslations {
-                                r#type: UpdateType::Init,
-                                translations: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateTrails {
-                                r#type: UpdateType::Init,
-                                trails: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateSoundEvents {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                soundEvents: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateRootInteractions {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                interactions: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateUnarmedInteractions {
-                                r#type: UpdateType::Init,
-                                interactions: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateBlockSoundSets {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                blockSoundSets: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateRepulsionConfig {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                repulsionConfigs: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateModelvfxs {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                modelVFXs: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateEntityUIComponents {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                components: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateSoundSets {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                soundSets: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateBlockSets {
-                                r#type: UpdateType::Init,
-                                blockSets: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateRecipes {
-                                r#type: UpdateType::Init,
-                                recipes: Some(Dictionary::default()),
-                                removedRecipes: Some(Vec::new()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateTagPatterns {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                patterns: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateItemSoundSets {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                itemSoundSets: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateReverbEffects {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                effects: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            UpdateInteractions {
-                                r#type: UpdateType::Init,
-                                maxId: 0,
-                                interactions: Some(Dictionary::default()),
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(
-                            WorldLoadProgress {
-                                status: Some("Meowing".to_string()),
-                                percentComplete: 50,
-                                percentCompleteSubitem: 0,
-                            }
-                            .into(),
-                        )
-                        .await
-                        .unwrap();
-
-                        tx.send(WorldLoadFinished {}.into()).await.unwrap();
-                    }
-                    AnyPacket::ViewRadius(_) => {}
-                    AnyPacket::PlayerOptions(_) => {}
-                    AnyPacket::Disconnect(_) => {}
-                    _ => unreachable!(),
-                }
+    connection
+        .send(WorldSettings {
+            worldHeight: state.config.world.height,
+            requiredAssets: Some(Vec::new()),
+        })
+        .await?;
+
+    let mut session = Session {
+        config: state.config.clone(),
+        outbound: Vec::new(),
+        next_phase: None,
+        packet_queue_size: 0,
+        next_ping_id: 0,
+        pending_ping: None,
+    };
+
+    let mut ping_interval = tokio::time::interval(connection::PING_INTERVAL);
+    ping_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        let playing = connection.phase() == ConnectionPhase::Playing;
+
+        let packet = tokio::select! {
+            packet = connection.recv() => packet?,
+            _ = ping_interval.tick(), if playing => {
+                connection.queue(session.ping());
+                continue;
             }
+        };
 
-            // tx.get_mut().finish().unwrap();
-            // tx.get_mut().stopped().await.unwrap();
-        });
+        session.packet_queue_size = connection.outbound().queue.packet_queue_size();
+
+        state
+            .router
+            .dispatch(&mut session, connection.phase(), packet)?;
+
+        for packet in session.outbound.drain(..) {
+            connection.queue(packet);
+        }
+
+        if let Some(phase) = session.next_phase.take() {
+            connection.transition(phase)?;
+        }
     }
+}
+
+async fn authenticate(
+    state: &ServerState,
+    connection: &mut Connection,
+    connect: &Connect,
+    cert_fingerprint: &str,
+) -> Result<(), ConnectionError> {
+    connection.transition(ConnectionPhase::Authenticating)?;
+
+    let server_credentials = state.auth_manager.credentials();
+    let server_credentials = server_credentials
+        .session
+        .as_ref()
+        .ok_or(ConnectionError::NotAuthenticated)?;
+
+    let identity_token = connect
+        .identityToken
+        .as_ref()
+        .ok_or(ConnectionError::MissingField {
+            descriptor: Connect::DESCRIPTOR,
+            field: "identityToken",
+        })?;
+
+    let grant = state
+        .session_service
+        .request_authorization_grant(
+            identity_token,
+            state.auth_manager.audience(),
+            &server_credentials.session_token,
+        )
+        .await?;
+
+    connection
+        .send(AuthGrant {
+            authorizationGrant: Some(grant.into()),
+            serverIdentityToken: Some(server_credentials.identity_token.as_str().into()),
+        })
+        .await?;
+
+    let auth_token = connection.expect::<AuthToken>().await?;
+
+    let server_authorization_grant =
+        auth_token
+            .serverAuthorizationGrant
+            .as_ref()
+            .ok_or(ConnectionError::MissingField {
+                descriptor: AuthToken::DESCRIPTOR,
+                field: "serverAuthorizationGrant",
+            })?;
+
+    let server_access_token = state
+        .session_service
+        .exchange_auth_grant_for_token(
+            server_authorization_grant,
+            cert_fingerprint,
+            &server_credentials.session_token,
+        )
+        .await?;
+
+    connection
+        .send(ServerAuthToken {
+            serverAccessToken: Some(server_access_token.into()),
+            passwordChallenge: None,
+        })
+        .await?;
+
+    tracing::info!("Authenticated!");
 
     Ok(())
 }
+
+struct Session {
+    config: Arc<Config>,
+    outbound: Vec<AnyPacket>,
+    /// The phase to move the connection to once the current packet has been handled.
+    next_phase: Option<ConnectionPhase>,
+    /// Our backlog when the current packet arrived, in the form `Pong` reports it.
+    packet_queue_size: i16,
+    next_ping_id: i32,
+    /// The ID of the last `Ping` and when it was sent, until the client answers it.
+    pending_ping: Option<(i32, Instant)>,
+}
+
+impl Session {
+    fn send(&mut self, packet: impl Into<AnyPacket>) {
+        self.outbound.push(packet.into());
+    }
+
+    fn ping(&mut self) -> Ping {
+        let id = self.next_ping_id;
+        self.next_ping_id = id.wrapping_add(1);
+        self.pending_ping = Some((id, Instant::now()));
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Ping {
+            id,
+            time: Some(InstantData {
+                seconds: now.as_secs() as i64,
+                nanos: now.subsec_nanos() as i32,
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+fn setup_router() -> PacketRouter<Session> {
+    let mut router = PacketRouter::new();
+
+    router
+        .unhandled(UnhandledPolicy::Log)
+        .on::<RequestAssets>(|session, _| {
+            send_initial_assets(session);
+            session.next_phase = Some(ConnectionPhase::Loading);
+            Ok(())
+        })
+        .on::<ClientReady>(|session, packet| {
+            if packet.readyForGameplay {
+                session.next_phase = Some(ConnectionPhase::Playing);
+
+                let server = &session.config.server;
+                let info = ServerInfo {
+                    serverName: Some(server.name.as_str().into()),
+                    motd: Some(server.motd.as_str().into()),
+                    maxPlayers: server.max_players.try_into().unwrap_or(i32::MAX),
+                };
+                session.send(info);
+            }
+            Ok(())
+        })
+        .on::<ViewRadius>(|session, packet| {
+            let allowed = session.config.world.view_radius.clamp(packet.value);
+
+            // Tell the client what it will actually get if it asked for too much or too little.
+            if allowed != packet.value {
+                session.send(ViewRadius { value: allowed });
+            }
+            Ok(())
+        })
+        .on::<PlayerOptions>(|_, _| Ok(()))
+        .on::<Pong>(|session, packet| {
+            // Clients answer each ping once per `PongType`.
+            let Some((id, sent_at)) = session.pending_ping else {
+                return Ok(());
+            };
+
+            if packet.id == id {
+                session.pending_ping = None;
+
+                tracing::debug!(
+                    round_trip = ?sent_at.elapsed(),
+                    client_queue = packet.packetQueueSize,
+                    server_queue = session.packet_queue_size,
+                    "client answered ping",
+                );
+            }
+            Ok(())
+        });
+
+    router
+}
+
+fn send_initial_assets(session: &mut Session) {
+    session.send(UpdateAmbienceFX {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        ambienceFX: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateAudioCategories {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        categories: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateBlockBreakingDecals {
+        r#type: UpdateType::Init,
+        blockBreakingDecals: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateBlockGroups {
+        r#type: UpdateType::Init,
+        groups: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateBlockHitboxes {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        blockBaseHitboxes: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateBlockParticleSets {
+        r#type: UpdateType::Init,
+        blockParticleSets: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateBlockTypes {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        blockTypes: Some(Dictionary::default()),
+        updateBlockTextures: true,
+        updateModelTextures: true,
+        updateModels: true,
+        updateMapGeometry: true,
+    });
+
+    session.send(UpdateCameraShake {
+        r#type: UpdateType::Init,
+        profiles: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateEntityEffects {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        entityEffects: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateEntityStatTypes {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        types: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateEnvironments {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        environments: Some(Dictionary::default()),
+        rebuildMapGeometry: true,
+    });
+
+    session.send(UpdateEqualizerEffects {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        effects: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateFieldcraftCategories {
+        r#type: UpdateType::Init,
+        itemCategories: Some(Vec::new()),
+    });
+
+    session.send(UpdateFluidFX {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        fluidFX: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateFluids {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        fluids: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateHitboxCollisionConfig {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        hitboxCollisionConfigs: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateItemCategories {
+        r#type: UpdateType::Init,
+        itemCategories: Some(vec![ItemCategory {
+            id: Some(ByteString::from_static("Blocks")),
+            name: Some(ByteString::from_static("Blocks")),
+            icon: Some(ByteString::from_static("Icons/ItemCategories/Natural.png")),
+            order: 0,
+            infoDisplayMode: ItemGridInfoDisplayMode::None,
+            children: Some(vec![ItemCategory {
+                id: Some(ByteString::from_static("Rocks")),
+                name: Some(ByteString::from_static("server.ui.itemcategory.rocks")),
+                icon: Some(ByteString::from_static("Icons/ItemCategories/Blocks.png")),
+                order: 0,
+                infoDisplayMode: ItemGridInfoDisplayMode::None,
+                children: None,
+            }]),
+        }]),
+    });
+
+    session.send(UpdateItemPlayerAnimations {
+        r#type: UpdateType::Init,
+        itemPlayerAnimations: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateItemQualities {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        itemQualities: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateItemReticles {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        itemReticleConfigs: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateParticleSpawners {
+        r#type: UpdateType::Init,
+        particleSpawners: Some(Dictionary::default()),
+        removedParticleSpawners: Some(Vec::new()),
+    });
+
+    session.send(UpdateParticleSystems {
+        r#type: UpdateType::Init,
+        particleSystems: Some(Dictionary::default()),
+        removedParticleSystems: Some(Vec::new()),
+    });
+
+    session.send(UpdateResourceTypes {
+        r#type: UpdateType::Init,
+        resourceTypes: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateWeathers {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        weathers: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateTranslations {
+        r#type: UpdateType::Init,
+        translations: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateTrails {
+        r#type: UpdateType::Init,
+        trails: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateSoundEvents {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        soundEvents: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateRootInteractions {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        interactions: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateUnarmedInteractions {
+        r#type: UpdateType::Init,
+        interactions: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateBlockSoundSets {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        blockSoundSets: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateRepulsionConfig {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        repulsionConfigs: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateModelvfxs {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        modelVFXs: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateEntityUIComponents {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        components: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateSoundSets {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        soundSets: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateBlockSets {
+        r#type: UpdateType::Init,
+        blockSets: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateRecipes {
+        r#type: UpdateType::Init,
+        recipes: Some(Dictionary::default()),
+        removedRecipes: Some(Vec::new()),
+    });
+
+    session.send(UpdateTagPatterns {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        patterns: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateItemSoundSets {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        itemSoundSets: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateReverbEffects {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        effects: Some(Dictionary::default()),
+    });
+
+    session.send(UpdateInteractions {
+        r#type: UpdateType::Init,
+        maxId: 0,
+        interactions: Some(Dictionary::default()),
+    });
+
+    session.send(WorldLoadProgress {
+        status: Some(ByteString::from_static("Meowing")),
+        percentComplete: 50,
+        percentCompleteSubitem: 0,
+    });
+
+    session.send(WorldLoadFinished {});
+}
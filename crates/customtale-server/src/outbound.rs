@@ -0,0 +1,311 @@
+use std::collections::VecDeque;
+
+use customtale_protocol::{
+    framed::HytaleEncodeError,
+    packets::{AnyPacket, PacketCategory, PacketDescriptor},
+};
+use tokio::time::{Duration, Instant, sleep_until};
+
+use crate::transport::TransportTx;
+
+// === SendPriority === //
+
+/// The classes outbound packets are scheduled in, from most to least urgent.
+///
+/// Packets of one class are always sent in the order they were queued. Once a queue is
+/// [prioritized](OutboundQueue::set_prioritized), packets of different classes may overtake each
+/// other. Until then the classes only decide which packets are shaped.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum SendPriority {
+    /// Connection and authentication control, which is never held back by shaping.
+    Control,
+    /// Movement, entity updates and everything else the player reacts to immediately.
+    Realtime,
+    /// Chunk and world map streaming.
+    Chunks,
+    /// Assets and setup data.
+    Bulk,
+}
+
+impl SendPriority {
+    pub const ALL: [Self; 4] = [Self::Control, Self::Realtime, Self::Chunks, Self::Bulk];
+
+    pub fn for_descriptor(descriptor: &PacketDescriptor) -> Self {
+        let category = descriptor.category;
+
+        if category.intersects(PacketCategory::CONNECTION | PacketCategory::AUTH) {
+            Self::Control
+        } else if category.intersects(PacketCategory::WORLD | PacketCategory::WORLD_MAP) {
+            Self::Chunks
+        } else if category.intersects(
+            PacketCategory::ASSETS | PacketCategory::SETUP | PacketCategory::ASSET_EDITOR,
+        ) {
+            Self::Bulk
+        } else {
+            Self::Realtime
+        }
+    }
+}
+
+// === OutboundQueue === //
+
+/// Packets waiting to be sent, one FIFO per [`SendPriority`].
+///
+/// Packets leave in the order they were queued unless the queue is
+/// [prioritized](Self::set_prioritized), which connections only do once the client is playing:
+/// during setup the client expects packets in the order the server produced them.
+#[derive(Debug, Default)]
+pub struct OutboundQueue {
+    classes: [VecDeque<QueuedPacket>; 4],
+    queued_bytes: usize,
+    prioritized: bool,
+    /// The sequence number of the next packet queued.
+    next_seq: u64,
+}
+
+#[derive(Debug)]
+struct QueuedPacket {
+    packet: AnyPacket,
+    len: usize,
+    seq: u64,
+}
+
+impl OutboundQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_prioritized(&self) -> bool {
+        self.prioritized
+    }
+
+    /// Lets more urgent classes overtake packets queued before them.
+    pub fn set_prioritized(&mut self, prioritized: bool) {
+        self.prioritized = prioritized;
+    }
+
+    /// Queues `packet` in the class its category maps to.
+    pub fn push(&mut self, packet: impl Into<AnyPacket>) {
+        let packet = packet.into();
+        self.push_with(SendPriority::for_descriptor(packet.descriptor()), packet);
+    }
+
+    /// Queues `packet` in a specific class, e.g. to keep it ordered with packets of that class.
+    pub fn push_with(&mut self, priority: SendPriority, packet: impl Into<AnyPacket>) {
+        let packet = packet.into();
+        let len = packet.encoded_len();
+        let seq = self.next_seq;
+
+        self.next_seq += 1;
+        self.queued_bytes += len;
+        self.classes[priority as usize].push_back(QueuedPacket { packet, len, seq });
+    }
+
+    /// The class of the packet which leaves next.
+    fn next_class(&self) -> Option<SendPriority> {
+        let mut fronts = SendPriority::ALL.into_iter().filter_map(|priority| {
+            self.classes[priority as usize]
+                .front()
+                .map(|queued| (priority, queued.seq))
+        });
+
+        let (priority, _) = match self.prioritized {
+            true => fronts.next(),
+            false => fronts.min_by_key(|&(_, seq)| seq),
+        }?;
+
+        Some(priority)
+    }
+
+    /// The queued packet which leaves next, along with its class and uncompressed length.
+    pub fn peek(&self) -> Option<(SendPriority, &AnyPacket, usize)> {
+        let priority = self.next_class()?;
+        let queued = self.classes[priority as usize].front()?;
+
+        Some((priority, &queued.packet, queued.len))
+    }
+
+    pub fn pop(&mut self) -> Option<(SendPriority, AnyPacket)> {
+        let priority = self.next_class()?;
+        let queued = self.classes[priority as usize].pop_front()?;
+
+        self.queued_bytes -= queued.len;
+        Some((priority, queued.packet))
+    }
+
+    pub fn len(&self) -> usize {
+        self.classes.iter().map(VecDeque::len).sum()
+    }
+
+    pub fn len_of(&self, priority: SendPriority) -> usize {
+        self.classes[priority as usize].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.classes.iter().all(VecDeque::is_empty)
+    }
+
+    /// The uncompressed size of every queued packet.
+    pub fn queued_bytes(&self) -> usize {
+        self.queued_bytes
+    }
+
+    /// The backlog in the form `Pong::packetQueueSize` reports it.
+    pub fn packet_queue_size(&self) -> i16 {
+        self.len().min(i16::MAX as usize) as i16
+    }
+}
+
+// === BandwidthLimit === //
+
+/// A cap on a connection's outbound bandwidth, measured in uncompressed packet bytes.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct BandwidthLimit {
+    pub bytes_per_second: u64,
+    /// How many bytes may be sent at once after the connection has been idle.
+    pub burst: u64,
+}
+
+/// A token bucket enforcing a [`BandwidthLimit`].
+#[derive(Debug)]
+struct TokenBucket {
+    limit: BandwidthLimit,
+    /// May go negative when a packet larger than the burst is let through.
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: BandwidthLimit) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst as f64,
+            refilled_at: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+
+        self.tokens = (self.tokens + elapsed * self.limit.bytes_per_second as f64)
+            .min(self.limit.burst as f64);
+        self.refilled_at = now;
+    }
+
+    /// When `bytes` may next be sent. Packets larger than the burst only wait for a full bucket.
+    fn ready_at(&mut self, bytes: usize) -> Instant {
+        let now = Instant::now();
+        self.refill(now);
+
+        let wanted = (bytes as f64).min(self.limit.burst as f64);
+        let missing = wanted - self.tokens;
+
+        if missing <= 0.0 || self.limit.bytes_per_second == 0 {
+            now
+        } else {
+            now + Duration::from_secs_f64(missing / self.limit.bytes_per_second as f64)
+        }
+    }
+
+    fn consume(&mut self, bytes: usize) {
+        self.tokens -= bytes as f64;
+    }
+}
+
+// === OutboundScheduler === //
+
+/// Drains an [`OutboundQueue`] into a connection's transport in the order it releases packets, coalescing small
+/// packets into a single write and optionally shaping the connection's bandwidth.
+#[derive(Debug)]
+pub struct OutboundScheduler {
+    pub queue: OutboundQueue,
+    /// The most bytes written before the transport is flushed.
+    pub max_batch_bytes: usize,
+    bucket: Option<TokenBucket>,
+    unflushed: bool,
+}
+
+impl Default for OutboundScheduler {
+    fn default() -> Self {
+        Self {
+            queue: OutboundQueue::new(),
+            max_batch_bytes: 64 * 1024,
+            bucket: None,
+            unflushed: false,
+        }
+    }
+}
+
+impl OutboundScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_bandwidth_limit(&mut self, limit: Option<BandwidthLimit>) {
+        self.bucket = limit.map(TokenBucket::new);
+    }
+
+    /// Whether [`drive`](Self::drive) has anything to do.
+    pub fn has_work(&self) -> bool {
+        self.unflushed || !self.queue.is_empty()
+    }
+
+    /// Writes one batch of queued packets to `tx` and flushes it.
+    ///
+    /// This is cancel-safe: packets only leave the queue once their stream has accepted them and
+    /// an interrupted flush is picked up by the next call.
+    pub async fn drive(&mut self, tx: &mut TransportTx) -> Result<(), HytaleEncodeError> {
+        let mut batch_bytes = 0;
+
+        while let Some((priority, _, len)) = self.queue.peek() {
+            if batch_bytes > 0 && batch_bytes + len > self.max_batch_bytes {
+                break;
+            }
+
+            let shaped = priority != SendPriority::Control;
+
+            if shaped && let Some(bucket) = &mut self.bucket {
+                let ready_at = bucket.ready_at(len);
+
+                if ready_at > Instant::now() {
+                    // Send what we have rather than holding it back behind this packet.
+                    if self.unflushed {
+                        break;
+                    }
+
+                    sleep_until(ready_at).await;
+                }
+            }
+
+            let (_, packet, _) = self.queue.peek().unwrap();
+            let route = tx.route_for(packet);
+            tx.ready(route).await?;
+
+            let (_, packet) = self.queue.pop().unwrap();
+            tx.start_send(route, packet)?;
+
+            if shaped && let Some(bucket) = &mut self.bucket {
+                bucket.consume(len);
+            }
+
+            batch_bytes += len;
+            self.unflushed = true;
+        }
+
+        if self.unflushed {
+            tx.flush().await?;
+            self.unflushed = false;
+        }
+
+        Ok(())
+    }
+
+    /// Sends everything that is queued.
+    pub async fn drain(&mut self, tx: &mut TransportTx) -> Result<(), HytaleEncodeError> {
+        while self.has_work() {
+            self.drive(tx).await?;
+        }
+
+        Ok(())
+    }
+}
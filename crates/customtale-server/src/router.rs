@@ -0,0 +1,153 @@
+use std::fmt;
+
+use customtale_protocol::packets::{AnyPacket, Packet, PacketDescriptor};
+use thiserror::Error;
+
+use crate::connection::ConnectionPhase;
+
+type ErasedHandler<C> = Box<dyn Fn(&mut C, AnyPacket) -> anyhow::Result<()> + Send + Sync>;
+
+#[derive(Debug, Error)]
+pub enum RouteError {
+    #[error(
+        "packet {descriptor} in category {:?} is not routed during phase {phase:?}",
+        descriptor.category,
+    )]
+    DeniedCategory {
+        descriptor: &'static PacketDescriptor,
+        phase: ConnectionPhase,
+    },
+    #[error("no handler is registered for packet {descriptor}")]
+    Unhandled {
+        descriptor: &'static PacketDescriptor,
+    },
+    #[error("handler for packet {descriptor} failed")]
+    Handler {
+        descriptor: &'static PacketDescriptor,
+        #[source]
+        error: anyhow::Error,
+    },
+}
+
+/// What a [`PacketRouter`] does with a packet for which neither a handler nor a fallback is
+/// registered.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default)]
+pub enum UnhandledPolicy {
+    /// Drop the packet silently.
+    Ignore,
+    /// Drop the packet, logging a warning.
+    #[default]
+    Log,
+    /// Fail with [`RouteError::Unhandled`], which the connection should treat as fatal.
+    Disconnect,
+}
+
+/// Dispatches [`AnyPacket`]s to handlers registered for their concrete packet type.
+///
+/// Handlers receive a mutable reference to a caller-defined context `C` through which they can
+/// reach the connection's state.
+pub struct PacketRouter<C> {
+    handlers: Vec<Option<ErasedHandler<C>>>,
+    fallback: Option<ErasedHandler<C>>,
+    pub unhandled: UnhandledPolicy,
+}
+
+impl<C> fmt::Debug for PacketRouter<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PacketRouter")
+            .field(
+                "handled",
+                &self.handlers.iter().filter(|v| v.is_some()).count(),
+            )
+            .field("has_fallback", &self.fallback.is_some())
+            .field("unhandled", &self.unhandled)
+            .finish()
+    }
+}
+
+impl<C> Default for PacketRouter<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> PacketRouter<C> {
+    pub fn new() -> Self {
+        Self {
+            handlers: Vec::new(),
+            fallback: None,
+            unhandled: UnhandledPolicy::default(),
+        }
+    }
+
+    /// Registers the handler for packets of type `P`, replacing any existing one.
+    pub fn on<P: Packet + 'static>(
+        &mut self,
+        handler: impl Fn(&mut C, Box<P>) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) -> &mut Self {
+        let id = P::DESCRIPTOR.id as usize;
+
+        if self.handlers.len() <= id {
+            self.handlers.resize_with(id + 1, || None);
+        }
+
+        self.handlers[id] = Some(Box::new(move |cx, packet| {
+            handler(cx, packet.downcast::<P>().unwrap())
+        }));
+
+        self
+    }
+
+    /// Registers the handler for packets of every type without a handler of their own.
+    pub fn fallback(
+        &mut self,
+        handler: impl Fn(&mut C, AnyPacket) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+
+    pub fn unhandled(&mut self, policy: UnhandledPolicy) -> &mut Self {
+        self.unhandled = policy;
+        self
+    }
+
+    pub fn handles(&self, descriptor: &PacketDescriptor) -> bool {
+        self.handlers
+            .get(descriptor.id as usize)
+            .is_some_and(Option::is_some)
+    }
+
+    /// Hands `packet` to its handler, refusing packets which are not allowed during `phase`.
+    pub fn dispatch(
+        &self,
+        cx: &mut C,
+        phase: ConnectionPhase,
+        packet: AnyPacket,
+    ) -> Result<(), RouteError> {
+        let descriptor = packet.descriptor();
+
+        if !phase.allowed_categories().contains(descriptor.category) {
+            return Err(RouteError::DeniedCategory { descriptor, phase });
+        }
+
+        let handler = self
+            .handlers
+            .get(descriptor.id as usize)
+            .and_then(Option::as_ref)
+            .or(self.fallback.as_ref());
+
+        let Some(handler) = handler else {
+            return match self.unhandled {
+                UnhandledPolicy::Ignore => Ok(()),
+                UnhandledPolicy::Log => {
+                    tracing::warn!(%descriptor, "ignoring packet with no registered handler");
+                    Ok(())
+                }
+                UnhandledPolicy::Disconnect => Err(RouteError::Unhandled { descriptor }),
+            };
+        };
+
+        handler(cx, packet).map_err(|error| RouteError::Handler { descriptor, error })
+    }
+}
@@ -0,0 +1,259 @@
+//! The certificate the QUIC endpoint presents to clients.
+//!
+//! Access tokens issued to the server are bound to the fingerprint of its certificate, so the
+//! certificate must survive restarts. The [`CertificateStore`] loads a PEM certificate chain and
+//! private key from disk, generating and saving a self-signed pair the first time neither exists.
+//! Operators may point it at certificates of their own instead.
+//!
+//! Each connection is accepted with a [configuration](CertificateStore::server_config) for the
+//! certificate which was current when it arrived, so [reloading](CertificateStore::reload) the
+//! store only affects connections made afterwards, and every connection knows the fingerprint of
+//! the certificate it was actually served.
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use customtale_auth::fingerprint::compute_certificate_fingerprint;
+use rustls::{
+    crypto::CryptoProvider,
+    pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+    server::{ClientHello, ResolvesServerCert},
+    sign::CertifiedKey,
+};
+use thiserror::Error;
+
+// === CertificateStoreError === //
+
+#[derive(Debug, Error)]
+pub enum CertificateStoreError {
+    #[error("failed to read {}", path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: rustls::pki_types::pem::Error,
+    },
+    #[error("{} contains no certificates", path.display())]
+    NoCertificates { path: PathBuf },
+    #[error(
+        "found {} but not {}; either provide both or neither",
+        present.display(),
+        missing.display(),
+    )]
+    Incomplete { present: PathBuf, missing: PathBuf },
+    #[error("failed to write {}", path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("failed to generate a certificate")]
+    Generate(#[from] rcgen::Error),
+    #[error("the certificate and private key are unusable")]
+    Tls(#[from] rustls::Error),
+}
+
+// === ServerCertificate === //
+
+/// A certificate chain and key ready to be served, along with the fingerprint of its end-entity
+/// certificate.
+#[derive(Debug)]
+pub struct ServerCertificate {
+    pub key: Arc<CertifiedKey>,
+    pub fingerprint: String,
+}
+
+impl ServerCertificate {
+    pub fn from_der(
+        chain: Vec<CertificateDer<'static>>,
+        key: PrivateKeyDer<'static>,
+        provider: &CryptoProvider,
+    ) -> Result<Self, CertificateStoreError> {
+        let key = CertifiedKey::from_der(chain, key, provider)?;
+        let fingerprint = compute_certificate_fingerprint(key.end_entity_cert()?);
+
+        Ok(Self {
+            key: Arc::new(key),
+            fingerprint,
+        })
+    }
+
+    /// Reads a PEM certificate chain, end-entity certificate first, and a PEM private key.
+    pub fn from_pem_files(
+        cert_path: &Path,
+        key_path: &Path,
+        provider: &CryptoProvider,
+    ) -> Result<Self, CertificateStoreError> {
+        let read_error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| CertificateStoreError::Read { path, source }
+        };
+
+        let chain = CertificateDer::pem_file_iter(cert_path)
+            .map_err(read_error(cert_path))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(read_error(cert_path))?;
+
+        if chain.is_empty() {
+            return Err(CertificateStoreError::NoCertificates {
+                path: cert_path.to_path_buf(),
+            });
+        }
+
+        let key = PrivateKeyDer::from_pem_file(key_path).map_err(read_error(key_path))?;
+
+        Self::from_der(chain, key, provider)
+    }
+}
+
+// === CertificateStore === //
+
+/// Serves the certificate stored at a pair of paths and swaps it out when asked to reload.
+#[derive(Debug)]
+pub struct CertificateStore {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+    provider: Arc<CryptoProvider>,
+    current: RwLock<Arc<ServerCertificate>>,
+}
+
+impl CertificateStore {
+    /// Loads the certificate at `cert_path` and the key at `key_path`. If neither exists, a
+    /// self-signed certificate for `subject_alt_names` is generated and saved there first.
+    pub fn open(
+        cert_path: impl Into<PathBuf>,
+        key_path: impl Into<PathBuf>,
+        subject_alt_names: &[String],
+        provider: Arc<CryptoProvider>,
+    ) -> Result<Self, CertificateStoreError> {
+        let cert_path = cert_path.into();
+        let key_path = key_path.into();
+
+        match (cert_path.exists(), key_path.exists()) {
+            (true, true) => {}
+            (false, false) => {
+                generate_self_signed(&cert_path, &key_path, subject_alt_names)?;
+
+                tracing::info!(
+                    "Generated a self-signed certificate in {}",
+                    cert_path.display(),
+                );
+            }
+            (true, false) => {
+                return Err(CertificateStoreError::Incomplete {
+                    present: cert_path,
+                    missing: key_path,
+                });
+            }
+            (false, true) => {
+                return Err(CertificateStoreError::Incomplete {
+                    present: key_path,
+                    missing: cert_path,
+                });
+            }
+        }
+
+        let current = ServerCertificate::from_pem_files(&cert_path, &key_path, &provider)?;
+
+        Ok(Self {
+            cert_path,
+            key_path,
+            provider,
+            current: RwLock::new(Arc::new(current)),
+        })
+    }
+
+    /// The certificate new connections are served.
+    pub fn current(&self) -> Arc<ServerCertificate> {
+        self.current.read().unwrap().clone()
+    }
+
+    /// The fingerprint of the certificate new connections are served.
+    pub fn fingerprint(&self) -> String {
+        self.current().fingerprint.clone()
+    }
+
+    /// Re-reads the certificate and key from disk. If they cannot be loaded, the current
+    /// certificate is kept.
+    pub fn reload(&self) -> Result<Arc<ServerCertificate>, CertificateStoreError> {
+        let loaded = Arc::new(ServerCertificate::from_pem_files(
+            &self.cert_path,
+            &self.key_path,
+            &self.provider,
+        )?);
+
+        *self.current.write().unwrap() = loaded.clone();
+
+        Ok(loaded)
+    }
+
+    /// A TLS 1.3 server configuration which serves `certificate`, usually the
+    /// [current](Self::current) one.
+    pub fn server_config(
+        &self,
+        certificate: Arc<ServerCertificate>,
+    ) -> Result<rustls::ServerConfig, CertificateStoreError> {
+        let config = rustls::ServerConfig::builder_with_provider(self.provider.clone())
+            .with_protocol_versions(&[&rustls::version::TLS13])?
+            .with_no_client_auth()
+            .with_cert_resolver(certificate);
+
+        Ok(config)
+    }
+}
+
+impl ResolvesServerCert for ServerCertificate {
+    fn resolve(&self, _client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        Some(self.key.clone())
+    }
+}
+
+fn generate_self_signed(
+    cert_path: &Path,
+    key_path: &Path,
+    subject_alt_names: &[String],
+) -> Result<(), CertificateStoreError> {
+    let ssc = rcgen::generate_simple_self_signed(subject_alt_names)?;
+
+    // The key is written first so that a failure part way through leaves neither file behind
+    // rather than a certificate without its key.
+    write_file(key_path, ssc.signing_key.serialize_pem().as_bytes(), true)?;
+
+    if let Err(error) = write_file(cert_path, ssc.cert.pem().as_bytes(), false) {
+        _ = fs::remove_file(key_path);
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Writes `contents` to a new file at `path`, which only its owner may read if `private` is set
+/// and the platform supports it.
+fn write_file(path: &Path, contents: &[u8], private: bool) -> Result<(), CertificateStoreError> {
+    let write = || {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        if private {
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        }
+
+        #[cfg(not(unix))]
+        let _ = private;
+
+        options.open(path)?.write_all(contents)
+    };
+
+    write().map_err(|source| CertificateStoreError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
@@ -0,0 +1,445 @@
+//! Maps packets onto the QUIC streams and datagrams of a connection.
+//!
+//! Every connection has a primary bidirectional stream, opened by the client, which carries the
+//! handshake and every packet a [`StreamLayout`] does not place elsewhere. Layouts can move
+//! categories onto their own unidirectional streams so that, for instance, a large asset transfer
+//! cannot hold up movement, or onto unreliable datagrams for packets which may be lost or
+//! reordered. Each stream is just a sequence of frames, so the receiver needs no knowledge of the
+//! layout: it decodes whatever arrives on any stream. It does however only let clients open as
+//! many streams as the layout has stream lanes, and only once they have authenticated.
+//!
+//! Packets are only ordered relative to other packets on the same stream. The vanilla client is
+//! only known to use the primary stream, so layouts only apply to connections which negotiated
+//! [`MULTI_STREAM_ALPN`], which vanilla clients never offer, and default to
+//! [`StreamLayout::single`].
+
+use std::{io, sync::Arc};
+
+use customtale_protocol::{
+    capture::CaptureTap,
+    compression::CompressionStats,
+    framed::{HytaleDecodeError, HytaleDecoder, HytaleEncodeError, HytaleEncoder},
+    packets::{self, AnyPacket, ClientboundPacket, PacketCategory, Protocol, ServerboundPacket},
+    serde::{DecodeLimits, SharedBudget},
+};
+use futures::{SinkExt, StreamExt, future::poll_fn, stream::SelectAll};
+use quinn::{RecvStream, SendDatagramError, SendStream, VarInt};
+use tokio_util::bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder, Framed};
+
+pub type PacketSink = Framed<SendStream, HytaleEncoder<ClientboundPacket>>;
+pub type PacketStream = Framed<RecvStream, HytaleDecoder<ServerboundPacket>>;
+
+/// The ALPN protocol under which a [`StreamLayout`] other than the primary stream may be used.
+pub const MULTI_STREAM_ALPN: &[u8] = b"customtale/split";
+
+// === StreamLayout === //
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum LaneKind {
+    /// A unidirectional stream opened by the server the first time it is needed.
+    Stream,
+    /// Unreliable, unordered datagrams. Packets too large for a datagram use the primary stream.
+    Datagram,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Lane {
+    pub categories: PacketCategory,
+    pub kind: LaneKind,
+}
+
+/// Assigns packet categories to lanes. Packets whose category is in no lane use the primary
+/// stream, as does every packet sent before the client has authenticated.
+#[derive(Debug, Clone, Default)]
+pub struct StreamLayout {
+    pub lanes: Vec<Lane>,
+}
+
+impl StreamLayout {
+    /// Sends everything over the primary stream.
+    pub fn single() -> Self {
+        Self::default()
+    }
+
+    /// Gives setup and asset transfers and world streaming a stream each.
+    pub fn split() -> Self {
+        Self {
+            lanes: vec![
+                Lane {
+                    // Setup packets report on the progress of asset transfers and must stay
+                    // ordered with them.
+                    categories: PacketCategory::SETUP
+                        | PacketCategory::ASSETS
+                        | PacketCategory::ASSET_EDITOR,
+                    kind: LaneKind::Stream,
+                },
+                Lane {
+                    categories: PacketCategory::WORLD | PacketCategory::WORLD_MAP,
+                    kind: LaneKind::Stream,
+                },
+            ],
+        }
+    }
+
+    pub fn lane_for(&self, category: PacketCategory) -> Option<usize> {
+        self.lanes
+            .iter()
+            .position(|lane| lane.categories.intersects(category))
+    }
+}
+
+/// Where a packet is sent.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Route {
+    Primary,
+    /// The stream of the layout's lane with this index.
+    Stream(usize),
+    Datagram,
+}
+
+/// Picks the layout a connection uses based on the ALPN protocol it negotiated.
+pub fn negotiate_layout(quic: &quinn::Connection, layout: &StreamLayout) -> StreamLayout {
+    let alpn = quic
+        .handshake_data()
+        .and_then(|data| data.downcast::<quinn::crypto::rustls::HandshakeData>().ok())
+        .and_then(|data| data.protocol);
+
+    if alpn.as_deref() == Some(MULTI_STREAM_ALPN) {
+        layout.clone()
+    } else {
+        StreamLayout::single()
+    }
+}
+
+fn connection_lost_write(error: quinn::ConnectionError) -> io::Error {
+    quinn::WriteError::ConnectionLost(error).into()
+}
+
+fn connection_lost_read(error: quinn::ConnectionError) -> io::Error {
+    quinn::ReadError::ConnectionLost(error).into()
+}
+
+// === TransportTx === //
+
+/// The sending half of a connection's transport.
+#[derive(Debug)]
+pub struct TransportTx {
+    quic: quinn::Connection,
+    layout: Arc<StreamLayout>,
+    /// Whether the client has authenticated, before which everything uses the primary stream.
+    layout_active: bool,
+    primary: PacketSink,
+    /// The streams of the layout's stream lanes, opened on first use.
+    lanes: Vec<Option<PacketSink>>,
+    datagrams: HytaleEncoder<ClientboundPacket>,
+    protocol: &'static Protocol<AnyPacket>,
+    tap: Option<CaptureTap>,
+}
+
+impl TransportTx {
+    pub fn new(quic: quinn::Connection, layout: Arc<StreamLayout>, primary: SendStream) -> Self {
+        Self {
+            lanes: layout.lanes.iter().map(|_| None).collect(),
+            quic,
+            layout,
+            layout_active: false,
+            primary: Framed::new(primary, HytaleEncoder::new()),
+            datagrams: HytaleEncoder::new(),
+            protocol: &packets::PROTOCOL,
+            tap: None,
+        }
+    }
+
+    pub fn set_protocol(&mut self, protocol: &'static Protocol<AnyPacket>) {
+        self.primary.codec_mut().protocol = protocol;
+        self.datagrams.protocol = protocol;
+
+        for lane in self.lanes.iter_mut().flatten() {
+            lane.codec_mut().protocol = protocol;
+        }
+
+        self.protocol = protocol;
+    }
+
+    pub fn set_tap(&mut self, tap: CaptureTap) {
+        self.primary.codec_mut().tap = Some(tap.clone());
+        self.datagrams.tap = Some(tap.clone());
+
+        for lane in self.lanes.iter_mut().flatten() {
+            lane.codec_mut().tap = Some(tap.clone());
+        }
+
+        self.tap = Some(tap);
+    }
+
+    /// Starts routing packets according to the layout. Until then, everything uses the primary
+    /// stream.
+    pub fn activate_layout(&mut self) {
+        self.layout_active = true;
+    }
+
+    /// The primary stream, bypassing the layout.
+    pub fn primary(&mut self) -> &mut PacketSink {
+        &mut self.primary
+    }
+
+    pub fn route_for(&self, packet: &AnyPacket) -> Route {
+        if !self.layout_active {
+            return Route::Primary;
+        }
+
+        let descriptor = packet.descriptor();
+
+        let Some(index) = self.layout.lane_for(descriptor.category) else {
+            return Route::Primary;
+        };
+
+        match self.layout.lanes[index].kind {
+            LaneKind::Stream => Route::Stream(index),
+            LaneKind::Datagram => match self.quic.max_datagram_size() {
+                Some(max) if HytaleEncoder::<ClientboundPacket>::max_frame_len(packet) <= max => {
+                    Route::Datagram
+                }
+                _ => Route::Primary,
+            },
+        }
+    }
+
+    /// Waits until `route` can accept a packet, opening its stream if necessary.
+    ///
+    /// This is cancel-safe.
+    pub async fn ready(&mut self, route: Route) -> Result<(), HytaleEncodeError> {
+        let sink = match route {
+            Route::Primary => &mut self.primary,
+            Route::Stream(index) => {
+                if self.lanes[index].is_none() {
+                    let stream = self.quic.open_uni().await.map_err(connection_lost_write)?;
+
+                    let mut sink = Framed::new(stream, HytaleEncoder::new());
+                    sink.codec_mut().protocol = self.protocol;
+                    sink.codec_mut().tap = self.tap.clone();
+
+                    tracing::debug!(
+                        categories = ?self.layout.lanes[index].categories,
+                        "opened a stream for lane {index}",
+                    );
+
+                    self.lanes[index] = Some(sink);
+                }
+
+                self.lanes[index].as_mut().unwrap()
+            }
+            Route::Datagram => return Ok(()),
+        };
+
+        poll_fn(|cx| sink.poll_ready_unpin(cx)).await
+    }
+
+    /// Sends `packet` along `route`, which must have been made [`ready`](Self::ready).
+    pub fn start_send(&mut self, route: Route, packet: AnyPacket) -> Result<(), HytaleEncodeError> {
+        match route {
+            Route::Primary => self.primary.start_send_unpin(packet),
+            Route::Stream(index) => self.lanes[index]
+                .as_mut()
+                .expect("lane was not made ready")
+                .start_send_unpin(packet),
+            Route::Datagram => {
+                let mut frame = BytesMut::new();
+                self.datagrams.encode(packet, &mut frame)?;
+
+                self.quic
+                    .send_datagram(frame.freeze())
+                    .map_err(|error| match error {
+                        SendDatagramError::ConnectionLost(error) => connection_lost_write(error),
+                        error => io::Error::other(error),
+                    })?;
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Flushes every stream.
+    pub async fn flush(&mut self) -> Result<(), HytaleEncodeError> {
+        self.primary.flush().await?;
+
+        for lane in self.lanes.iter_mut().flatten() {
+            lane.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Finishes every lane's stream so that the client receives what was sent on them.
+    pub fn finish_lanes(&mut self) {
+        for lane in self.lanes.iter_mut().flatten() {
+            _ = lane.get_mut().finish();
+        }
+    }
+
+    /// The compression statistics of every stream and datagram sent so far.
+    pub fn stats(&self) -> CompressionStats {
+        let mut stats = self.primary.codec().stats().clone();
+        stats.merge(self.datagrams.stats());
+
+        for lane in self.lanes.iter().flatten() {
+            stats.merge(lane.codec().stats());
+        }
+
+        stats
+    }
+}
+
+// === TransportRx === //
+
+/// The receiving half of a connection's transport.
+#[derive(Debug)]
+pub struct TransportRx {
+    quic: quinn::Connection,
+    /// Whether the client may open streams and send datagrams of its own.
+    multi_stream: bool,
+    /// Whether the client has authenticated, before which only the primary stream is read.
+    layout_active: bool,
+    /// The number of streams the client may have open at once besides the primary stream.
+    max_streams: usize,
+    primary: PacketStream,
+    streams: SelectAll<PacketStream>,
+    datagrams: HytaleDecoder<ServerboundPacket>,
+    allowed_categories: PacketCategory,
+    protocol: &'static Protocol<AnyPacket>,
+    tap: Option<CaptureTap>,
+    /// The memory budget every stream and datagram is decoded against.
+    budget: SharedBudget,
+}
+
+impl TransportRx {
+    pub fn new(
+        quic: quinn::Connection,
+        layout: &StreamLayout,
+        primary: RecvStream,
+        allowed_categories: PacketCategory,
+    ) -> Self {
+        let budget = SharedBudget::default();
+
+        let decoder = || {
+            let mut decoder = HytaleDecoder::new(allowed_categories);
+            decoder.limits = DecodeLimits::default().sharing(budget.clone());
+            decoder
+        };
+
+        Self {
+            multi_stream: !layout.lanes.is_empty(),
+            layout_active: false,
+            max_streams: layout
+                .lanes
+                .iter()
+                .filter(|lane| lane.kind == LaneKind::Stream)
+                .count(),
+            primary: Framed::new(primary, decoder()),
+            streams: SelectAll::new(),
+            datagrams: decoder(),
+            quic,
+            allowed_categories,
+            protocol: &packets::PROTOCOL,
+            tap: None,
+            budget,
+        }
+    }
+
+    /// Starts accepting the streams and datagrams of the layout, once the client has
+    /// authenticated.
+    pub fn activate_layout(&mut self) {
+        if self.multi_stream && !self.layout_active {
+            self.quic
+                .set_max_concurrent_uni_streams(VarInt::from_u32(self.max_streams as u32));
+        }
+
+        self.layout_active = true;
+    }
+
+    pub fn set_protocol(&mut self, protocol: &'static Protocol<AnyPacket>) {
+        self.primary.codec_mut().protocol = protocol;
+        self.datagrams.protocol = protocol;
+
+        for stream in self.streams.iter_mut() {
+            stream.codec_mut().protocol = protocol;
+        }
+
+        self.protocol = protocol;
+    }
+
+    pub fn set_tap(&mut self, tap: CaptureTap) {
+        self.primary.codec_mut().tap = Some(tap.clone());
+        self.datagrams.tap = Some(tap.clone());
+
+        for stream in self.streams.iter_mut() {
+            stream.codec_mut().tap = Some(tap.clone());
+        }
+
+        self.tap = Some(tap);
+    }
+
+    pub fn set_allowed_categories(&mut self, allowed: PacketCategory) {
+        self.primary.codec_mut().allowed_categories = allowed;
+        self.datagrams.allowed_categories = allowed;
+
+        for stream in self.streams.iter_mut() {
+            stream.codec_mut().allowed_categories = allowed;
+        }
+
+        self.allowed_categories = allowed;
+    }
+
+    /// Receives the next packet from any stream or datagram, returning `None` once the client
+    /// closes the primary stream.
+    ///
+    /// This is cancel-safe.
+    pub async fn next(&mut self) -> Option<Result<ServerboundPacket, HytaleDecodeError>> {
+        loop {
+            tokio::select! {
+                packet = self.primary.next() => return packet,
+                Some(packet) = self.streams.next(), if !self.streams.is_empty() => {
+                    return Some(packet);
+                }
+                stream = self.quic.accept_uni(), if self.accepts_streams() => match stream {
+                    Ok(stream) => {
+                        let mut decoder = HytaleDecoder::new(self.allowed_categories);
+                        decoder.protocol = self.protocol;
+                        decoder.limits = DecodeLimits::default().sharing(self.budget.clone());
+                        decoder.tap = self.tap.clone();
+                        self.streams.push(Framed::new(stream, decoder));
+                    }
+                    Err(error) => return Some(Err(connection_lost_read(error).into())),
+                },
+                datagram = self.quic.read_datagram(), if self.accepts_datagrams() => match datagram {
+                    Ok(datagram) => return Some(self.decode_datagram(&datagram)),
+                    Err(error) => return Some(Err(connection_lost_read(error).into())),
+                },
+            }
+        }
+    }
+
+    /// Whether another of the client's streams may be accepted. Flow control should already stop
+    /// the client from opening more than [`activate_layout`](Self::activate_layout) allows.
+    fn accepts_streams(&self) -> bool {
+        self.accepts_datagrams() && self.streams.len() < self.max_streams
+    }
+
+    fn accepts_datagrams(&self) -> bool {
+        self.multi_stream && self.layout_active
+    }
+
+    fn decode_datagram(&mut self, datagram: &[u8]) -> Result<ServerboundPacket, HytaleDecodeError> {
+        let mut src = BytesMut::from(datagram);
+
+        match self.datagrams.decode(&mut src)? {
+            Some(packet) if src.is_empty() => Ok(packet),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "datagram did not hold exactly one frame",
+            )
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Helpers shared by the tests which need a live connection.
+
+#![allow(dead_code)]
+
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::Arc,
+};
+
+use customtale_protocol::{
+    framed::{HytaleDecoder, HytaleEncoder},
+    packets::{
+        Asset, ClientReady, ClientboundPacket, PacketCategory, ServerboundPacket, WorldSettings,
+    },
+};
+use customtale_server::{connection::transport_config, transport::MULTI_STREAM_ALPN};
+use futures::SinkExt;
+use quinn::crypto::rustls::{QuicClientConfig, QuicServerConfig};
+use rustls::pki_types::{CertificateDer, PrivatePkcs8KeyDer};
+use tokio_util::codec::Framed;
+
+/// Both ends of a connection over the loopback interface, along with the primary stream.
+pub struct Pair {
+    pub server: quinn::Connection,
+    pub client: quinn::Connection,
+    pub server_primary: (quinn::SendStream, quinn::RecvStream),
+    pub client_primary: Framed<quinn::SendStream, HytaleEncoder<ServerboundPacket>>,
+    pub client_primary_rx: quinn::RecvStream,
+    // Kept alive for the duration of the test.
+    pub endpoints: (quinn::Endpoint, quinn::Endpoint),
+}
+
+/// Connects a client which offers `alpn` to a server which offers [`MULTI_STREAM_ALPN`] and
+/// `hytale/2`. The client opens the primary stream by sending a `ClientReady`, which is left
+/// unread.
+pub async fn connect(alpn: &[u8]) -> Pair {
+    let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+
+    let ssc = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let cert = CertificateDer::from(ssc.cert);
+    let key = PrivatePkcs8KeyDer::from(ssc.signing_key.serialize_der());
+
+    let mut server_tls = rustls::ServerConfig::builder_with_provider(provider.clone())
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(vec![cert.clone()], key.into())
+        .unwrap();
+
+    server_tls.alpn_protocols = vec![MULTI_STREAM_ALPN.to_vec(), b"hytale/2".to_vec()];
+
+    let mut server_config =
+        quinn::ServerConfig::with_crypto(Arc::new(QuicServerConfig::try_from(server_tls).unwrap()));
+    server_config.transport_config(transport_config());
+
+    let server_endpoint =
+        quinn::Endpoint::server(server_config, (Ipv4Addr::LOCALHOST, 0).into()).unwrap();
+    let addr: SocketAddr = server_endpoint.local_addr().unwrap();
+
+    let mut roots = rustls::RootCertStore::empty();
+    roots.add(cert).unwrap();
+
+    let mut client_tls = rustls::ClientConfig::builder_with_provider(provider)
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    client_tls.alpn_protocols = vec![alpn.to_vec()];
+
+    let mut client_endpoint = quinn::Endpoint::client((Ipv4Addr::LOCALHOST, 0).into()).unwrap();
+    client_endpoint.set_default_client_config(quinn::ClientConfig::new(Arc::new(
+        QuicClientConfig::try_from(client_tls).unwrap(),
+    )));
+
+    let (server, client) = tokio::join!(
+        async { server_endpoint.accept().await.unwrap().await.unwrap() },
+        async {
+            client_endpoint
+                .connect(addr, "localhost")
+                .unwrap()
+                .await
+                .unwrap()
+        },
+    );
+
+    // Streams are only announced to the peer once something is sent on them.
+    let (tx, client_primary_rx) = client.open_bi().await.unwrap();
+    let mut client_primary = Framed::new(tx, HytaleEncoder::new());
+    client_primary
+        .send(ClientReady::default().into())
+        .await
+        .unwrap();
+
+    let server_primary = server.accept_bi().await.unwrap();
+
+    Pair {
+        server,
+        client,
+        server_primary,
+        client_primary,
+        client_primary_rx,
+        endpoints: (server_endpoint, client_endpoint),
+    }
+}
+
+pub fn client_decoder() -> HytaleDecoder<ClientboundPacket> {
+    HytaleDecoder::new(PacketCategory::all())
+}
+
+/// A `WorldSettings` listing `assets` assets, which grows by about 100 bytes per asset.
+pub fn world_settings(assets: usize) -> WorldSettings {
+    WorldSettings {
+        worldHeight: 320,
+        requiredAssets: Some(
+            (0..assets)
+                .map(|i| Asset {
+                    hash: format!("{i:064x}").into(),
+                    name: format!("Common/Blocks/Stone_{i}.png").into(),
+                })
+                .collect(),
+        ),
+    }
+}
@@ -0,0 +1,130 @@
+//! Checks how configuration files and command line overrides are combined and validated.
+
+use customtale_server::{
+    config::{AuthMode, Config, ConfigError, StreamLayoutKind},
+    transport::MULTI_STREAM_ALPN,
+};
+
+const FILE: &str = r#"
+[server]
+name = "Lobby"
+max_players = 20
+
+[network]
+bind = ["0.0.0.0:5520"]
+stream_layout = "split"
+"#;
+
+fn parse(text: &str, overrides: &[&str]) -> Result<Config, ConfigError> {
+    let overrides = overrides
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect::<Vec<_>>();
+    Config::parse("customtale.toml".to_string(), text.to_string(), &overrides)
+}
+
+/// The text an error points at.
+fn flagged(error: &ConfigError) -> String {
+    let ConfigError::Invalid {
+        source_code, span, ..
+    } = error
+    else {
+        panic!("expected an invalid configuration, got {error:?}");
+    };
+
+    source_code.inner()[span.offset()..span.offset() + span.len()].to_string()
+}
+
+#[test]
+fn empty_files_use_defaults() {
+    let config = parse("", &[]).unwrap();
+
+    assert_eq!(config.world.height, 320);
+    assert_eq!(config.network.alpn, ["hytale/2", "hytale/1"]);
+    assert_eq!(config.auth.mode, AuthMode::Authenticated);
+    assert_eq!(config.network.protocol_fallback.as_deref(), Some("v1"));
+}
+
+#[test]
+fn example_matches_defaults() {
+    let config = parse(include_str!("../customtale.example.toml"), &[]).unwrap();
+    let defaults = Config::default();
+
+    assert_eq!(config.server.name, defaults.server.name);
+    assert_eq!(config.network.bind, defaults.network.bind);
+    assert_eq!(config.world.view_radius, defaults.world.view_radius);
+    assert_eq!(config.paths.tls_key, defaults.paths.tls_key);
+}
+
+#[test]
+fn overrides_replace_file_values() {
+    let config = parse(
+        FILE,
+        &[
+            "server.max_players=5",
+            "server.motd=Welcome!",
+            "auth.mode=offline",
+            r#"network.bind=["[::]:5520"]"#,
+            "network.protocol_fallback=v1",
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(config.server.name, "Lobby");
+    assert_eq!(config.server.max_players, 5);
+    assert_eq!(config.server.motd, "Welcome!");
+    assert_eq!(config.auth.mode, AuthMode::Offline);
+    assert_eq!(config.network.bind, ["[::]:5520".parse().unwrap()]);
+    assert_eq!(config.network.protocol_fallback.as_deref(), Some("v1"));
+    assert_eq!(config.network.stream_layout, StreamLayoutKind::Split);
+}
+
+#[test]
+fn errors_point_into_the_file() {
+    let error = parse(&FILE.replace("max_players = 20", "max_players = -20"), &[]).unwrap_err();
+    assert_eq!(flagged(&error), "-20");
+
+    let error = parse(&format!("{FILE}colour = \"red\"\n"), &[]).unwrap_err();
+    assert_eq!(flagged(&error), "colour");
+
+    let error = parse("[world]\nview_radius = { min = 8, max = 4 }\n", &[]).unwrap_err();
+    assert_eq!(flagged(&error), "{ min = 8, max = 4 }");
+}
+
+#[test]
+fn errors_point_into_overrides() {
+    let error = parse(FILE, &["network.bind=[]"]).unwrap_err();
+    assert_eq!(flagged(&error), "[]");
+
+    let error = parse(FILE, &["world.height=tall"]).unwrap_err();
+    assert_eq!(flagged(&error), r#""tall""#);
+
+    let error = parse(FILE, &["network.protocol_fallback=v0"]).unwrap_err();
+    assert_eq!(flagged(&error), r#""v0""#);
+
+    assert!(matches!(
+        parse(FILE, &["server.name"]),
+        Err(ConfigError::MalformedOverride { .. })
+    ));
+}
+
+#[test]
+fn protocol_fallback_can_be_disabled() {
+    let config = parse(FILE, &["network.protocol_fallback=false"]).unwrap();
+    assert_eq!(config.network.protocol_fallback, None);
+
+    let error = parse(FILE, &["network.protocol_fallback=true"]).unwrap_err();
+    assert_eq!(flagged(&error), "true");
+}
+
+#[test]
+fn split_layout_offers_its_own_alpn() {
+    let config = parse(FILE, &[]).unwrap();
+    assert_eq!(
+        config.network.offered_alpn(),
+        [MULTI_STREAM_ALPN, b"hytale/2", b"hytale/1"],
+    );
+
+    let config = parse(FILE, &["network.stream_layout=single"]).unwrap();
+    assert_eq!(config.network.offered_alpn(), [b"hytale/2", b"hytale/1"]);
+}
@@ -0,0 +1,222 @@
+//! Checks the order in which the outbound queue releases packets and how the scheduler batches
+//! and shapes them.
+
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use customtale_protocol::{
+    framed::HytaleDecoder,
+    packets::{
+        AnyPacket, ClientboundPacket, Disconnect, EntityUpdates, SetChunk, UpdateBlockTypes,
+        ViewRadius, WorldSettings,
+    },
+};
+use customtale_server::{
+    outbound::{BandwidthLimit, OutboundQueue, OutboundScheduler, SendPriority},
+    transport::{StreamLayout, TransportTx},
+};
+use futures::StreamExt;
+use tokio::time::Instant;
+use tokio_util::codec::FramedRead;
+
+use self::common::{Pair, client_decoder, connect, world_settings};
+
+fn name(packet: &AnyPacket) -> &'static str {
+    packet.descriptor().name
+}
+
+#[test]
+fn releases_packets_by_priority_then_order() {
+    let mut queue = OutboundQueue::new();
+    queue.set_prioritized(true);
+
+    queue.push(UpdateBlockTypes::default());
+    queue.push(SetChunk::default());
+    queue.push(WorldSettings::default());
+    queue.push(EntityUpdates::default());
+    queue.push(Disconnect::default());
+    queue.push_with(SendPriority::Realtime, SetChunk::default());
+
+    assert_eq!(queue.len(), 6);
+    assert_eq!(queue.len_of(SendPriority::Bulk), 2);
+    assert_eq!(queue.packet_queue_size(), 6);
+
+    let order = std::iter::from_fn(|| queue.pop())
+        .map(|(priority, packet)| (priority, name(&packet)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        order,
+        [
+            (SendPriority::Control, "Disconnect"),
+            (SendPriority::Realtime, "EntityUpdates"),
+            (SendPriority::Realtime, "SetChunk"),
+            (SendPriority::Chunks, "SetChunk"),
+            (SendPriority::Bulk, "UpdateBlockTypes"),
+            (SendPriority::Bulk, "WorldSettings"),
+        ],
+    );
+
+    assert!(queue.is_empty());
+    assert_eq!(queue.queued_bytes(), 0);
+}
+
+#[test]
+fn keeps_queue_order_until_prioritized() {
+    let mut queue = OutboundQueue::new();
+
+    queue.push(UpdateBlockTypes::default());
+    queue.push(EntityUpdates::default());
+    queue.push(WorldSettings::default());
+    queue.push(Disconnect::default());
+
+    let (priority, packet) = queue.pop().unwrap();
+    assert_eq!(
+        (priority, name(&packet)),
+        (SendPriority::Bulk, "UpdateBlockTypes")
+    );
+
+    let (priority, packet, _) = queue.peek().unwrap();
+    assert_eq!(
+        (priority, name(packet)),
+        (SendPriority::Realtime, "EntityUpdates")
+    );
+
+    // Packets queued before the switch may now be overtaken.
+    queue.set_prioritized(true);
+
+    let order = std::iter::from_fn(|| queue.pop())
+        .map(|(_, packet)| name(&packet))
+        .collect::<Vec<_>>();
+
+    assert_eq!(order, ["Disconnect", "EntityUpdates", "WorldSettings"]);
+}
+
+// === OutboundScheduler === //
+
+/// A scheduler sending over the primary stream of a fresh connection.
+struct Harness {
+    scheduler: OutboundScheduler,
+    tx: TransportTx,
+    /// What the client receives on the primary stream.
+    received: FramedRead<quinn::RecvStream, HytaleDecoder<ClientboundPacket>>,
+    // Kept alive for the duration of the test.
+    _pair: (quinn::Connection, (quinn::Endpoint, quinn::Endpoint)),
+}
+
+async fn harness(limit: Option<BandwidthLimit>) -> Harness {
+    let Pair {
+        server,
+        client,
+        server_primary: (primary, _),
+        client_primary_rx,
+        endpoints,
+        ..
+    } = connect(b"hytale/2").await;
+
+    let mut scheduler = OutboundScheduler::new();
+    scheduler.set_bandwidth_limit(limit);
+
+    Harness {
+        scheduler,
+        tx: TransportTx::new(server, Arc::new(StreamLayout::single()), primary),
+        received: FramedRead::new(client_primary_rx, client_decoder()),
+        _pair: (client, endpoints),
+    }
+}
+
+impl Harness {
+    /// Runs one batch, returning how many packets are left.
+    async fn drive(&mut self) -> usize {
+        self.scheduler.drive(&mut self.tx).await.unwrap();
+        self.scheduler.queue.len()
+    }
+}
+
+fn len(packet: impl Into<AnyPacket>) -> usize {
+    packet.into().encoded_len()
+}
+
+#[tokio::test]
+async fn batches_stop_at_the_byte_limit() {
+    let mut harness = harness(None).await;
+    harness.scheduler.max_batch_bytes = 2 * len(ViewRadius::default());
+
+    for value in 0..5 {
+        harness.scheduler.queue.push(ViewRadius { value });
+    }
+
+    let mut left = Vec::new();
+    while harness.scheduler.has_work() {
+        left.push(harness.drive().await);
+    }
+
+    assert_eq!(left, [3, 1, 0]);
+
+    for value in 0..5 {
+        let packet = harness.received.next().await.unwrap().unwrap().into_inner();
+        assert!(
+            matches!(&packet, AnyPacket::ViewRadius(packet) if packet.value == value),
+            "{packet:?}",
+        );
+    }
+}
+
+#[tokio::test]
+async fn bandwidth_limit_ends_batches_early() {
+    let packet_len = len(world_settings(10));
+
+    // The burst covers a single packet and refills in 100 ms.
+    let mut harness = harness(Some(BandwidthLimit {
+        bytes_per_second: 10 * packet_len as u64,
+        burst: packet_len as u64,
+    }))
+    .await;
+
+    for _ in 0..3 {
+        harness.scheduler.queue.push(world_settings(10));
+    }
+
+    let started = Instant::now();
+
+    // Rather than waiting for the bucket to refill, each batch is sent as soon as the next
+    // packet would have to wait.
+    assert_eq!(harness.drive().await, 2);
+    assert_eq!(harness.drive().await, 1);
+    assert_eq!(harness.drive().await, 0);
+
+    assert!(started.elapsed() >= Duration::from_millis(200));
+
+    for _ in 0..3 {
+        let packet = harness.received.next().await.unwrap().unwrap().into_inner();
+        assert!(matches!(packet, AnyPacket::WorldSettings(_)), "{packet:?}");
+    }
+}
+
+#[tokio::test]
+async fn control_packets_are_not_shaped() {
+    let packet_len = len(world_settings(10));
+
+    // Sending the first packet leaves the bucket half a packet short, which takes 125 ms to
+    // refill.
+    let mut harness = harness(Some(BandwidthLimit {
+        bytes_per_second: 4 * packet_len as u64,
+        burst: packet_len as u64 / 2,
+    }))
+    .await;
+
+    let started = Instant::now();
+
+    harness.scheduler.queue.push(world_settings(10));
+    assert_eq!(harness.drive().await, 0);
+
+    let control_started = Instant::now();
+    harness.scheduler.queue.push(Disconnect::default());
+    assert_eq!(harness.drive().await, 0);
+    assert!(control_started.elapsed() < Duration::from_millis(100));
+
+    harness.scheduler.queue.push(EntityUpdates::default());
+    assert_eq!(harness.drive().await, 0);
+    assert!(started.elapsed() >= Duration::from_millis(120));
+}
@@ -0,0 +1,109 @@
+//! Checks which handler packets are dispatched to and what happens to those without one.
+
+use customtale_protocol::packets::{
+    AnyPacket, ClientMovement, ClientReady, PlayerOptions, RequestAssets, ViewRadius,
+};
+use customtale_server::{
+    connection::ConnectionPhase,
+    router::{PacketRouter, RouteError, UnhandledPolicy},
+};
+
+/// The packets each handler saw, in order.
+#[derive(Debug, Default)]
+struct Seen(Vec<String>);
+
+fn router() -> PacketRouter<Seen> {
+    let mut router = PacketRouter::<Seen>::new();
+
+    router
+        .on::<ViewRadius>(|seen, packet| {
+            seen.0.push(format!("ViewRadius({})", packet.value));
+            Ok(())
+        })
+        .on::<RequestAssets>(|_, _| anyhow::bail!("no assets here"));
+
+    router
+}
+
+fn dispatch(
+    router: &PacketRouter<Seen>,
+    phase: ConnectionPhase,
+    packet: impl Into<AnyPacket>,
+) -> (Result<(), RouteError>, Vec<String>) {
+    let mut seen = Seen::default();
+    let result = router.dispatch(&mut seen, phase, packet.into());
+    (result, seen.0)
+}
+
+#[test]
+fn packets_reach_their_typed_handler() {
+    let router = router();
+
+    let (result, seen) = dispatch(&router, ConnectionPhase::Setup, ViewRadius { value: 12 });
+    result.unwrap();
+    assert_eq!(seen, ["ViewRadius(12)"]);
+
+    let (result, _) = dispatch(&router, ConnectionPhase::Setup, RequestAssets::default());
+    assert!(matches!(result, Err(RouteError::Handler { .. })));
+}
+
+#[test]
+fn fallback_receives_packets_without_a_handler() {
+    let mut router = router();
+    router.fallback(|seen, packet| {
+        seen.0.push(packet.descriptor().name.to_string());
+        Ok(())
+    });
+
+    let (result, seen) = dispatch(&router, ConnectionPhase::Loading, ClientReady::default());
+    result.unwrap();
+    assert_eq!(seen, ["ClientReady"]);
+
+    let (result, seen) = dispatch(&router, ConnectionPhase::Loading, ViewRadius { value: 4 });
+    result.unwrap();
+    assert_eq!(seen, ["ViewRadius(4)"]);
+}
+
+#[test]
+fn unhandled_policies() {
+    let mut router = router();
+
+    for (policy, fails) in [
+        (UnhandledPolicy::Ignore, false),
+        (UnhandledPolicy::Log, false),
+        (UnhandledPolicy::Disconnect, true),
+    ] {
+        router.unhandled(policy);
+
+        let (result, seen) = dispatch(&router, ConnectionPhase::Setup, PlayerOptions::default());
+        assert!(seen.is_empty());
+        assert_eq!(
+            matches!(result, Err(RouteError::Unhandled { .. })),
+            fails,
+            "{policy:?}",
+        );
+    }
+}
+
+#[test]
+fn packets_outside_the_phase_are_refused() {
+    let mut router = router();
+    router.on::<ClientMovement>(|seen, _| {
+        seen.0.push("ClientMovement".to_string());
+        Ok(())
+    });
+
+    let (result, seen) = dispatch(&router, ConnectionPhase::Setup, ClientMovement::default());
+    assert!(matches!(
+        result,
+        Err(RouteError::DeniedCategory {
+            phase: ConnectionPhase::Setup,
+            ..
+        })
+    ));
+    assert!(seen.is_empty());
+
+    let (result, seen) = dispatch(&router, ConnectionPhase::Playing, ClientMovement::default());
+    result.unwrap();
+    assert_eq!(seen, ["ClientMovement"]);
+}
@@ -0,0 +1,146 @@
+//! Checks that the certificate store persists its certificate, reloads it from disk, and serves
+//! each connection the certificate it was configured with.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use customtale_auth::fingerprint::compute_certificate_fingerprint;
+use customtale_server::tls::{CertificateStore, CertificateStoreError, ServerCertificate};
+use rustls::{ClientConnection, RootCertStore, ServerConnection, crypto::CryptoProvider};
+
+fn provider() -> Arc<CryptoProvider> {
+    Arc::new(rustls::crypto::aws_lc_rs::default_provider())
+}
+
+fn temp_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("customtale-tls-{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn open(dir: &Path, name: &str) -> Result<CertificateStore, CertificateStoreError> {
+    CertificateStore::open(
+        dir.join(name).with_extension("crt"),
+        dir.join(name).with_extension("key"),
+        &["localhost".to_string()],
+        provider(),
+    )
+}
+
+/// Performs a handshake in memory against `server_config` and returns the fingerprint of the
+/// certificate the client was served.
+fn served_fingerprint(
+    server_config: rustls::ServerConfig,
+    trusted: &[&ServerCertificate],
+) -> String {
+    let mut roots = RootCertStore::empty();
+    for certificate in trusted {
+        roots.add(certificate.key.cert[0].clone()).unwrap();
+    }
+
+    let client_config = rustls::ClientConfig::builder_with_provider(provider())
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .unwrap()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    let mut client =
+        ClientConnection::new(Arc::new(client_config), "localhost".try_into().unwrap()).unwrap();
+    let mut server = ServerConnection::new(Arc::new(server_config)).unwrap();
+
+    while client.is_handshaking() || server.is_handshaking() {
+        let mut buf = Vec::new();
+        client.write_tls(&mut buf).unwrap();
+        server.read_tls(&mut &buf[..]).unwrap();
+        server.process_new_packets().unwrap();
+
+        buf.clear();
+        server.write_tls(&mut buf).unwrap();
+        client.read_tls(&mut &buf[..]).unwrap();
+        client.process_new_packets().unwrap();
+    }
+
+    compute_certificate_fingerprint(&client.peer_certificates().unwrap()[0])
+}
+
+#[test]
+fn generated_certificates_survive_restarts() {
+    let dir = temp_dir();
+
+    let fingerprint = open(&dir, "server").unwrap().fingerprint();
+    assert!(dir.join("server.crt").exists());
+    assert!(dir.join("server.key").exists());
+
+    assert_eq!(open(&dir, "server").unwrap().fingerprint(), fingerprint);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn reloads_replaced_certificates() {
+    let dir = temp_dir();
+
+    let store = open(&dir, "server").unwrap();
+    let original = store.fingerprint();
+
+    // Generate a different pair and move it into place.
+    let replacement = open(&dir, "replacement").unwrap().fingerprint();
+    fs::rename(dir.join("replacement.crt"), dir.join("server.crt")).unwrap();
+    fs::rename(dir.join("replacement.key"), dir.join("server.key")).unwrap();
+
+    assert_eq!(store.fingerprint(), original);
+    assert_eq!(store.reload().unwrap().fingerprint, replacement);
+    assert_eq!(store.fingerprint(), replacement);
+
+    // A broken replacement leaves the current certificate in place.
+    fs::write(dir.join("server.key"), "").unwrap();
+    assert!(store.reload().is_err());
+    assert_eq!(store.fingerprint(), replacement);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn rejects_a_certificate_without_its_key() {
+    let dir = temp_dir();
+
+    open(&dir, "server").unwrap();
+    fs::remove_file(dir.join("server.key")).unwrap();
+
+    assert!(matches!(
+        open(&dir, "server"),
+        Err(CertificateStoreError::Incomplete { .. })
+    ));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn configurations_keep_serving_their_certificate() {
+    let dir = temp_dir();
+
+    let store = open(&dir, "server").unwrap();
+    let original = store.current();
+    let server_config = store.server_config(original.clone()).unwrap();
+
+    open(&dir, "replacement").unwrap();
+    fs::rename(dir.join("replacement.crt"), dir.join("server.crt")).unwrap();
+    fs::rename(dir.join("replacement.key"), dir.join("server.key")).unwrap();
+    let replacement = store.reload().unwrap();
+
+    let trusted = [&*original, &*replacement];
+
+    assert_eq!(
+        served_fingerprint(server_config, &trusted),
+        original.fingerprint,
+    );
+    assert_eq!(
+        served_fingerprint(store.server_config(store.current()).unwrap(), &trusted),
+        replacement.fingerprint,
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
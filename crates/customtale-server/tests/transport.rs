@@ -0,0 +1,207 @@
+//! Checks which lanes stream layouts assign packets to and that packets arrive over the streams
+//! and datagrams they were routed to.
+
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use customtale_protocol::{
+    framed::{HytaleDecoder, HytaleEncoder},
+    packets::{
+        AnyPacket, ClientMovement, ClientReady, DirectedPacket, Disconnect, PacketCategory,
+        ServerboundPacket, SetChunk, UpdateBlockTypes, ViewRadius, WorldLoadProgress,
+    },
+};
+use customtale_server::transport::{
+    Lane, LaneKind, MULTI_STREAM_ALPN, Route, StreamLayout, TransportRx, TransportTx,
+    negotiate_layout,
+};
+use futures::{SinkExt, StreamExt};
+use tokio_util::{
+    bytes::BytesMut,
+    codec::{Decoder, Encoder, Framed, FramedRead},
+};
+
+use self::common::{client_decoder, connect, world_settings};
+
+fn lane(layout: &StreamLayout, packet: impl Into<AnyPacket>) -> Option<usize> {
+    layout.lane_for(packet.into().descriptor().category)
+}
+
+#[test]
+fn single_layout_uses_the_primary_stream() {
+    let layout = StreamLayout::single();
+
+    assert_eq!(lane(&layout, SetChunk::default()), None);
+    assert_eq!(lane(&layout, UpdateBlockTypes::default()), None);
+}
+
+#[test]
+fn split_layout_separates_bulk_transfers() {
+    let layout = StreamLayout::split();
+
+    assert_eq!(lane(&layout, Disconnect::default()), None);
+    assert_eq!(lane(&layout, ClientMovement::default()), None);
+    assert_eq!(lane(&layout, UpdateBlockTypes::default()), Some(0));
+    assert_eq!(lane(&layout, WorldLoadProgress::default()), Some(0));
+    assert_eq!(lane(&layout, SetChunk::default()), Some(1));
+}
+
+// === Connections === //
+
+fn decode_one<P: DirectedPacket>(decoder: &mut HytaleDecoder<P>, frame: &[u8]) -> AnyPacket {
+    let mut src = BytesMut::from(frame);
+    let packet = decoder.decode(&mut src).unwrap().unwrap();
+    assert!(src.is_empty());
+    packet.into()
+}
+
+async fn send(tx: &mut TransportTx, packet: impl Into<AnyPacket>) -> Route {
+    let packet = packet.into();
+    let route = tx.route_for(&packet);
+
+    tx.ready(route).await.unwrap();
+    tx.start_send(route, packet).unwrap();
+    tx.flush().await.unwrap();
+
+    route
+}
+
+#[tokio::test]
+async fn layouts_require_their_alpn() {
+    let pair = connect(MULTI_STREAM_ALPN).await;
+    let layout = negotiate_layout(&pair.server, &StreamLayout::split());
+    assert_eq!(layout.lanes, StreamLayout::split().lanes);
+
+    let pair = connect(b"hytale/2").await;
+    let layout = negotiate_layout(&pair.server, &StreamLayout::split());
+    assert!(layout.lanes.is_empty());
+}
+
+#[tokio::test]
+async fn stream_lanes_get_their_own_streams() {
+    let pair = connect(MULTI_STREAM_ALPN).await;
+    let mut tx = TransportTx::new(
+        pair.server.clone(),
+        Arc::new(StreamLayout::split()),
+        pair.server_primary.0,
+    );
+
+    // Until the client has authenticated, everything uses the primary stream.
+    assert_eq!(send(&mut tx, SetChunk::default()).await, Route::Primary);
+
+    tx.activate_layout();
+
+    let chunk = SetChunk {
+        x: 4,
+        ..SetChunk::default()
+    };
+
+    assert_eq!(send(&mut tx, chunk).await, Route::Stream(1));
+    assert_eq!(send(&mut tx, Disconnect::default()).await, Route::Primary);
+
+    let mut primary = FramedRead::new(pair.client_primary_rx, client_decoder());
+    let packet = primary.next().await.unwrap().unwrap().into_inner();
+    assert!(matches!(packet, AnyPacket::SetChunk(chunk) if chunk.x == 0));
+
+    let packet = primary.next().await.unwrap().unwrap().into_inner();
+    assert!(matches!(packet, AnyPacket::Disconnect(_)));
+
+    let lane = pair.client.accept_uni().await.unwrap();
+    let mut lane = FramedRead::new(lane, client_decoder());
+    let packet = lane.next().await.unwrap().unwrap().into_inner();
+    assert!(matches!(packet, AnyPacket::SetChunk(chunk) if chunk.x == 4));
+}
+
+#[tokio::test]
+async fn datagram_lanes_fall_back_to_the_primary_stream() {
+    let pair = connect(MULTI_STREAM_ALPN).await;
+    let layout = StreamLayout {
+        lanes: vec![Lane {
+            categories: PacketCategory::SETUP,
+            kind: LaneKind::Datagram,
+        }],
+    };
+
+    let mut tx = TransportTx::new(pair.server.clone(), Arc::new(layout), pair.server_primary.0);
+    tx.activate_layout();
+
+    assert_eq!(send(&mut tx, world_settings(1)).await, Route::Datagram);
+
+    // Far larger than any datagram.
+    assert_eq!(send(&mut tx, world_settings(200)).await, Route::Primary);
+
+    let datagram = pair.client.read_datagram().await.unwrap();
+    let packet = decode_one(&mut client_decoder(), &datagram);
+    let AnyPacket::WorldSettings(packet) = packet else {
+        panic!("expected WorldSettings, got {packet:?}");
+    };
+    assert_eq!(packet.requiredAssets.unwrap().len(), 1);
+
+    let mut primary = FramedRead::new(pair.client_primary_rx, client_decoder());
+    let packet = primary.next().await.unwrap().unwrap().into_inner();
+    let AnyPacket::WorldSettings(packet) = packet else {
+        panic!("expected WorldSettings, got {packet:?}");
+    };
+    assert_eq!(packet.requiredAssets.unwrap().len(), 200);
+}
+
+#[tokio::test]
+async fn receiver_merges_streams_and_datagrams() {
+    let mut pair = connect(MULTI_STREAM_ALPN).await;
+    let mut rx = TransportRx::new(
+        pair.server.clone(),
+        &StreamLayout::split(),
+        pair.server_primary.1,
+        PacketCategory::all(),
+    );
+    rx.activate_layout();
+
+    let lane = pair.client.open_uni().await.unwrap();
+    let mut lane = Framed::new(lane, HytaleEncoder::<ServerboundPacket>::new());
+    lane.send(ClientMovement::default().into()).await.unwrap();
+
+    let mut datagram = BytesMut::new();
+    HytaleEncoder::<ServerboundPacket>::new()
+        .encode(ViewRadius { value: 12 }.into(), &mut datagram)
+        .unwrap();
+    pair.client.send_datagram(datagram.freeze()).unwrap();
+
+    pair.client_primary
+        .send(ClientReady::default().into())
+        .await
+        .unwrap();
+
+    let mut received = Vec::new();
+    for _ in 0..4 {
+        let packet = rx.next().await.unwrap().unwrap();
+        received.push(packet.descriptor().name);
+    }
+
+    received.sort_unstable();
+    assert_eq!(
+        received,
+        ["ClientMovement", "ClientReady", "ClientReady", "ViewRadius"],
+    );
+}
+
+#[tokio::test]
+async fn client_streams_wait_for_authentication() {
+    let pair = connect(MULTI_STREAM_ALPN).await;
+    let mut rx = TransportRx::new(
+        pair.server.clone(),
+        &StreamLayout::split(),
+        pair.server_primary.1,
+        PacketCategory::all(),
+    );
+
+    let open = || tokio::time::timeout(Duration::from_millis(200), pair.client.open_uni());
+
+    assert!(open().await.is_err());
+
+    rx.activate_layout();
+
+    // One stream for each of the layout's stream lanes, and no more.
+    let _lanes = (open().await.unwrap(), open().await.unwrap());
+    assert!(open().await.is_err());
+}